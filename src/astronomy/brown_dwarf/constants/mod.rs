@@ -0,0 +1,74 @@
+/// The minimum mass of a brown dwarf; below this, deuterium never ignites.
+///
+/// Measured in Mjupiter.
+pub const MINIMUM_MASS: f64 = 13.0;
+
+/// The maximum mass of a brown dwarf; above this, it's a red dwarf.
+///
+/// This is `star::constants::MINIMUM_MASS`, converted.
+///
+/// Measured in Mjupiter.
+pub const MAXIMUM_MASS: f64 = 78.5;
+
+/// The minimum age of a brown dwarf we'll generate.
+///
+/// Measured in Gyr.
+pub const MINIMUM_AGE: f64 = 0.1;
+
+/// The maximum age of a brown dwarf we'll generate.
+///
+/// Measured in Gyr.
+pub const MAXIMUM_AGE: f64 = 10.0;
+
+/// The age at which a brown dwarf is considered to have formed; we use this
+/// to work out where its frost line was.
+///
+/// Measured in Gyr.
+pub const FORMATION_AGE: f64 = 0.001;
+
+/// Solar masses per Jupiter mass.
+pub const MSOL_PER_MJUPITER: f64 = 0.000_954_6;
+
+/// Solar radii per Jupiter radius.
+pub const RSOL_PER_RJUPITER: f64 = 0.100_45;
+
+/// Brown dwarfs are all about the size of Jupiter, give or take.
+///
+/// Measured in Rjupiter.
+pub const RADIUS: f64 = 1.0;
+
+/// The reference mass for the cooling relation (0.05 Msol).
+///
+/// Measured in Mjupiter.
+pub const REFERENCE_MASS: f64 = 52.4;
+
+/// The temperature of a reference-mass brown dwarf at 1 Gyr.
+///
+/// Measured in Kelvin.
+pub const REFERENCE_TEMPERATURE: f64 = 1_550.0;
+
+/// The maximum temperature of a (very young, very heavy) brown dwarf.
+///
+/// Measured in Kelvin.
+pub const MAXIMUM_TEMPERATURE: f64 = 2_800.0;
+
+/// The temperature of an L0 dwarf; anything hotter is a late M.
+///
+/// Measured in Kelvin.
+pub const L_CLASS_MAXIMUM_TEMPERATURE: f64 = 2_250.0;
+
+/// The temperature of a T0 dwarf.
+///
+/// Measured in Kelvin.
+pub const T_CLASS_MAXIMUM_TEMPERATURE: f64 = 1_300.0;
+
+/// The temperature of a Y0 dwarf.
+///
+/// Measured in Kelvin.
+pub const Y_CLASS_MAXIMUM_TEMPERATURE: f64 = 500.0;
+
+/// The probability that a planet beyond the frost line is a brown dwarf.
+///
+/// This is the "brown dwarf desert"; fewer than one Sun-like star in a
+/// hundred has a brown dwarf companion within a few AU.
+pub const COMPANION_PROBABILITY: f64 = 0.01;
@@ -0,0 +1,147 @@
+use rand::prelude::*;
+use std::default::Default;
+
+use crate::astronomy::brown_dwarf::constants::*;
+use crate::astronomy::brown_dwarf::error::Error;
+use crate::astronomy::brown_dwarf::BrownDwarf;
+use crate::astronomy::host_star::HostStar;
+use crate::astronomy::star::constants::MEAN_METALLICITY;
+use crate::astronomy::star::math::metallicity::get_random_metallicity;
+
+/// Constraints for creating a brown dwarf.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Constraints {
+  /// The minimum mass, in Mjupiter.
+  pub minimum_mass: Option<f64>,
+  /// The maximum mass, in Mjupiter.
+  pub maximum_mass: Option<f64>,
+  /// The minimum age, in Gyr.
+  pub minimum_age: Option<f64>,
+  /// The maximum age, in Gyr.
+  pub maximum_age: Option<f64>,
+  /// The metallicity ([Fe/H]) of a free-floating brown dwarf.
+  pub metallicity: Option<f64>,
+}
+
+impl Constraints {
+  /// Generate a free-floating brown dwarf.
+  #[named]
+  pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<BrownDwarf, Error> {
+    trace_enter!();
+    let minimum_age = self.minimum_age.unwrap_or(MINIMUM_AGE);
+    trace_var!(minimum_age);
+    let maximum_age = self.maximum_age.unwrap_or(MAXIMUM_AGE);
+    trace_var!(maximum_age);
+    let current_age = rng.gen_range(minimum_age..maximum_age);
+    trace_var!(current_age);
+    let metallicity = match self.metallicity {
+      Some(metallicity) => metallicity,
+      None => get_random_metallicity(rng, MEAN_METALLICITY),
+    };
+    trace_var!(metallicity);
+    let result = self.generate_at_age(rng, current_age, metallicity)?;
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Generate a brown dwarf orbiting a host star at a given distance in AU.
+  ///
+  /// It formed alongside the host star, so it shares its metallicity.
+  #[named]
+  pub fn generate_companion<R: Rng + ?Sized>(
+    &self,
+    rng: &mut R,
+    host_star: &HostStar,
+    distance: f64,
+  ) -> Result<BrownDwarf, Error> {
+    trace_enter!();
+    trace_var!(distance);
+    let mut result = self.generate_at_age(rng, host_star.get_current_age(), host_star.get_metallicity())?;
+    result.semi_major_axis = distance;
+    let orbital_eccentricity = rng.gen_range(0.0..0.3);
+    result.orbital_eccentricity = orbital_eccentricity;
+    trace_var!(orbital_eccentricity);
+    result.perihelion = (1.0 - orbital_eccentricity) * distance;
+    result.aphelion = (1.0 + orbital_eccentricity) * distance;
+    let orbital_period = (distance.powf(3.0) / (host_star.get_stellar_mass() + result.get_stellar_mass())).sqrt();
+    result.orbital_period = orbital_period;
+    trace_var!(orbital_period);
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Generate a brown dwarf of a given age, in Gyr, and metallicity.
+  #[named]
+  fn generate_at_age<R: Rng + ?Sized>(
+    &self,
+    rng: &mut R,
+    current_age: f64,
+    metallicity: f64,
+  ) -> Result<BrownDwarf, Error> {
+    trace_enter!();
+    trace_var!(current_age);
+    trace_var!(metallicity);
+    let minimum_mass = self.minimum_mass.unwrap_or(MINIMUM_MASS);
+    trace_var!(minimum_mass);
+    let maximum_mass = self.maximum_mass.unwrap_or(MAXIMUM_MASS);
+    trace_var!(maximum_mass);
+    // Lighter brown dwarfs are more common; uniform in log mass is a decent
+    // approximation of the substellar mass function.
+    let mass = rng.gen_range(minimum_mass.ln()..=maximum_mass.ln()).exp();
+    trace_var!(mass);
+    let mut result = BrownDwarf::from_mass_and_age(rng, mass, current_age)?;
+    result.metallicity = metallicity;
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+}
+
+impl Default for Constraints {
+  /// No constraints, just let it all hang out.
+  fn default() -> Self {
+    let minimum_mass = None;
+    let maximum_mass = None;
+    let minimum_age = None;
+    let maximum_age = None;
+    let metallicity = None;
+    Self {
+      minimum_mass,
+      maximum_mass,
+      minimum_age,
+      maximum_age,
+      metallicity,
+    }
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::astronomy::host_star::constraints::Constraints as HostStarConstraints;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_generate() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let brown_dwarf = Constraints::default().generate(&mut rng)?;
+    print_var!(brown_dwarf);
+    assert!(brown_dwarf.mass >= MINIMUM_MASS && brown_dwarf.mass <= MAXIMUM_MASS);
+    let host_star = HostStarConstraints::default().generate(&mut rng).unwrap();
+    let companion = Constraints::default().generate_companion(&mut rng, &host_star, 30.0)?;
+    print_var!(companion);
+    assert_approx_eq!(companion.current_age, host_star.get_current_age());
+    assert!(companion.orbital_period > 0.0);
+    trace_exit!();
+    Ok(())
+  }
+}
@@ -0,0 +1,19 @@
+/// Brown dwarf-related errors.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Error {
+  /// Lower than MINIMUM_MASS.
+  MassTooLowForBrownDwarf,
+  /// Higher than MAXIMUM_MASS.
+  MassTooHighForBrownDwarf,
+  /// Brown dwarfs are too dim and too cold.
+  BrownDwarfsCannotSupportLife,
+}
+
+honeyholt_define_brief!(Error, |error: &Error| {
+  use Error::*;
+  match error {
+    MassTooLowForBrownDwarf => "its mass is too low to be a brown dwarf".to_string(),
+    MassTooHighForBrownDwarf => "its mass is too high to be a brown dwarf".to_string(),
+    BrownDwarfsCannotSupportLife => "brown dwarfs cannot support life".to_string(),
+  }
+});
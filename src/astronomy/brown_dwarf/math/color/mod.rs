@@ -0,0 +1,58 @@
+use crate::astronomy::brown_dwarf::constants::*;
+
+/// Get the (very approximate) color of a brown dwarf in sRGB from its
+/// temperature in Kelvin.
+///
+/// L dwarfs are a deep, dusty red.  Sodium and potassium absorption make T
+/// dwarfs a dim magenta to the eye, and Y dwarfs barely glow at all.
+#[named]
+pub fn brown_dwarf_temperature_to_rgb(temperature: f64) -> (u8, u8, u8) {
+  trace_enter!();
+  trace_var!(temperature);
+  let anchors: [(f64, (f64, f64, f64)); 4] = [
+    (L_CLASS_MAXIMUM_TEMPERATURE, (255.0, 110.0, 50.0)),
+    (T_CLASS_MAXIMUM_TEMPERATURE, (200.0, 50.0, 40.0)),
+    (Y_CLASS_MAXIMUM_TEMPERATURE, (130.0, 40.0, 110.0)),
+    (250.0, (40.0, 10.0, 30.0)),
+  ];
+  let temperature = temperature.min(anchors[0].0).max(anchors[3].0);
+  let index = anchors
+    .windows(2)
+    .position(|pair| temperature >= pair[1].0)
+    .unwrap_or(2);
+  let (hotter, hot) = anchors[index];
+  let (cooler, cool) = anchors[index + 1];
+  let fraction = (hotter - temperature) / (hotter - cooler);
+  let interpolate = |a: f64, b: f64| (a + fraction * (b - a)).round() as u8;
+  let result = (
+    interpolate(hot.0, cool.0),
+    interpolate(hot.1, cool.1),
+    interpolate(hot.2, cool.2),
+  );
+  trace_3u8!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_brown_dwarf_temperature_to_rgb() {
+    init();
+    trace_enter!();
+    assert_eq!((255, 110, 50), brown_dwarf_temperature_to_rgb(3_000.0));
+    assert_eq!(
+      (200, 50, 40),
+      brown_dwarf_temperature_to_rgb(T_CLASS_MAXIMUM_TEMPERATURE)
+    );
+    assert_eq!((40, 10, 30), brown_dwarf_temperature_to_rgb(100.0));
+    let t_dwarf = brown_dwarf_temperature_to_rgb(900.0);
+    assert!(t_dwarf.2 > 40);
+    trace_exit!();
+  }
+}
@@ -0,0 +1,3 @@
+pub mod color;
+pub mod spectral_class;
+pub mod temperature;
@@ -0,0 +1,68 @@
+use crate::astronomy::brown_dwarf::constants::*;
+use crate::astronomy::star::spectral_class::SpectralClass;
+use crate::astronomy::star::spectral_peculiarities::SpectralPeculiarities;
+use crate::astronomy::star::spectral_type::SpectralType;
+
+/// Get the spectral class of a brown dwarf from its temperature in Kelvin.
+///
+/// Young, heavy brown dwarfs are still hot enough to look like late M dwarfs;
+/// after that they cool through L (dusty), T (methane), and Y (water clouds).
+/// Subclasses are spaced evenly in temperature within each class, which is
+/// not quite right but close enough.
+#[named]
+pub fn brown_dwarf_temperature_to_spectral_class(temperature: f64) -> SpectralClass {
+  trace_enter!();
+  trace_var!(temperature);
+  use SpectralType::*;
+  let (spectral_type, hotter, cooler) = match temperature {
+    temperature if temperature > L_CLASS_MAXIMUM_TEMPERATURE => (M, MAXIMUM_TEMPERATURE, L_CLASS_MAXIMUM_TEMPERATURE),
+    temperature if temperature > T_CLASS_MAXIMUM_TEMPERATURE => {
+      (L, L_CLASS_MAXIMUM_TEMPERATURE, T_CLASS_MAXIMUM_TEMPERATURE)
+    },
+    temperature if temperature > Y_CLASS_MAXIMUM_TEMPERATURE => {
+      (T, T_CLASS_MAXIMUM_TEMPERATURE, Y_CLASS_MAXIMUM_TEMPERATURE)
+    },
+    _ => (Y, Y_CLASS_MAXIMUM_TEMPERATURE, 250.0),
+  };
+  let fraction = ((hotter - temperature) / (hotter - cooler)).clamp(0.0, 0.99);
+  let subclass = match spectral_type {
+    // Late M only covers M7 to M9.
+    M => 7 + (fraction * 3.0) as u8,
+    _ => (fraction * 10.0) as u8,
+  };
+  let result = SpectralClass {
+    spectral_type,
+    subclass: Some(subclass.into()),
+    luminosity_class: None,
+    peculiarities: SpectralPeculiarities::default(),
+  };
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_brown_dwarf_temperature_to_spectral_class() {
+    init();
+    trace_enter!();
+    let get_class = |temperature: f64| brown_dwarf_temperature_to_spectral_class(temperature).to_string();
+    assert_eq!("M8", get_class(2_500.0));
+    assert_eq!("L0", get_class(2_250.0));
+    assert_eq!("L7", get_class(1_500.0));
+    assert_eq!("T0", get_class(1_300.0));
+    assert_eq!("T5", get_class(900.0));
+    assert_eq!("Y1", get_class(470.0));
+    assert_eq!(
+      SpectralType::T,
+      brown_dwarf_temperature_to_spectral_class(900.0).spectral_type
+    );
+    trace_exit!();
+  }
+}
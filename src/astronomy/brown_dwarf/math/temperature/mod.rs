@@ -0,0 +1,54 @@
+use crate::astronomy::brown_dwarf::constants::*;
+
+/// Get the effective temperature of a brown dwarf in Kelvin from its mass in
+/// Mjupiter and its age in Gyr.
+///
+/// Brown dwarfs never settle down; they just cool off forever.  This is the
+/// power-law fit from Burrows & Liebert (1993).
+#[named]
+pub fn get_brown_dwarf_temperature(mass: f64, age: f64) -> f64 {
+  trace_enter!();
+  trace_var!(mass);
+  trace_var!(age);
+  let result = (REFERENCE_TEMPERATURE * (mass / REFERENCE_MASS).powf(0.83) * age.powf(-0.32)).min(MAXIMUM_TEMPERATURE);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the luminosity of a brown dwarf in Lsol from its radius in Rjupiter
+/// and its temperature in Kelvin.
+#[named]
+pub fn get_brown_dwarf_luminosity(radius: f64, temperature: f64) -> f64 {
+  trace_enter!();
+  trace_var!(radius);
+  trace_var!(temperature);
+  let result = (radius * RSOL_PER_RJUPITER).powf(2.0) * (temperature / 5776.0).powf(4.0);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_brown_dwarf_temperature() {
+    init();
+    trace_enter!();
+    assert_approx_eq!(get_brown_dwarf_temperature(REFERENCE_MASS, 1.0), REFERENCE_TEMPERATURE);
+    // They cool as they age, and the heavy ones stay warmer.
+    assert!(get_brown_dwarf_temperature(30.0, 5.0) < get_brown_dwarf_temperature(30.0, 1.0));
+    assert!(get_brown_dwarf_temperature(70.0, 5.0) > get_brown_dwarf_temperature(30.0, 5.0));
+    // Luhman 16A is around 34 Mjup, 0.6 Gyr, 1,350 K.
+    assert_approx_eq!(get_brown_dwarf_temperature(34.0, 0.6), 1_350.0, 250.0);
+    let luminosity = get_brown_dwarf_luminosity(RADIUS, REFERENCE_TEMPERATURE);
+    print_var!(luminosity);
+    assert!(luminosity > 1e-5 && luminosity < 1e-4);
+    trace_exit!();
+  }
+}
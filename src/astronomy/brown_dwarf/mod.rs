@@ -0,0 +1,214 @@
+use rand::prelude::*;
+
+use crate::astronomy::star::math::habitable_zone::star_luminosity_to_habitable_zone;
+use crate::astronomy::star::math::satellite_zone::{get_approximate_innermost_orbit, get_approximate_outermost_orbit};
+use crate::astronomy::star::name::generate_star_name;
+use crate::astronomy::star::spectral_class::SpectralClass;
+
+pub mod constants;
+use constants::*;
+pub mod constraints;
+pub mod error;
+use error::Error;
+pub mod math;
+use math::color::brown_dwarf_temperature_to_rgb;
+use math::spectral_class::brown_dwarf_temperature_to_spectral_class;
+use math::temperature::{get_brown_dwarf_luminosity, get_brown_dwarf_temperature};
+
+/// The `BrownDwarf` type.
+///
+/// Too heavy to be a planet, too light to be a star.  These can wander the
+/// neighborhood on their own, tag along with a star at a distance, or sit at
+/// the top end of a planetary system.
+///
+/// The orbital parameters are only meaningful when the brown dwarf orbits a
+/// star; otherwise they're zero.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BrownDwarf {
+  /// Spectral class, e.g. L3 or T6.
+  pub class: SpectralClass,
+  /// Mass, in Mjupiter.
+  pub mass: f64,
+  /// Iron abundance relative to the Sun, i.e. [Fe/H], measured in dex.
+  pub metallicity: f64,
+  /// Radius, in Rjupiter.
+  pub radius: f64,
+  /// Density, in Djupiter.
+  pub density: f64,
+  /// Measured in Kelvin.
+  pub temperature: f64,
+  /// Measured in Lsol.
+  pub luminosity: f64,
+  /// Measured in Gyr.
+  pub current_age: f64,
+  /// Habitable zone, measured in AU.
+  pub habitable_zone: (f64, f64),
+  /// Minimum and maximum sustainable distance for satellites, measured in AU.
+  pub satellite_zone: (f64, f64),
+  /// The frost line when the brown dwarf formed, measured in AU.
+  pub frost_line: f64,
+  /// The absolute color of this brown dwarf in SRGB.
+  pub absolute_rgb: (u8, u8, u8),
+  /// A generated name for this brown dwarf.
+  pub name: String,
+  /// Semi-Major Axis, in AU.
+  pub semi_major_axis: f64,
+  /// Orbital eccentricity.
+  pub orbital_eccentricity: f64,
+  /// Perihelion, in AU.
+  pub perihelion: f64,
+  /// Aphelion, in AU.
+  pub aphelion: f64,
+  /// Orbital period, in Earth years.
+  pub orbital_period: f64,
+}
+
+impl BrownDwarf {
+  /// Generate a free-floating brown dwarf from a given mass and age.
+  ///
+  /// The brown dwarf will have solar metallicity.
+  #[named]
+  pub fn from_mass_and_age<R: Rng + ?Sized>(rng: &mut R, mass: f64, current_age: f64) -> Result<Self, Error> {
+    trace_enter!();
+    trace_var!(mass);
+    trace_var!(current_age);
+    if mass < MINIMUM_MASS {
+      return Err(Error::MassTooLowForBrownDwarf);
+    }
+    if mass > MAXIMUM_MASS {
+      return Err(Error::MassTooHighForBrownDwarf);
+    }
+    let radius = RADIUS;
+    trace_var!(radius);
+    let density = mass / radius.powf(3.0);
+    trace_var!(density);
+    let temperature = get_brown_dwarf_temperature(mass, current_age);
+    trace_var!(temperature);
+    let luminosity = get_brown_dwarf_luminosity(radius, temperature);
+    trace_var!(luminosity);
+    let class = brown_dwarf_temperature_to_spectral_class(temperature);
+    trace_var!(class);
+    let absolute_rgb = brown_dwarf_temperature_to_rgb(temperature);
+    trace_3u8!(absolute_rgb);
+    let habitable_zone = star_luminosity_to_habitable_zone(luminosity);
+    trace_var!(habitable_zone);
+    let satellite_zone = (
+      get_approximate_innermost_orbit(mass * MSOL_PER_MJUPITER),
+      get_approximate_outermost_orbit(mass * MSOL_PER_MJUPITER),
+    );
+    trace_var!(satellite_zone);
+    let formation_luminosity = get_brown_dwarf_luminosity(radius, get_brown_dwarf_temperature(mass, FORMATION_AGE));
+    trace_var!(formation_luminosity);
+    let frost_line = 4.85 * formation_luminosity.sqrt();
+    trace_var!(frost_line);
+    let name = generate_star_name(rng);
+    trace_var!(name);
+    let result = Self {
+      class,
+      mass,
+      metallicity: 0.0,
+      radius,
+      density,
+      temperature,
+      luminosity,
+      current_age,
+      habitable_zone,
+      satellite_zone,
+      frost_line,
+      absolute_rgb,
+      name,
+      semi_major_axis: 0.0,
+      orbital_eccentricity: 0.0,
+      perihelion: 0.0,
+      aphelion: 0.0,
+      orbital_period: 0.0,
+    };
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Retrieve or calculate the mass in Msol.
+  #[named]
+  pub fn get_stellar_mass(&self) -> f64 {
+    trace_enter!();
+    let result = self.mass * MSOL_PER_MJUPITER;
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the habitable zone at a given age, in AU.
+  #[named]
+  pub fn get_habitable_zone_at_age(&self, age: f64) -> (f64, f64) {
+    trace_enter!();
+    trace_var!(age);
+    let temperature = get_brown_dwarf_temperature(self.mass, age);
+    trace_var!(temperature);
+    let luminosity = get_brown_dwarf_luminosity(self.radius, temperature);
+    trace_var!(luminosity);
+    let result = star_luminosity_to_habitable_zone(luminosity);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Indicate whether this brown dwarf is capable of supporting conventional
+  /// life.
+  #[named]
+  pub fn check_habitable(&self) -> Result<(), Error> {
+    trace_enter!();
+    let result = Err(Error::BrownDwarfsCannotSupportLife);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Indicate whether this brown dwarf is capable of supporting conventional
+  /// life.
+  #[named]
+  pub fn is_habitable(&self) -> bool {
+    trace_enter!();
+    let result = match self.check_habitable() {
+      Ok(()) => true,
+      Err(_) => false,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::astronomy::star::spectral_type::SpectralType;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_from_mass_and_age() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let brown_dwarf = BrownDwarf::from_mass_and_age(&mut rng, 40.0, 3.0)?;
+    print_var!(brown_dwarf);
+    assert_eq!(SpectralType::T, brown_dwarf.class.spectral_type);
+    assert!(brown_dwarf.luminosity < 1e-4);
+    assert!(brown_dwarf.get_habitable_zone_at_age(0.1).0 > brown_dwarf.habitable_zone.0);
+    assert_eq!(
+      Err(Error::MassTooLowForBrownDwarf),
+      BrownDwarf::from_mass_and_age(&mut rng, 5.0, 3.0)
+    );
+    assert_eq!(
+      Err(Error::MassTooHighForBrownDwarf),
+      BrownDwarf::from_mass_and_age(&mut rng, 100.0, 3.0)
+    );
+    trace_exit!();
+    Ok(())
+  }
+}
@@ -4,7 +4,9 @@ use std::default::Default;
 use crate::astronomy::close_binary_star::constants::*;
 use crate::astronomy::close_binary_star::error::Error;
 use crate::astronomy::close_binary_star::CloseBinaryStar;
+use crate::astronomy::star::constants::MEAN_METALLICITY;
 use crate::astronomy::star::constraints::Constraints as StarConstraints;
+use crate::astronomy::star::math::metallicity::get_random_metallicity;
 
 /// Constraints for creating a binary star.
 ///
@@ -43,6 +45,8 @@ pub struct Constraints {
   pub enforce_habitability: bool,
   /// Star constraints.
   pub star_constraints: Option<StarConstraints>,
+  /// The metallicity ([Fe/H]) shared by both stars.
+  pub metallicity: Option<f64>,
 }
 
 impl Constraints {
@@ -123,6 +127,14 @@ impl Constraints {
       primary_constraints = self.star_constraints.unwrap_or(StarConstraints::default());
       secondary_constraints = self.star_constraints.unwrap_or(StarConstraints::default());
     }
+    // Both stars formed from the same cloud, so they share a metallicity.
+    let metallicity = match self.metallicity.or(primary_constraints.metallicity) {
+      Some(metallicity) => metallicity,
+      None => get_random_metallicity(rng, MEAN_METALLICITY),
+    };
+    trace_var!(metallicity);
+    primary_constraints.metallicity = Some(metallicity);
+    secondary_constraints.metallicity = Some(metallicity);
     let (primary, secondary) = {
       combined_mass = rng.gen_range(minimum_combined_mass..maximum_combined_mass);
       let half = combined_mass / 2.0;
@@ -136,20 +148,22 @@ impl Constraints {
       primary_constraints.maximum_mass = Some(1.001 * primary_mass);
       secondary_constraints.minimum_mass = Some(0.999 * secondary_mass);
       secondary_constraints.maximum_mass = Some(1.001 * secondary_mass);
-      let mut primary = primary_constraints.generate(rng)?;
-      let mut secondary = secondary_constraints.generate(rng)?;
-      let minimum_age = match self.enforce_habitability {
+      // The masses are already settled, and it's the pair that needs to be
+      // habitable, not each star on its own.
+      primary_constraints.make_habitable = false;
+      secondary_constraints.make_habitable = false;
+      let primary = primary_constraints.generate(rng)?;
+      let secondary = secondary_constraints.generate(rng)?;
+      let maximum_age = 0.9 * primary.life_expectancy;
+      trace_var!(maximum_age);
+      let minimum_age = match self.enforce_habitability && maximum_age > MINIMUM_HABITABLE_AGE {
         true => MINIMUM_HABITABLE_AGE,
         false => 0.1 * primary.life_expectancy,
       };
       trace_var!(minimum_age);
-      let maximum_age = 0.9 * primary.life_expectancy;
-      trace_var!(maximum_age);
       let current_age = rng.gen_range(minimum_age..maximum_age);
       trace_var!(current_age);
-      primary.current_age = current_age;
-      secondary.current_age = current_age;
-      (primary, secondary)
+      (primary.get_at_age(current_age)?, secondary.get_at_age(current_age)?)
     };
     trace_var!(primary);
     trace_var!(secondary);
@@ -177,6 +191,7 @@ impl Default for Constraints {
     let maximum_age = None;
     let enforce_habitability = false;
     let star_constraints = None;
+    let metallicity = None;
     let result = Self {
       minimum_combined_mass,
       maximum_combined_mass,
@@ -190,6 +205,7 @@ impl Default for Constraints {
       maximum_age,
       enforce_habitability,
       star_constraints,
+      metallicity,
     };
     trace_var!(result);
     trace_exit!();
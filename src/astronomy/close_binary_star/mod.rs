@@ -1,6 +1,7 @@
 use rand::prelude::*;
 
 use crate::astronomy::star::error::Error as StarError;
+use crate::astronomy::star::math::magnitude::*;
 use crate::astronomy::star::Star;
 
 pub mod constants;
@@ -142,6 +143,43 @@ impl CloseBinaryStar {
     result
   }
 
+  /// Retrieve or calculate the XUV flux at a distance in AU, in erg/cm²/s.
+  ///
+  /// Both stars are treated as if they were at the barycenter.
+  #[named]
+  pub fn get_xuv_flux(&self, distance: f64) -> f64 {
+    trace_enter!();
+    trace_var!(distance);
+    let result = self.primary.get_xuv_flux(distance) + self.secondary.get_xuv_flux(distance);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the rate of flares at or above an energy in ergs,
+  /// in flares per year.
+  #[named]
+  pub fn get_flare_rate(&self, energy: f64) -> f64 {
+    trace_enter!();
+    trace_var!(energy);
+    let result = self.primary.get_flare_rate(energy) + self.secondary.get_flare_rate(energy);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the metallicity ([Fe/H]) of the stars.
+  ///
+  /// They formed together, so they share a metallicity.
+  #[named]
+  pub fn get_metallicity(&self) -> f64 {
+    trace_enter!();
+    let result = self.primary.metallicity;
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
   /// Retrieve or calculate the total mass of the stars.
   ///
   /// Calculated in Msol.
@@ -164,6 +202,81 @@ impl CloseBinaryStar {
     result
   }
 
+  /// Retrieve or calculate the absolute bolometric magnitude of both stars
+  /// together.
+  #[named]
+  pub fn get_absolute_bolometric_magnitude(&self) -> f64 {
+    trace_enter!();
+    let result = star_luminosity_to_absolute_bolometric_magnitude(self.get_luminosity());
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the absolute visual (V band) magnitude of both
+  /// stars together.
+  #[named]
+  pub fn get_absolute_visual_magnitude(&self) -> f64 {
+    trace_enter!();
+    let result = combine_magnitudes(
+      self.primary.get_absolute_visual_magnitude(),
+      self.secondary.get_absolute_visual_magnitude(),
+    );
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the B-V color index of both stars together.
+  ///
+  /// We combine the light in each band separately, then take the difference.
+  #[named]
+  pub fn get_color_index(&self) -> f64 {
+    trace_enter!();
+    let blue_magnitude = combine_magnitudes(
+      self.primary.get_absolute_visual_magnitude() + self.primary.get_color_index(),
+      self.secondary.get_absolute_visual_magnitude() + self.secondary.get_color_index(),
+    );
+    trace_var!(blue_magnitude);
+    let result = blue_magnitude - self.get_absolute_visual_magnitude();
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the apparent visual magnitude of both stars
+  /// together at a distance in light years.
+  ///
+  /// Extinction is in magnitudes; if it isn't given, we estimate it from the
+  /// distance.
+  #[named]
+  pub fn get_apparent_magnitude(&self, distance: f64, extinction: Option<f64>) -> f64 {
+    trace_enter!();
+    trace_var!(distance);
+    trace_var!(extinction);
+    let result = get_apparent_magnitude(self.get_absolute_visual_magnitude(), distance, extinction);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the habitable zone at a given age, in AU.
+  ///
+  /// Both stars are evolved to the specified age.
+  #[named]
+  pub fn get_habitable_zone_at_age(&self, age: f64) -> Result<(f64, f64), Error> {
+    trace_enter!();
+    trace_var!(age);
+    let primary = self.primary.get_at_age(age)?;
+    trace_var!(primary);
+    let secondary = self.secondary.get_at_age(age)?;
+    trace_var!(secondary);
+    let result = get_habitable_zone(&primary, &secondary);
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
   #[named]
   pub fn get_name(&self) -> String {
     trace_enter!();
@@ -232,4 +345,32 @@ pub mod test {
     trace_exit!();
     Ok(())
   }
+
+  #[named]
+  #[test]
+  pub fn test_magnitudes() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let star = Star::from_mass(&mut rng, 1.0)?;
+    let twins = CloseBinaryStar::from_stars(&mut rng, star.clone(), star.clone(), 0.1, 0.0)?;
+    print_var!(twins);
+    // Twice the light is about three quarters of a magnitude brighter.
+    let brightening = star.get_absolute_visual_magnitude() - twins.get_absolute_visual_magnitude();
+    assert_approx_eq!(brightening, 0.753, 0.001);
+    assert_approx_eq!(
+      star.get_absolute_bolometric_magnitude() - twins.get_absolute_bolometric_magnitude(),
+      brightening,
+      0.001
+    );
+    assert_approx_eq!(twins.get_color_index(), star.get_color_index());
+    let apparent_magnitude = twins.get_apparent_magnitude(100.0, Some(0.0));
+    assert_approx_eq!(
+      apparent_magnitude,
+      star.get_apparent_magnitude(100.0, Some(0.0)) - brightening
+    );
+    trace_exit!();
+    Ok(())
+  }
 }
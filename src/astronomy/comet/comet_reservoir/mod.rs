@@ -0,0 +1,13 @@
+/// The `CometReservoir` type.
+///
+/// Where a comet was kept in cold storage before something nudged it inward,
+/// which decides what kind of orbit it has.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CometReservoir {
+  /// The shell of icy bodies far out at the edge of the system; comets from
+  /// here fall in from any direction on orbits that take millennia.
+  OortCloud,
+  /// The disk of icy bodies beyond the giants; comets from here have been
+  /// herded inward by the giants and come back every few years.
+  KuiperBelt,
+}
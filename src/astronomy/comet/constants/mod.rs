@@ -0,0 +1,66 @@
+/// The absolute magnitude of a comet with one square kilometer of active
+/// surface; Halley's Comet, about 5.5 km across and a tenth active, comes out
+/// around 5.5.
+pub const REFERENCE_ABSOLUTE_MAGNITUDE: f64 = 6.7;
+
+/// How steeply a comet brightens as it nears the star; a bare rock would be
+/// 2.
+pub const ACTIVITY_INDEX: f64 = 4.0;
+
+/// The faintest thing you can see with the naked eye under a dark sky.
+pub const NAKED_EYE_MAGNITUDE: f64 = 6.0;
+
+/// The smallest comet nucleus we bother with, in km.
+pub const MINIMUM_NUCLEUS_RADIUS: f64 = 1.0;
+
+/// The largest comet nucleus, in km; about Bernardinelli-Bernstein.
+pub const MAXIMUM_NUCLEUS_RADIUS: f64 = 50.0;
+
+/// The index of the cumulative size distribution of comet nuclei; there are
+/// about 4 times as many half the size.
+pub const NUCLEUS_RADIUS_INDEX: f64 = 1.9;
+
+/// The closest an Oort Cloud comet gets to an Earth-like insolation, in AU;
+/// some skim right through the corona.
+pub const MINIMUM_OORT_CLOUD_PERIHELION: f64 = 0.05;
+
+/// The farthest perihelion at which an Oort Cloud comet still livens up
+/// enough for anyone to notice, in AU at an Earth-like insolation.
+pub const MAXIMUM_OORT_CLOUD_PERIHELION: f64 = 5.0;
+
+/// The smallest semi-major axis of a comet fresh from the Oort Cloud, in AU.
+pub const MINIMUM_OORT_CLOUD_SEMI_MAJOR_AXIS: f64 = 1.0E3;
+
+/// The largest semi-major axis of a comet fresh from the Oort Cloud, in AU.
+pub const MAXIMUM_OORT_CLOUD_SEMI_MAJOR_AXIS: f64 = 5.0E4;
+
+/// The least fraction of the surface of an Oort Cloud comet that's active;
+/// they're fresh, and still have plenty of ice near the surface.
+pub const MINIMUM_OORT_CLOUD_ACTIVITY: f64 = 0.1;
+
+/// The greatest fraction of the surface of an Oort Cloud comet that's active.
+pub const MAXIMUM_OORT_CLOUD_ACTIVITY: f64 = 1.0;
+
+/// The closest a Kuiper Belt comet gets to an Earth-like insolation, in AU.
+pub const MINIMUM_KUIPER_BELT_PERIHELION: f64 = 1.0;
+
+/// The farthest a Kuiper Belt comet gets from an Earth-like insolation, in
+/// AU, and still counts as a comet.
+pub const MAXIMUM_KUIPER_BELT_PERIHELION: f64 = 3.0;
+
+/// The least eccentricity of a Kuiper Belt comet.
+pub const MINIMUM_KUIPER_BELT_ECCENTRICITY: f64 = 0.3;
+
+/// The greatest eccentricity of a Kuiper Belt comet.
+pub const MAXIMUM_KUIPER_BELT_ECCENTRICITY: f64 = 0.7;
+
+/// The greatest inclination of a Kuiper Belt comet, in degrees.
+pub const MAXIMUM_KUIPER_BELT_INCLINATION: f64 = 30.0;
+
+/// The least fraction of the surface of a Kuiper Belt comet that's active;
+/// they've been around the block, and most of them are crusted over.
+pub const MINIMUM_KUIPER_BELT_ACTIVITY: f64 = 0.01;
+
+/// The greatest fraction of the surface of a Kuiper Belt comet that's
+/// active.
+pub const MAXIMUM_KUIPER_BELT_ACTIVITY: f64 = 0.2;
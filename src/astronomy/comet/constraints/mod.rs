@@ -0,0 +1,233 @@
+use rand::prelude::*;
+
+use crate::astronomy::comet::comet_reservoir::CometReservoir;
+use crate::astronomy::comet::constants::*;
+use crate::astronomy::comet::error::Error;
+use crate::astronomy::comet::math::magnitude::get_absolute_magnitude;
+use crate::astronomy::comet::math::orbital_period::get_orbital_period;
+use crate::astronomy::comet::Comet;
+use crate::astronomy::host_star::HostStar;
+
+/// Constraints for creating a comet.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Constraints {
+  /// Where it came from.
+  pub reservoir: Option<CometReservoir>,
+  /// The minimum perihelion, in AU.
+  pub minimum_perihelion: Option<f64>,
+  /// The maximum perihelion, in AU.
+  pub maximum_perihelion: Option<f64>,
+  /// The minimum radius of the nucleus, in km.
+  pub minimum_nucleus_radius: Option<f64>,
+  /// The maximum radius of the nucleus, in km.
+  pub maximum_nucleus_radius: Option<f64>,
+  /// The minimum fraction of the surface that's active.
+  pub minimum_activity: Option<f64>,
+  /// The maximum fraction of the surface that's active.
+  pub maximum_activity: Option<f64>,
+  /// When it next passes perihelion, in years from now.
+  pub time_of_perihelion: Option<f64>,
+}
+
+impl Constraints {
+  /// A comet fresh from the Oort Cloud.
+  pub fn oort_cloud() -> Self {
+    Self {
+      reservoir: Some(CometReservoir::OortCloud),
+      ..Constraints::default()
+    }
+  }
+
+  /// A comet herded in from the Kuiper Belt.
+  pub fn kuiper_belt() -> Self {
+    Self {
+      reservoir: Some(CometReservoir::KuiperBelt),
+      ..Constraints::default()
+    }
+  }
+
+  /// Generate.
+  ///
+  /// How close it gets depends on how bright the star is, since that's what
+  /// decides whether it lights up; the rest depends on where it came from.
+  #[named]
+  pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R, host_star: &HostStar) -> Result<Comet, Error> {
+    trace_enter!();
+    trace_var!(host_star);
+    use CometReservoir::*;
+    let reservoir = match self.reservoir {
+      Some(reservoir) => reservoir,
+      None => match rng.gen_bool(0.5) {
+        true => OortCloud,
+        false => KuiperBelt,
+      },
+    };
+    trace_var!(reservoir);
+    let distance_factor = host_star.get_luminosity().sqrt();
+    trace_var!(distance_factor);
+    let (default_minimum_perihelion, default_maximum_perihelion, default_minimum_activity, default_maximum_activity) =
+      match reservoir {
+        OortCloud => (
+          MINIMUM_OORT_CLOUD_PERIHELION,
+          MAXIMUM_OORT_CLOUD_PERIHELION,
+          MINIMUM_OORT_CLOUD_ACTIVITY,
+          MAXIMUM_OORT_CLOUD_ACTIVITY,
+        ),
+        KuiperBelt => (
+          MINIMUM_KUIPER_BELT_PERIHELION,
+          MAXIMUM_KUIPER_BELT_PERIHELION,
+          MINIMUM_KUIPER_BELT_ACTIVITY,
+          MAXIMUM_KUIPER_BELT_ACTIVITY,
+        ),
+      };
+    let minimum_perihelion = self
+      .minimum_perihelion
+      .unwrap_or(default_minimum_perihelion * distance_factor);
+    trace_var!(minimum_perihelion);
+    let maximum_perihelion = self
+      .maximum_perihelion
+      .unwrap_or(default_maximum_perihelion * distance_factor);
+    trace_var!(maximum_perihelion);
+    let perihelion = match minimum_perihelion < maximum_perihelion {
+      true => rng.gen_range(minimum_perihelion..maximum_perihelion),
+      false => minimum_perihelion,
+    };
+    trace_var!(perihelion);
+    let (semi_major_axis, orbital_eccentricity, orbital_inclination) = match reservoir {
+      OortCloud => {
+        let semi_major_axis = rng
+          .gen_range(MINIMUM_OORT_CLOUD_SEMI_MAJOR_AXIS.ln()..MAXIMUM_OORT_CLOUD_SEMI_MAJOR_AXIS.ln())
+          .exp()
+          .max(perihelion);
+        // They come in from every direction, so the pole's anywhere on the sky.
+        let orbital_inclination = rng.gen_range(-1.0_f64..1.0).acos().to_degrees();
+        (semi_major_axis, 1.0 - perihelion / semi_major_axis, orbital_inclination)
+      },
+      KuiperBelt => {
+        let orbital_eccentricity = rng.gen_range(MINIMUM_KUIPER_BELT_ECCENTRICITY..MAXIMUM_KUIPER_BELT_ECCENTRICITY);
+        let orbital_inclination = rng.gen_range(0.0..MAXIMUM_KUIPER_BELT_INCLINATION);
+        (
+          perihelion / (1.0 - orbital_eccentricity),
+          orbital_eccentricity,
+          orbital_inclination,
+        )
+      },
+    };
+    trace_var!(semi_major_axis);
+    trace_var!(orbital_eccentricity);
+    trace_var!(orbital_inclination);
+    let longitude_of_ascending_node = rng.gen_range(0.0..360.0);
+    trace_var!(longitude_of_ascending_node);
+    let argument_of_perihelion = rng.gen_range(0.0..360.0);
+    trace_var!(argument_of_perihelion);
+    let orbital_period = get_orbital_period(semi_major_axis, host_star.get_stellar_mass());
+    trace_var!(orbital_period);
+    let time_of_perihelion = self
+      .time_of_perihelion
+      .unwrap_or_else(|| rng.gen_range(0.0..orbital_period));
+    trace_var!(time_of_perihelion);
+    let minimum_nucleus_radius = self.minimum_nucleus_radius.unwrap_or(MINIMUM_NUCLEUS_RADIUS);
+    trace_var!(minimum_nucleus_radius);
+    let maximum_nucleus_radius = self.maximum_nucleus_radius.unwrap_or(MAXIMUM_NUCLEUS_RADIUS);
+    trace_var!(maximum_nucleus_radius);
+    // Small ones are much more common than big ones, so sample the power law.
+    let nucleus_radius = match minimum_nucleus_radius < maximum_nucleus_radius {
+      true => {
+        let range = 1.0 - (minimum_nucleus_radius / maximum_nucleus_radius).powf(NUCLEUS_RADIUS_INDEX);
+        minimum_nucleus_radius * (1.0 - rng.gen_range(0.0..range)).powf(-1.0 / NUCLEUS_RADIUS_INDEX)
+      },
+      false => minimum_nucleus_radius,
+    };
+    trace_var!(nucleus_radius);
+    let minimum_activity = self.minimum_activity.unwrap_or(default_minimum_activity);
+    trace_var!(minimum_activity);
+    let maximum_activity = self.maximum_activity.unwrap_or(default_maximum_activity);
+    trace_var!(maximum_activity);
+    let activity = match minimum_activity < maximum_activity {
+      true => rng.gen_range(minimum_activity..maximum_activity),
+      false => minimum_activity,
+    };
+    trace_var!(activity);
+    let absolute_magnitude = get_absolute_magnitude(nucleus_radius, activity);
+    trace_var!(absolute_magnitude);
+    let result = Comet {
+      reservoir,
+      perihelion,
+      semi_major_axis,
+      orbital_eccentricity,
+      orbital_inclination,
+      longitude_of_ascending_node,
+      argument_of_perihelion,
+      orbital_period,
+      time_of_perihelion,
+      nucleus_radius,
+      activity,
+      absolute_magnitude,
+    };
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+}
+
+impl Default for Constraints {
+  /// No constraints, just let it all hang out.
+  fn default() -> Self {
+    let reservoir = None;
+    let minimum_perihelion = None;
+    let maximum_perihelion = None;
+    let minimum_nucleus_radius = None;
+    let maximum_nucleus_radius = None;
+    let minimum_activity = None;
+    let maximum_activity = None;
+    let time_of_perihelion = None;
+    Self {
+      reservoir,
+      minimum_perihelion,
+      maximum_perihelion,
+      minimum_nucleus_radius,
+      maximum_nucleus_radius,
+      minimum_activity,
+      maximum_activity,
+      time_of_perihelion,
+    }
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::astronomy::host_star::constraints::Constraints as HostStarConstraints;
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_generate() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let host_star = &HostStarConstraints::habitable().generate(&mut rng)?;
+    trace_var!(host_star);
+    let comet = Constraints::oort_cloud().generate(&mut rng, host_star)?;
+    print_var!(comet);
+    assert_eq!(comet.reservoir, CometReservoir::OortCloud);
+    assert!(comet.orbital_period > 1.0E4);
+    assert_approx_eq!(
+      comet.semi_major_axis * (1.0 - comet.orbital_eccentricity),
+      comet.perihelion
+    );
+    assert!(comet.nucleus_radius >= MINIMUM_NUCLEUS_RADIUS && comet.nucleus_radius <= MAXIMUM_NUCLEUS_RADIUS);
+    let comet = Constraints::kuiper_belt().generate(&mut rng, host_star)?;
+    print_var!(comet);
+    assert_eq!(comet.reservoir, CometReservoir::KuiperBelt);
+    assert!(comet.orbital_period < 1.0E3);
+    assert!(comet.orbital_inclination < MAXIMUM_KUIPER_BELT_INCLINATION);
+    assert!(comet.time_of_perihelion < comet.orbital_period);
+    trace_exit!();
+    Ok(())
+  }
+}
@@ -0,0 +1,77 @@
+use crate::astronomy::comet::constants::*;
+
+/// Get the absolute magnitude of a comet, its brightness 1 AU from both the
+/// star and the observer, given the radius of its nucleus in km and the
+/// fraction of its surface that's active.
+///
+/// Most of the light comes from the coma rather than the nucleus, and the
+/// coma scales with the area that's boiling off.
+#[named]
+pub fn get_absolute_magnitude(nucleus_radius: f64, activity: f64) -> f64 {
+  trace_enter!();
+  trace_var!(nucleus_radius);
+  trace_var!(activity);
+  let result = REFERENCE_ABSOLUTE_MAGNITUDE - 2.5 * (activity * nucleus_radius.powf(2.0)).log10();
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the apparent magnitude of a comet, given its absolute magnitude, the
+/// luminosity of the star in Lsol, its distance from the star in AU, and its
+/// distance from the observer in AU.
+///
+/// A comet brightens much faster than a rock as it nears the star, since it
+/// boils off more the warmer it gets; a brighter star just moves everything
+/// out a bit.
+#[named]
+pub fn get_apparent_magnitude(
+  absolute_magnitude: f64,
+  luminosity: f64,
+  star_distance: f64,
+  observer_distance: f64,
+) -> f64 {
+  trace_enter!();
+  trace_var!(absolute_magnitude);
+  trace_var!(luminosity);
+  trace_var!(star_distance);
+  trace_var!(observer_distance);
+  let effective_distance = star_distance / luminosity.sqrt();
+  trace_var!(effective_distance);
+  let result = absolute_magnitude + 5.0 * observer_distance.log10() + 2.5 * ACTIVITY_INDEX * effective_distance.log10();
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_apparent_magnitude() {
+    init();
+    trace_enter!();
+    // Halley's Comet.
+    let absolute_magnitude = get_absolute_magnitude(5.5, 0.1);
+    print_var!(absolute_magnitude);
+    assert_approx_eq!(absolute_magnitude, 5.5, 0.2);
+    assert_approx_eq!(
+      get_apparent_magnitude(absolute_magnitude, 1.0, 1.0, 1.0),
+      absolute_magnitude
+    );
+    // Fainter farther out, and much faster than a rock.
+    let far = get_apparent_magnitude(absolute_magnitude, 1.0, 3.0, 3.0);
+    print_var!(far);
+    assert!(far > absolute_magnitude + 7.0);
+    // A brighter star makes for a brighter comet.
+    assert!(
+      get_apparent_magnitude(absolute_magnitude, 4.0, 2.0, 1.0)
+        < get_apparent_magnitude(absolute_magnitude, 1.0, 2.0, 1.0)
+    );
+    trace_exit!();
+  }
+}
@@ -0,0 +1,2 @@
+pub mod magnitude;
+pub mod orbital_period;
@@ -0,0 +1,29 @@
+/// Get the orbital period of a comet, in years, given its semi-major axis in
+/// AU and the mass of the star in Msol.
+#[named]
+pub fn get_orbital_period(semi_major_axis: f64, stellar_mass: f64) -> f64 {
+  trace_enter!();
+  trace_var!(semi_major_axis);
+  trace_var!(stellar_mass);
+  let result = (semi_major_axis.powf(3.0) / stellar_mass).sqrt();
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_orbital_period() {
+    init();
+    trace_enter!();
+    // Halley's Comet.
+    assert_approx_eq!(get_orbital_period(17.834, 1.0), 75.3, 0.1);
+    trace_exit!();
+  }
+}
@@ -0,0 +1,108 @@
+pub mod comet_reservoir;
+use comet_reservoir::CometReservoir;
+pub mod constants;
+use constants::*;
+pub mod constraints;
+pub mod error;
+pub mod math;
+use math::magnitude::get_apparent_magnitude;
+
+/// A `Comet` is a chunk of dirty ice that falls in toward the star, lights
+/// up, and (usually) goes back out again.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comet {
+  /// Where it came from.
+  pub reservoir: CometReservoir,
+  /// The closest it gets to the star, in AU.
+  pub perihelion: f64,
+  /// Semi-major axis, in AU.
+  pub semi_major_axis: f64,
+  /// Orbital eccentricity.
+  pub orbital_eccentricity: f64,
+  /// Orbital inclination, in degrees; above 90º, it goes around backwards.
+  pub orbital_inclination: f64,
+  /// The longitude where it climbs up through the planets' plane, in
+  /// degrees.
+  pub longitude_of_ascending_node: f64,
+  /// The angle from there to perihelion, in degrees.
+  pub argument_of_perihelion: f64,
+  /// Orbital period, in years.
+  pub orbital_period: f64,
+  /// When it next passes perihelion, in years from now.
+  pub time_of_perihelion: f64,
+  /// The radius of the nucleus, in km.
+  pub nucleus_radius: f64,
+  /// The fraction of the surface of the nucleus that boils off.
+  pub activity: f64,
+  /// The brightness 1 AU from both the star and the observer.
+  pub absolute_magnitude: f64,
+}
+
+impl Comet {
+  /// Retrieve or calculate the times, in years from now, that it passes
+  /// perihelion between the start and the end.
+  #[named]
+  pub fn get_perihelion_passages(&self, start: f64, end: f64) -> Vec<f64> {
+    trace_enter!();
+    trace_var!(start);
+    trace_var!(end);
+    let first = ((start - self.time_of_perihelion) / self.orbital_period).ceil();
+    trace_var!(first);
+    let result = (0..)
+      .map(|orbit| self.time_of_perihelion + (first + orbit as f64) * self.orbital_period)
+      .take_while(|time| *time < end)
+      .collect::<Vec<f64>>();
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the apparent magnitude, given the luminosity of
+  /// the star in Lsol, its distance from the star in AU, and its distance
+  /// from the observer in AU.
+  #[named]
+  pub fn get_apparent_magnitude(&self, luminosity: f64, star_distance: f64, observer_distance: f64) -> f64 {
+    trace_enter!();
+    trace_var!(luminosity);
+    trace_var!(star_distance);
+    trace_var!(observer_distance);
+    let result = get_apparent_magnitude(self.absolute_magnitude, luminosity, star_distance, observer_distance);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the apparent magnitude at perihelion, seen from a
+  /// planet the given distance in AU from a star of the given luminosity in
+  /// Lsol.
+  ///
+  /// We don't track where the planet is along its orbit, so we take the
+  /// comet to be off to the side, neither right next to the planet nor
+  /// behind the star.
+  #[named]
+  pub fn get_peak_apparent_magnitude(&self, luminosity: f64, planet_distance: f64) -> f64 {
+    trace_enter!();
+    trace_var!(luminosity);
+    trace_var!(planet_distance);
+    let observer_distance = (self.perihelion.powf(2.0) + planet_distance.powf(2.0)).sqrt();
+    trace_var!(observer_distance);
+    let result = self.get_apparent_magnitude(luminosity, self.perihelion, observer_distance);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Indicate whether it's visible to the naked eye around perihelion from
+  /// a planet the given distance in AU from a star of the given luminosity
+  /// in Lsol.
+  #[named]
+  pub fn is_visible(&self, luminosity: f64, planet_distance: f64) -> bool {
+    trace_enter!();
+    trace_var!(luminosity);
+    trace_var!(planet_distance);
+    let result = self.get_peak_apparent_magnitude(luminosity, planet_distance) <= NAKED_EYE_MAGNITUDE;
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
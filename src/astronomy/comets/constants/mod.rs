@@ -0,0 +1,35 @@
+/// The least number of bodies bigger than a kilometer in the Oort Cloud of a
+/// star of one Msol.
+pub const MINIMUM_OORT_CLOUD_POPULATION: f64 = 1.0E11;
+
+/// The greatest number of bodies bigger than a kilometer in the Oort Cloud
+/// of a star of one Msol.
+pub const MAXIMUM_OORT_CLOUD_POPULATION: f64 = 1.0E12;
+
+/// The least number of bodies bigger than a kilometer in the Kuiper Belt of
+/// a star of one Msol.
+pub const MINIMUM_KUIPER_BELT_POPULATION: f64 = 1.0E8;
+
+/// The greatest number of bodies bigger than a kilometer in the Kuiper Belt
+/// of a star of one Msol.
+pub const MAXIMUM_KUIPER_BELT_POPULATION: f64 = 1.0E10;
+
+/// The chance each year that any particular Oort Cloud body gets nudged
+/// into the inner system by a passing star or the galactic tide.
+pub const OORT_CLOUD_INFLUX_RATE: f64 = 1.0E-12;
+
+/// The fraction of the Kuiper Belt that's been herded inward by the giants
+/// and is making the rounds as comets at any given time.
+pub const KUIPER_BELT_COMET_FRACTION: f64 = 5.0E-8;
+
+/// How many years of Oort Cloud comets we bother with, starting from now;
+/// they only come around once in a long while, so any further out and we'd
+/// just be making them up as we go.
+pub const EPOCH_DURATION: f64 = 100.0;
+
+/// The sporadic hourly rate at an Earth-like insolation, per square
+/// kilometer of active surface among the periodic comets.
+pub const SPORADIC_HOURLY_RATE_PER_ACTIVE_AREA: f64 = 0.2;
+
+/// How steeply the dust thickens toward the star, as it spirals inward.
+pub const DUST_DENSITY_INDEX: f64 = 1.3;
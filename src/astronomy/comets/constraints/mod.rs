@@ -0,0 +1,126 @@
+use rand::prelude::*;
+
+use crate::astronomy::comet::constraints::Constraints as CometConstraints;
+use crate::astronomy::comets::constants::*;
+use crate::astronomy::comets::error::Error;
+use crate::astronomy::comets::Comets;
+use crate::astronomy::host_star::HostStar;
+
+/// Constraints for creating comets.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Constraints {
+  /// The number of bodies bigger than a kilometer in the Oort Cloud.
+  pub oort_cloud_population: Option<u64>,
+  /// The number of bodies bigger than a kilometer in the Kuiper Belt.
+  pub kuiper_belt_population: Option<u64>,
+}
+
+impl Constraints {
+  /// Generate.
+  ///
+  /// Bigger stars had bigger disks, and so have more leftovers.  Only a
+  /// tiny fraction of them are falling inward at any one time, though.
+  #[named]
+  pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R, host_star: &HostStar) -> Result<Comets, Error> {
+    trace_enter!();
+    trace_var!(host_star);
+    let stellar_mass = host_star.get_stellar_mass();
+    trace_var!(stellar_mass);
+    let oort_cloud_population = self.oort_cloud_population.unwrap_or_else(|| {
+      (rng
+        .gen_range(MINIMUM_OORT_CLOUD_POPULATION.ln()..MAXIMUM_OORT_CLOUD_POPULATION.ln())
+        .exp()
+        * stellar_mass)
+        .round() as u64
+    });
+    trace_var!(oort_cloud_population);
+    let kuiper_belt_population = self.kuiper_belt_population.unwrap_or_else(|| {
+      (rng
+        .gen_range(MINIMUM_KUIPER_BELT_POPULATION.ln()..MAXIMUM_KUIPER_BELT_POPULATION.ln())
+        .exp()
+        * stellar_mass)
+        .round() as u64
+    });
+    trace_var!(kuiper_belt_population);
+    let mut comets = vec![];
+    let oort_cloud_count = oort_cloud_population as f64 * OORT_CLOUD_INFLUX_RATE * EPOCH_DURATION;
+    trace_var!(oort_cloud_count);
+    let oort_cloud_count = oort_cloud_count.floor() as u64 + rng.gen_bool(oort_cloud_count.fract()) as u64;
+    trace_var!(oort_cloud_count);
+    for _ in 0..oort_cloud_count {
+      let constraints = CometConstraints {
+        time_of_perihelion: Some(rng.gen_range(0.0..EPOCH_DURATION)),
+        ..CometConstraints::oort_cloud()
+      };
+      comets.push(constraints.generate(rng, host_star)?);
+    }
+    let kuiper_belt_count = kuiper_belt_population as f64 * KUIPER_BELT_COMET_FRACTION;
+    trace_var!(kuiper_belt_count);
+    let kuiper_belt_count = kuiper_belt_count.floor() as u64 + rng.gen_bool(kuiper_belt_count.fract()) as u64;
+    trace_var!(kuiper_belt_count);
+    for _ in 0..kuiper_belt_count {
+      comets.push(CometConstraints::kuiper_belt().generate(rng, host_star)?);
+    }
+    let result = Comets {
+      oort_cloud_population,
+      kuiper_belt_population,
+      comets,
+    };
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+}
+
+impl Default for Constraints {
+  /// No constraints, just let it all hang out.
+  fn default() -> Self {
+    let oort_cloud_population = None;
+    let kuiper_belt_population = None;
+    Self {
+      oort_cloud_population,
+      kuiper_belt_population,
+    }
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::astronomy::comet::comet_reservoir::CometReservoir;
+  use crate::astronomy::host_star::constraints::Constraints as HostStarConstraints;
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_generate() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let host_star = &HostStarConstraints::habitable().generate(&mut rng)?;
+    trace_var!(host_star);
+    let constraints = Constraints {
+      oort_cloud_population: Some(1_000_000_000_000),
+      kuiper_belt_population: Some(1_000_000_000),
+    };
+    let comets = constraints.generate(&mut rng, host_star)?;
+    print_var!(comets.comets.len());
+    let oort_cloud_count = comets
+      .comets
+      .iter()
+      .filter(|comet| comet.reservoir == CometReservoir::OortCloud)
+      .count();
+    assert_eq!(oort_cloud_count, 100);
+    assert_eq!(comets.comets.len() - oort_cloud_count, 50);
+    assert!(comets
+      .comets
+      .iter()
+      .all(|comet| comet.reservoir == CometReservoir::KuiperBelt || comet.time_of_perihelion < EPOCH_DURATION));
+    trace_exit!();
+    Ok(())
+  }
+}
@@ -0,0 +1,36 @@
+use crate::astronomy::comet::error::Error as CometError;
+use crate::astronomy::host_star::error::Error as HostStarError;
+
+/// Comets errors.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Error {
+  /// Comet Error.
+  CometError(CometError),
+  /// Host Star Error.
+  HostStarError(HostStarError),
+}
+
+honeyholt_define_brief!(Error, |error: &Error| {
+  use Error::*;
+  match error {
+    CometError(comet_error) => format!("an error occurred in the comet ({})", honeyholt_brief!(comet_error)),
+    HostStarError(host_star_error) => format!(
+      "an error occurred in the host star ({})",
+      honeyholt_brief!(host_star_error)
+    ),
+  }
+});
+
+impl From<CometError> for Error {
+  #[named]
+  fn from(error: CometError) -> Self {
+    Error::CometError(error)
+  }
+}
+
+impl From<HostStarError> for Error {
+  #[named]
+  fn from(error: HostStarError) -> Self {
+    Error::HostStarError(error)
+  }
+}
@@ -0,0 +1,210 @@
+use crate::astronomy::comet::Comet;
+use crate::astronomy::host_star::HostStar;
+use crate::astronomy::meteor_shower::constants::MAXIMUM_PARENT_ORBITAL_PERIOD;
+use crate::astronomy::meteor_shower::orbital_node::OrbitalNode;
+use crate::astronomy::meteor_shower::MeteorShower;
+use crate::astronomy::planet::Planet;
+
+pub mod constants;
+use constants::*;
+pub mod constraints;
+pub mod error;
+
+/// `Comets` are the icy bodies left over in the outskirts of a planetary
+/// system, and the handful of them that are currently falling inward.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comets {
+  /// The number of bodies bigger than a kilometer in the Oort Cloud.
+  pub oort_cloud_population: u64,
+  /// The number of bodies bigger than a kilometer in the Kuiper Belt.
+  pub kuiper_belt_population: u64,
+  /// The comets passing through the inner system these days.
+  pub comets: Vec<Comet>,
+}
+
+impl Comets {
+  /// Retrieve or calculate the comets that will be visible to the naked eye
+  /// between the start and the end, in years from now, from a planet the
+  /// given distance in AU from a star of the given luminosity in Lsol.
+  ///
+  /// Each comet shows up once for each time it passes perihelion, along
+  /// with when, in order.
+  #[named]
+  pub fn get_visible_comets(&self, luminosity: f64, planet_distance: f64, start: f64, end: f64) -> Vec<(f64, &Comet)> {
+    trace_enter!();
+    trace_var!(luminosity);
+    trace_var!(planet_distance);
+    trace_var!(start);
+    trace_var!(end);
+    let mut result = self
+      .comets
+      .iter()
+      .filter(|comet| comet.is_visible(luminosity, planet_distance))
+      .flat_map(|comet| {
+        comet
+          .get_perihelion_passages(start, end)
+          .into_iter()
+          .map(move |time| (time, comet))
+      })
+      .collect::<Vec<(f64, &Comet)>>();
+    result.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the meteor showers the planet sees each year,
+  /// one for each node of each comet's orbit that it passes close to, in
+  /// order through the year.
+  #[named]
+  pub fn get_meteor_showers(&self, host_star: &HostStar, planet: &Planet) -> Vec<MeteorShower> {
+    trace_enter!();
+    trace_var!(host_star);
+    trace_var!(planet);
+    let mut result = self
+      .comets
+      .iter()
+      .flat_map(|comet| {
+        [OrbitalNode::Ascending, OrbitalNode::Descending]
+          .into_iter()
+          .filter_map(move |orbital_node| MeteorShower::from_comet(comet, orbital_node, host_star, planet).ok())
+      })
+      .collect::<Vec<MeteorShower>>();
+    result.sort_by(|a, b| a.date.partial_cmp(&b.date).unwrap());
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the hourly rate of sporadic meteors, the ones
+  /// that don't belong to any shower, seen from a planet the given distance
+  /// in AU from a star of the given luminosity in Lsol.
+  ///
+  /// Old streams get scattered into a haze of dust that fills the inner
+  /// system, so it's about as thick as the periodic comets are active.
+  #[named]
+  pub fn get_sporadic_hourly_rate(&self, luminosity: f64, planet_distance: f64) -> f64 {
+    trace_enter!();
+    trace_var!(luminosity);
+    trace_var!(planet_distance);
+    let active_area = self
+      .comets
+      .iter()
+      .filter(|comet| comet.orbital_period <= MAXIMUM_PARENT_ORBITAL_PERIOD)
+      .map(|comet| comet.activity * comet.nucleus_radius.powf(2.0))
+      .sum::<f64>();
+    trace_var!(active_area);
+    let effective_distance = planet_distance / luminosity.sqrt();
+    trace_var!(effective_distance);
+    let result = SPORADIC_HOURLY_RATE_PER_ACTIVE_AREA * active_area * effective_distance.powf(-DUST_DENSITY_INDEX);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::astronomy::comet::constraints::Constraints as CometConstraints;
+  use crate::astronomy::comets::constraints::Constraints;
+  use crate::astronomy::host_star::constraints::Constraints as HostStarConstraints;
+  use crate::astronomy::terrestrial_planet::TerrestrialPlanet;
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::astronomy::comets::error::Error;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_visible_comets() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let host_star = &HostStarConstraints::habitable().generate(&mut rng)?;
+    trace_var!(host_star);
+    let luminosity = host_star.get_luminosity();
+    let planet_distance = luminosity.sqrt();
+    // A big, lively one that comes close.
+    let great_comet = CometConstraints {
+      minimum_perihelion: Some(planet_distance),
+      maximum_perihelion: Some(planet_distance),
+      minimum_nucleus_radius: Some(10.0),
+      maximum_nucleus_radius: Some(10.0),
+      minimum_activity: Some(0.5),
+      maximum_activity: Some(0.5),
+      time_of_perihelion: Some(2.0),
+      ..CometConstraints::kuiper_belt()
+    }
+    .generate(&mut rng, host_star)?;
+    // A small, crusty one that stays out past the belt.
+    let faint_comet = CometConstraints {
+      minimum_perihelion: Some(planet_distance * 3.0),
+      maximum_perihelion: Some(planet_distance * 3.0),
+      minimum_nucleus_radius: Some(1.0),
+      maximum_nucleus_radius: Some(1.0),
+      minimum_activity: Some(0.01),
+      maximum_activity: Some(0.01),
+      time_of_perihelion: Some(1.0),
+      ..CometConstraints::kuiper_belt()
+    }
+    .generate(&mut rng, host_star)?;
+    let comets = Comets {
+      oort_cloud_population: 0,
+      kuiper_belt_population: 0,
+      comets: vec![faint_comet, great_comet.clone()],
+    };
+    let end = 2.0 + great_comet.orbital_period * 2.5;
+    let visible_comets = comets.get_visible_comets(luminosity, planet_distance, 2.0, end);
+    print_var!(visible_comets);
+    assert_eq!(visible_comets.len(), 3);
+    assert!(visible_comets.iter().all(|(_, comet)| **comet == great_comet));
+    assert_approx_eq!(visible_comets[0].0, 2.0);
+    assert_approx_eq!(visible_comets[2].0, 2.0 + great_comet.orbital_period * 2.0);
+    // Nothing between passes.
+    assert!(comets
+      .get_visible_comets(
+        luminosity,
+        planet_distance,
+        2.0 + great_comet.orbital_period * 0.1,
+        2.0 + great_comet.orbital_period * 0.9
+      )
+      .is_empty());
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_get_meteor_showers() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let host_star = &HostStarConstraints::habitable().generate(&mut rng)?;
+    trace_var!(host_star);
+    let luminosity = host_star.get_luminosity();
+    let mut terrestrial_planet = TerrestrialPlanet::from_mass(1.0).unwrap();
+    terrestrial_planet.semi_major_axis = luminosity.sqrt();
+    let planet = &Planet::TerrestrialPlanet(terrestrial_planet);
+    let constraints = Constraints {
+      kuiper_belt_population: Some(10_000_000_000),
+      ..Constraints::default()
+    };
+    let comets = constraints.generate(&mut rng, host_star)?;
+    let meteor_showers = comets.get_meteor_showers(host_star, planet);
+    print_var!(meteor_showers);
+    assert!(meteor_showers.windows(2).all(|pair| pair[0].date <= pair[1].date));
+    assert!(meteor_showers.iter().all(|meteor_shower| meteor_shower.date >= 0.0
+      && meteor_shower.date < planet.get_orbital_period()
+      && meteor_shower.zenithal_hourly_rate > 0.0));
+    let sporadic_hourly_rate = comets.get_sporadic_hourly_rate(luminosity, planet.get_semi_major_axis());
+    print_var!(sporadic_hourly_rate);
+    assert!(sporadic_hourly_rate > 0.0);
+    assert!(comets.get_sporadic_hourly_rate(luminosity, planet.get_semi_major_axis() * 2.0) < sporadic_hourly_rate);
+    trace_exit!();
+    Ok(())
+  }
+}
@@ -5,6 +5,8 @@ use crate::astronomy::distant_binary_star::constants::*;
 use crate::astronomy::distant_binary_star::error::Error;
 use crate::astronomy::distant_binary_star::DistantBinaryStar;
 use crate::astronomy::planetary_system::constraints::Constraints as PlanetarySystemConstraints;
+use crate::astronomy::star::constants::MEAN_METALLICITY;
+use crate::astronomy::star::math::metallicity::get_random_metallicity;
 
 /// Constraints for creating a main-sequence star subsystem.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -17,6 +19,12 @@ pub struct Constraints {
   pub minimum_orbital_eccentricity: Option<f64>,
   /// The maximum orbital eccentricity.
   pub maximum_orbital_eccentricity: Option<f64>,
+  /// Constraints for the primary planetary system.
+  pub primary_constraints: Option<PlanetarySystemConstraints>,
+  /// Constraints for the secondary planetary system.
+  pub secondary_constraints: Option<PlanetarySystemConstraints>,
+  /// The metallicity ([Fe/H]) shared by both planetary systems.
+  pub metallicity: Option<f64>,
 }
 
 impl Constraints {
@@ -36,11 +44,25 @@ impl Constraints {
   #[named]
   pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<DistantBinaryStar, Error> {
     trace_enter!();
-    let primary_constraints = PlanetarySystemConstraints::default();
+    // Both systems formed from the same cloud, so they share a metallicity.
+    let metallicity = match self.metallicity {
+      Some(metallicity) => metallicity,
+      None => get_random_metallicity(rng, MEAN_METALLICITY),
+    };
+    trace_var!(metallicity);
+    let primary_constraints = self.primary_constraints.unwrap_or_default();
+    let primary_constraints = PlanetarySystemConstraints {
+      metallicity: Some(metallicity),
+      ..primary_constraints
+    };
     trace_var!(primary_constraints);
     let primary = primary_constraints.generate(rng)?;
     trace_var!(primary);
-    let secondary_constraints = PlanetarySystemConstraints::default();
+    let secondary_constraints = self.secondary_constraints.unwrap_or_default();
+    let secondary_constraints = PlanetarySystemConstraints {
+      metallicity: Some(metallicity),
+      ..secondary_constraints
+    };
     trace_var!(secondary_constraints);
     let secondary = secondary_constraints.generate(rng)?;
     trace_var!(secondary);
@@ -58,11 +80,17 @@ impl Default for Constraints {
     let maximum_average_separation = Some(MAXIMUM_AVERAGE_SEPARATION);
     let minimum_orbital_eccentricity = Some(MINIMUM_ORBITAL_ECCENTRICITY);
     let maximum_orbital_eccentricity = Some(MAXIMUM_ORBITAL_ECCENTRICITY);
+    let primary_constraints = None;
+    let secondary_constraints = None;
+    let metallicity = None;
     Self {
       minimum_average_separation,
       maximum_average_separation,
       minimum_orbital_eccentricity,
       maximum_orbital_eccentricity,
+      primary_constraints,
+      secondary_constraints,
+      metallicity,
     }
   }
 }
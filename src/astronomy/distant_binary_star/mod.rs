@@ -1,4 +1,5 @@
 use crate::astronomy::planetary_system::PlanetarySystem;
+use crate::astronomy::star::math::magnitude::combine_magnitudes;
 
 pub mod constants;
 pub mod constraints;
@@ -62,6 +63,20 @@ impl DistantBinaryStar {
     result
   }
 
+  /// Retrieve or calculate the absolute visual (V band) magnitude of the
+  /// stars together.
+  #[named]
+  pub fn get_absolute_visual_magnitude(&self) -> f64 {
+    trace_enter!();
+    let result = combine_magnitudes(
+      self.primary.get_absolute_visual_magnitude(),
+      self.secondary.get_absolute_visual_magnitude(),
+    );
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
   /// Retrieve or calculate the total number of stars in the system.
   #[named]
   pub fn get_stellar_count(&self) -> u8 {
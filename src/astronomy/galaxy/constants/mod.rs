@@ -1 +1,139 @@
+/// The radius of the stellar disk.
+///
+/// Measured in Ly, or light years.
+pub const GALAXY_RADIUS: f64 = 50_000.0;
 
+/// The distance of the Sun from the galactic center.
+///
+/// This is where the local stellar density is "normal".
+///
+/// Measured in Ly, or light years.
+pub const SOLAR_GALACTOCENTRIC_RADIUS: f64 = 26_700.0;
+
+/// The minimum scale radius of the central bulge.
+///
+/// Measured in Ly, or light years.
+pub const MINIMUM_BULGE_RADIUS: f64 = 2_500.0;
+
+/// The maximum scale radius of the central bulge.
+///
+/// Measured in Ly, or light years.
+pub const MAXIMUM_BULGE_RADIUS: f64 = 4_000.0;
+
+/// The stellar density at the very center of the bulge.
+///
+/// Measured in s/ly^3, or stars per cubic light year.
+pub const BULGE_CENTRAL_DENSITY: f64 = 0.3;
+
+/// The minimum scale length of the disk.
+///
+/// Measured in Ly, or light years.
+pub const MINIMUM_DISK_SCALE_LENGTH: f64 = 7_500.0;
+
+/// The maximum scale length of the disk.
+///
+/// Measured in Ly, or light years.
+pub const MAXIMUM_DISK_SCALE_LENGTH: f64 = 10_000.0;
+
+/// The minimum scale height of the (thin) disk.
+///
+/// Measured in Ly, or light years.
+pub const MINIMUM_DISK_SCALE_HEIGHT: f64 = 800.0;
+
+/// The maximum scale height of the (thin) disk.
+///
+/// Measured in Ly, or light years.
+pub const MAXIMUM_DISK_SCALE_HEIGHT: f64 = 1_200.0;
+
+/// The minimum number of spiral arms.
+pub const MINIMUM_ARM_COUNT: u8 = 2;
+
+/// The maximum number of spiral arms.
+pub const MAXIMUM_ARM_COUNT: u8 = 4;
+
+/// The minimum pitch angle of the spiral arms.
+///
+/// Measured in degrees.
+pub const MINIMUM_ARM_PITCH_ANGLE: f64 = 10.0;
+
+/// The maximum pitch angle of the spiral arms.
+///
+/// Measured in degrees.
+pub const MAXIMUM_ARM_PITCH_ANGLE: f64 = 25.0;
+
+/// The half-width of a spiral arm.
+///
+/// Measured in Ly, or light years.
+pub const ARM_WIDTH: f64 = 1_500.0;
+
+/// How much denser a spiral arm is than the disk around it.
+///
+/// The contrast is much higher in gas and young stars, but the old stars
+/// that make up most of the disk are only slightly bunched up.
+pub const ARM_DENSITY_CONTRAST: f64 = 0.3;
+
+/// The metallicity of the disk at the Sun's galactocentric radius.
+///
+/// Measured in [Fe/H], or dex relative to the Sun.
+pub const SOLAR_RADIUS_METALLICITY: f64 = 0.0;
+
+/// The change in metallicity with galactocentric radius.
+///
+/// About -0.06 dex per kiloparsec.
+///
+/// Measured in dex/ly.
+pub const RADIAL_METALLICITY_GRADIENT: f64 = -0.000_018;
+
+/// The change in metallicity with height above the midplane.
+///
+/// About -0.3 dex per kiloparsec.
+///
+/// Measured in dex/ly.
+pub const VERTICAL_METALLICITY_GRADIENT: f64 = -0.000_09;
+
+/// The minimum metallicity anywhere in the galaxy.
+///
+/// Measured in [Fe/H].
+pub const MINIMUM_METALLICITY: f64 = -2.5;
+
+/// The maximum metallicity anywhere in the galaxy.
+///
+/// Measured in [Fe/H].
+pub const MAXIMUM_METALLICITY: f64 = 0.5;
+
+/// The minimum galactocentric radius of our stellar neighborhood.
+///
+/// Any closer in and the neighborhoods get enormous.
+///
+/// Measured in Ly, or light years.
+pub const MINIMUM_HOME_RADIUS: f64 = 15_000.0;
+
+/// The maximum galactocentric radius of our stellar neighborhood.
+///
+/// Measured in Ly, or light years.
+pub const MAXIMUM_HOME_RADIUS: f64 = 40_000.0;
+
+/// The maximum height of our stellar neighborhood above (or below) the
+/// midplane.
+///
+/// Measured in Ly, or light years.
+pub const MAXIMUM_HOME_HEIGHT: f64 = 300.0;
+
+/// The inner edge of the galactic habitable zone.
+///
+/// Closer in, supernovae and close stellar encounters are too frequent.
+///
+/// Measured in Ly, or light years.
+pub const MINIMUM_HABITABLE_HOME_RADIUS: f64 = 23_000.0;
+
+/// The outer edge of the galactic habitable zone.
+///
+/// Farther out, there aren't enough heavy elements to build planets.
+///
+/// Measured in Ly, or light years.
+pub const MAXIMUM_HABITABLE_HOME_RADIUS: f64 = 30_000.0;
+
+/// The step size used when integrating along a line of sight.
+///
+/// Measured in Ly, or light years.
+pub const LINE_OF_SIGHT_STEP: f64 = 100.0;
@@ -1,21 +1,49 @@
 use rand::prelude::*;
+use std::collections::HashMap;
 
+use crate::astronomy::galaxy::constants::*;
 use crate::astronomy::galaxy::error::*;
+use crate::astronomy::galaxy::math::seed::get_sector_seed;
 use crate::astronomy::galaxy::Galaxy;
+use crate::astronomy::stellar_neighborhood::constants::STELLAR_NEIGHBORHOOD_RADIUS;
 use crate::astronomy::stellar_neighborhood::constraints::Constraints as StellarNeighborhoodConstraints;
+use crate::astronomy::stellar_neighborhood::StellarNeighborhood;
 
 /// Constraints for creating a galaxy.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Constraints {
-  /// Any constraints placed on the various neighbors.
+  /// The seed from which every sector's seed is derived.
+  pub seed: Option<u64>,
+  /// The minimum galactocentric radius of our stellar neighborhood, in light
+  /// years.
+  pub minimum_home_radius: Option<f64>,
+  /// The maximum galactocentric radius of our stellar neighborhood, in light
+  /// years.
+  pub maximum_home_radius: Option<f64>,
+  /// The maximum height of our stellar neighborhood above (or below) the
+  /// midplane, in light years.
+  pub maximum_home_height: Option<f64>,
+  /// The minimum number of spiral arms.
+  pub minimum_arm_count: Option<u8>,
+  /// The maximum number of spiral arms.
+  pub maximum_arm_count: Option<u8>,
+  /// Any constraints placed on our own stellar neighborhood.
   pub stellar_neighborhood_constraints: Option<StellarNeighborhoodConstraints>,
+  /// Any constraints placed on other sectors.
+  pub sector_constraints: Option<StellarNeighborhoodConstraints>,
 }
 
 impl Constraints {
   /// Generate a habitable galaxy.
+  ///
+  /// This places our neighborhood within the galactic habitable zone.
   pub fn habitable() -> Self {
+    let minimum_home_radius = Some(MINIMUM_HABITABLE_HOME_RADIUS);
+    let maximum_home_radius = Some(MAXIMUM_HABITABLE_HOME_RADIUS);
     let stellar_neighborhood_constraints = Some(StellarNeighborhoodConstraints::habitable());
     Self {
+      minimum_home_radius,
+      maximum_home_radius,
       stellar_neighborhood_constraints,
       ..Constraints::default()
     }
@@ -27,12 +55,68 @@ impl Constraints {
   #[named]
   pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<Galaxy, Error> {
     trace_enter!();
-    let stellar_neighborhood_constraints = self
-      .stellar_neighborhood_constraints
-      .unwrap_or(StellarNeighborhoodConstraints::default());
+    let seed = self.seed.unwrap_or_else(|| rng.gen());
+    trace_var!(seed);
+    let minimum_home_radius = self.minimum_home_radius.unwrap_or(MINIMUM_HOME_RADIUS);
+    trace_var!(minimum_home_radius);
+    let maximum_home_radius = self.maximum_home_radius.unwrap_or(MAXIMUM_HOME_RADIUS);
+    trace_var!(maximum_home_radius);
+    let maximum_home_height = self.maximum_home_height.unwrap_or(MAXIMUM_HOME_HEIGHT);
+    trace_var!(maximum_home_height);
+    let minimum_arm_count = self.minimum_arm_count.unwrap_or(MINIMUM_ARM_COUNT);
+    trace_u8!(minimum_arm_count);
+    let maximum_arm_count = self.maximum_arm_count.unwrap_or(MAXIMUM_ARM_COUNT);
+    trace_u8!(maximum_arm_count);
+    let radius = GALAXY_RADIUS;
+    trace_var!(radius);
+    let bulge_radius = rng.gen_range(MINIMUM_BULGE_RADIUS..MAXIMUM_BULGE_RADIUS);
+    trace_var!(bulge_radius);
+    let disk_scale_length = rng.gen_range(MINIMUM_DISK_SCALE_LENGTH..MAXIMUM_DISK_SCALE_LENGTH);
+    trace_var!(disk_scale_length);
+    let disk_scale_height = rng.gen_range(MINIMUM_DISK_SCALE_HEIGHT..MAXIMUM_DISK_SCALE_HEIGHT);
+    trace_var!(disk_scale_height);
+    let arm_count = rng.gen_range(minimum_arm_count..=maximum_arm_count);
+    trace_u8!(arm_count);
+    let arm_pitch_angle = rng.gen_range(MINIMUM_ARM_PITCH_ANGLE..MAXIMUM_ARM_PITCH_ANGLE);
+    trace_var!(arm_pitch_angle);
+    let arm_offset = rng.gen_range(0.0..360.0);
+    trace_var!(arm_offset);
+    let home_radius = rng.gen_range(minimum_home_radius..maximum_home_radius);
+    trace_var!(home_radius);
+    let home_azimuth = rng.gen_range(0.0..360.0);
+    trace_var!(home_azimuth);
+    let home_height = rng.gen_range(-maximum_home_height..=maximum_home_height);
+    trace_var!(home_height);
+    let stellar_neighborhood_constraints = self.stellar_neighborhood_constraints.unwrap_or_default();
     trace_var!(stellar_neighborhood_constraints);
-    let stellar_neighborhood = stellar_neighborhood_constraints.generate(rng)?;
-    let result = Galaxy { stellar_neighborhood };
+    let sector_size = 2.0
+      * stellar_neighborhood_constraints
+        .radius
+        .unwrap_or(STELLAR_NEIGHBORHOOD_RADIUS);
+    trace_var!(sector_size);
+    let sector_constraints = self.sector_constraints.unwrap_or_default();
+    trace_var!(sector_constraints);
+    let mut result = Galaxy {
+      seed,
+      radius,
+      bulge_radius,
+      disk_scale_length,
+      disk_scale_height,
+      arm_count,
+      arm_pitch_angle,
+      arm_offset,
+      home_radius,
+      home_azimuth,
+      home_height,
+      sector_size,
+      sector_constraints,
+      stellar_neighborhood: StellarNeighborhood::default(),
+      sectors: HashMap::new(),
+    };
+    let home_constraints = result.get_sector_constraints(stellar_neighborhood_constraints, (0, 0, 0));
+    trace_var!(home_constraints);
+    let mut home_rng = StdRng::seed_from_u64(get_sector_seed(seed, (0, 0, 0)));
+    result.stellar_neighborhood = home_constraints.generate(&mut home_rng)?;
     trace_var!(result);
     trace_exit!();
     Ok(result)
@@ -42,9 +126,23 @@ impl Constraints {
 impl Default for Constraints {
   /// No constraints, just let it all hang out.
   fn default() -> Self {
+    let seed = None;
+    let minimum_home_radius = Some(MINIMUM_HOME_RADIUS);
+    let maximum_home_radius = Some(MAXIMUM_HOME_RADIUS);
+    let maximum_home_height = Some(MAXIMUM_HOME_HEIGHT);
+    let minimum_arm_count = Some(MINIMUM_ARM_COUNT);
+    let maximum_arm_count = Some(MAXIMUM_ARM_COUNT);
     let stellar_neighborhood_constraints = Some(StellarNeighborhoodConstraints::default());
+    let sector_constraints = Some(StellarNeighborhoodConstraints::default());
     Self {
+      seed,
+      minimum_home_radius,
+      maximum_home_radius,
+      maximum_home_height,
+      minimum_arm_count,
+      maximum_arm_count,
       stellar_neighborhood_constraints,
+      sector_constraints,
     }
   }
 }
@@ -67,6 +165,10 @@ pub mod test {
     let constraints = Constraints::habitable();
     let galaxy = constraints.generate(&mut rng)?;
     info_var!(galaxy);
+    assert!(galaxy.home_radius >= MINIMUM_HABITABLE_HOME_RADIUS);
+    assert!(galaxy.home_radius < MAXIMUM_HABITABLE_HOME_RADIUS);
+    assert_approx_eq!(galaxy.stellar_neighborhood.density, galaxy.get_home_density());
+    assert_approx_eq!(galaxy.stellar_neighborhood.metallicity, galaxy.get_home_metallicity());
     print_var!(galaxy);
     trace_exit!();
     Ok(())
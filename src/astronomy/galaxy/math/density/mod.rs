@@ -0,0 +1,81 @@
+use crate::astronomy::galaxy::constants::*;
+use crate::astronomy::stellar_neighborhood::constants::STELLAR_NEIGHBORHOOD_DENSITY;
+
+/// Get the stellar density of the disk at a position in the galaxy.
+///
+/// The disk falls off exponentially both with galactocentric radius and with
+/// height above the midplane, and is normalized so that it matches our usual
+/// neighborhood density at the Sun's distance from the center.
+///
+/// Radius, height, and scales are measured in light years; the result is
+/// measured in stars per cubic light year.
+#[named]
+pub fn get_disk_density(radius: f64, height: f64, scale_length: f64, scale_height: f64) -> f64 {
+  trace_enter!();
+  trace_var!(radius);
+  trace_var!(height);
+  trace_var!(scale_length);
+  trace_var!(scale_height);
+  let result = match radius > GALAXY_RADIUS {
+    true => 0.0,
+    false => {
+      STELLAR_NEIGHBORHOOD_DENSITY
+        * (-(radius - SOLAR_GALACTOCENTRIC_RADIUS) / scale_length).exp()
+        * (-height.abs() / scale_height).exp()
+    },
+  };
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the stellar density of the bulge at a position in the galaxy.
+///
+/// The bulge is treated as a Gaussian blob, so it dominates the center and
+/// vanishes quickly outside it.
+///
+/// Radius, height, and bulge radius are measured in light years; the result
+/// is measured in stars per cubic light year.
+#[named]
+pub fn get_bulge_density(radius: f64, height: f64, bulge_radius: f64) -> f64 {
+  trace_enter!();
+  trace_var!(radius);
+  trace_var!(height);
+  trace_var!(bulge_radius);
+  let distance = (radius.powf(2.0) + height.powf(2.0)).sqrt();
+  trace_var!(distance);
+  let result = BULGE_CENTRAL_DENSITY * (-(distance / bulge_radius).powf(2.0)).exp();
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_disk_density() {
+    init();
+    trace_enter!();
+    let local = get_disk_density(SOLAR_GALACTOCENTRIC_RADIUS, 0.0, 8_500.0, 1_000.0);
+    assert_approx_eq!(local, STELLAR_NEIGHBORHOOD_DENSITY);
+    assert!(get_disk_density(10_000.0, 0.0, 8_500.0, 1_000.0) > local);
+    assert!(get_disk_density(SOLAR_GALACTOCENTRIC_RADIUS, 1_000.0, 8_500.0, 1_000.0) < local);
+    assert_approx_eq!(get_disk_density(2.0 * GALAXY_RADIUS, 0.0, 8_500.0, 1_000.0), 0.0);
+    trace_exit!();
+  }
+
+  #[named]
+  #[test]
+  pub fn test_get_bulge_density() {
+    init();
+    trace_enter!();
+    assert_approx_eq!(get_bulge_density(0.0, 0.0, 3_000.0), BULGE_CENTRAL_DENSITY);
+    assert!(get_bulge_density(SOLAR_GALACTOCENTRIC_RADIUS, 0.0, 3_000.0) < 1.0e-20);
+    trace_exit!();
+  }
+}
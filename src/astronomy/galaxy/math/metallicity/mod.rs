@@ -0,0 +1,50 @@
+use crate::astronomy::galaxy::constants::*;
+
+/// Get the typical metallicity at a position in the galaxy.
+///
+/// Metallicity falls off gently with galactocentric radius and more steeply
+/// with height above the midplane; the outer disk and the halo are where the
+/// metal-poor stars live.
+///
+/// Radius and height are measured in light years; the result is measured in
+/// [Fe/H].
+#[named]
+pub fn get_metallicity(radius: f64, height: f64) -> f64 {
+  trace_enter!();
+  trace_var!(radius);
+  trace_var!(height);
+  let result = (SOLAR_RADIUS_METALLICITY
+    + RADIAL_METALLICITY_GRADIENT * (radius - SOLAR_GALACTOCENTRIC_RADIUS)
+    + VERTICAL_METALLICITY_GRADIENT * height.abs())
+  .clamp(MINIMUM_METALLICITY, MAXIMUM_METALLICITY);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_metallicity() {
+    init();
+    trace_enter!();
+    assert_approx_eq!(
+      get_metallicity(SOLAR_GALACTOCENTRIC_RADIUS, 0.0),
+      SOLAR_RADIUS_METALLICITY
+    );
+    assert!(get_metallicity(15_000.0, 0.0) > 0.0);
+    assert!(get_metallicity(40_000.0, 0.0) < 0.0);
+    assert!(get_metallicity(SOLAR_GALACTOCENTRIC_RADIUS, 3_000.0) < -0.2);
+    assert!(get_metallicity(0.0, 0.0) <= MAXIMUM_METALLICITY);
+    assert_approx_eq!(
+      get_metallicity(SOLAR_GALACTOCENTRIC_RADIUS, 50_000.0),
+      MINIMUM_METALLICITY
+    );
+    trace_exit!();
+  }
+}
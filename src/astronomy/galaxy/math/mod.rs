@@ -0,0 +1,4 @@
+pub mod density;
+pub mod metallicity;
+pub mod seed;
+pub mod spiral_arm;
@@ -0,0 +1,50 @@
+/// Scramble a 64-bit value.
+///
+/// This is the finalizer from SplitMix64; it's cheap, and nearby inputs give
+/// wildly different outputs, which is exactly what we want for seeds.
+fn mix(value: u64) -> u64 {
+  let mut result = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+  result = (result ^ (result >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+  result = (result ^ (result >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+  result ^ (result >> 31)
+}
+
+/// Get the seed for a sector from the galaxy's seed and the sector's
+/// coordinates.
+///
+/// The same galaxy seed and coordinates always give the same sector seed, so
+/// a sector comes out the same no matter when (or whether) its neighbors are
+/// generated.
+#[named]
+pub fn get_sector_seed(seed: u64, coordinates: (i64, i64, i64)) -> u64 {
+  trace_enter!();
+  trace_var!(seed);
+  trace_var!(coordinates);
+  let result = [coordinates.0, coordinates.1, coordinates.2]
+    .iter()
+    .fold(mix(seed), |hash, &coordinate| mix(hash ^ coordinate as u64));
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_sector_seed() {
+    init();
+    trace_enter!();
+    let seed = get_sector_seed(42, (1, -2, 3));
+    print_var!(seed);
+    assert_eq!(seed, get_sector_seed(42, (1, -2, 3)));
+    assert_ne!(seed, get_sector_seed(43, (1, -2, 3)));
+    assert_ne!(seed, get_sector_seed(42, (-2, 1, 3)));
+    assert_ne!(get_sector_seed(42, (0, 0, 1)), get_sector_seed(42, (0, 1, 0)));
+    trace_exit!();
+  }
+}
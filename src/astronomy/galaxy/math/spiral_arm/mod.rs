@@ -0,0 +1,70 @@
+use std::f64::consts::PI;
+
+use crate::astronomy::galaxy::constants::*;
+
+/// Get the distance from a position in the disk to the nearest spiral arm.
+///
+/// Each arm is a logarithmic spiral, `azimuth = ln(radius / scale) / tan(pitch)
+/// + offset`, and the arms are evenly spaced around the center.  We measure
+/// the azimuthal gap to the nearest arm and convert it to a distance across
+/// the arm.
+///
+/// Radius and result are measured in light years; azimuth, pitch angle, and
+/// offset in degrees.
+#[named]
+pub fn get_distance_to_arm(radius: f64, azimuth: f64, arm_count: u8, pitch_angle: f64, offset: f64) -> f64 {
+  trace_enter!();
+  trace_var!(radius);
+  trace_var!(azimuth);
+  trace_u8!(arm_count);
+  trace_var!(pitch_angle);
+  trace_var!(offset);
+  let pitch_angle = pitch_angle.to_radians();
+  let arm_azimuth = (radius.max(1.0) / SOLAR_GALACTOCENTRIC_RADIUS).ln() / pitch_angle.tan() + offset.to_radians();
+  trace_var!(arm_azimuth);
+  let spacing = 2.0 * PI / arm_count as f64;
+  trace_var!(spacing);
+  let gap = (azimuth.to_radians() - arm_azimuth).rem_euclid(spacing);
+  let gap = gap.min(spacing - gap);
+  trace_var!(gap);
+  let result = radius * gap * pitch_angle.sin();
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the factor by which a spiral arm enhances the local density.
+///
+/// Distance is measured in light years.
+#[named]
+pub fn get_arm_density_factor(distance: f64) -> f64 {
+  trace_enter!();
+  trace_var!(distance);
+  let result = 1.0 + ARM_DENSITY_CONTRAST * (-(distance / ARM_WIDTH).powf(2.0)).exp();
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_distance_to_arm() {
+    init();
+    trace_enter!();
+    let on_arm = get_distance_to_arm(SOLAR_GALACTOCENTRIC_RADIUS, 30.0, 2, 12.0, 30.0);
+    assert_approx_eq!(on_arm, 0.0);
+    assert_approx_eq!(get_arm_density_factor(on_arm), 1.0 + ARM_DENSITY_CONTRAST);
+    let other_arm = get_distance_to_arm(SOLAR_GALACTOCENTRIC_RADIUS, 210.0, 2, 12.0, 30.0);
+    assert_approx_eq!(other_arm, 0.0);
+    let between = get_distance_to_arm(SOLAR_GALACTOCENTRIC_RADIUS, 120.0, 2, 12.0, 30.0);
+    assert!(between > 3.0 * ARM_WIDTH);
+    assert!(get_arm_density_factor(between) < 1.01);
+    trace_exit!();
+  }
+}
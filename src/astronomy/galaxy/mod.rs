@@ -1,14 +1,465 @@
+use rand::prelude::*;
+use std::collections::HashMap;
+
+use crate::astronomy::stellar_neighborhood::constraints::Constraints as StellarNeighborhoodConstraints;
 use crate::astronomy::stellar_neighborhood::StellarNeighborhood;
 
 pub mod constants;
+use constants::*;
 pub mod constraints;
 pub mod error;
+use error::Error;
+pub mod math;
+use math::density::{get_bulge_density, get_disk_density};
+use math::metallicity::get_metallicity;
+use math::seed::get_sector_seed;
+use math::spiral_arm::{get_arm_density_factor, get_distance_to_arm};
 
 /// A `Galaxy` is the "outermost" or largest-scale object.
 ///
-/// It's a wrapper around `StellarNeighborhood`.
+/// It's a spiral galaxy: a central bulge surrounded by an exponential disk,
+/// with a few logarithmic spiral arms winding through it.  Somewhere in that
+/// disk sits our stellar neighborhood.
+///
+/// Positions within the galaxy are given in galactocentric cylindrical
+/// coordinates: a radius from the center, an azimuth, and a height above the
+/// midplane.
+///
+/// The stellar neighborhood's own coordinates are oriented to match: x points
+/// toward the galactic center, y in the direction of rotation, and z toward
+/// the north galactic pole.  So sky positions in the neighborhood are in
+/// galactic longitude and latitude.
+///
+/// Beyond our own neighborhood, space is divided into a grid of cubic
+/// sectors, each of which is a stellar neighborhood in its own right.  Our
+/// neighborhood is the sector at (0, 0, 0); the others are generated only
+/// when someone asks for them, from a seed derived from their coordinates.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Galaxy {
-  /// This might be plural someday.  For now, we don't care.
+  /// The seed from which every sector's seed is derived.
+  pub seed: u64,
+  /// The radius of the stellar disk, in light years.
+  pub radius: f64,
+  /// The scale radius of the central bulge, in light years.
+  pub bulge_radius: f64,
+  /// The scale length of the disk, in light years.
+  pub disk_scale_length: f64,
+  /// The scale height of the disk, in light years.
+  pub disk_scale_height: f64,
+  /// The number of spiral arms.
+  pub arm_count: u8,
+  /// The pitch angle of the spiral arms, in degrees.
+  pub arm_pitch_angle: f64,
+  /// The azimuth at which the first arm crosses the Sun's galactocentric
+  /// radius, in degrees.
+  pub arm_offset: f64,
+  /// The galactocentric radius of our stellar neighborhood, in light years.
+  pub home_radius: f64,
+  /// The azimuth of our stellar neighborhood, in degrees.
+  pub home_azimuth: f64,
+  /// The height of our stellar neighborhood above the midplane, in light
+  /// years.
+  pub home_height: f64,
+  /// The length of the edge of a sector, in light years.
+  pub sector_size: f64,
+  /// The constraints used to generate sectors other than our own.
+  pub sector_constraints: StellarNeighborhoodConstraints,
+  /// Our own stellar neighborhood, which is also the sector at (0, 0, 0).
   pub stellar_neighborhood: StellarNeighborhood,
+  /// Any other sectors that have been generated so far, by coordinates.
+  pub sectors: HashMap<(i64, i64, i64), StellarNeighborhood>,
+}
+
+impl Galaxy {
+  /// Calculate the galactocentric position of a point near our neighborhood.
+  ///
+  /// The point is given in our neighborhood's frame, in light years.
+  ///
+  /// Returns (radius, azimuth, height); radius and height are measured in
+  /// light years and azimuth in degrees.
+  #[named]
+  pub fn get_galactocentric_position(&self, point: (f64, f64, f64)) -> (f64, f64, f64) {
+    trace_enter!();
+    trace_var!(point);
+    let home_azimuth = self.home_azimuth.to_radians();
+    let (cos, sin) = (home_azimuth.cos(), home_azimuth.sin());
+    // x points toward the center, y in the direction of rotation.
+    let x = self.home_radius * cos - point.0 * cos - point.1 * sin;
+    let y = self.home_radius * sin - point.0 * sin + point.1 * cos;
+    let height = self.home_height + point.2;
+    let radius = (x.powf(2.0) + y.powf(2.0)).sqrt();
+    let azimuth = y.atan2(x).to_degrees().rem_euclid(360.0);
+    let result = (radius, azimuth, height);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Calculate the coordinates of the sector containing a point.
+  ///
+  /// The point is given in our neighborhood's frame, in light years.
+  #[named]
+  pub fn get_sector_coordinates(&self, point: (f64, f64, f64)) -> (i64, i64, i64) {
+    trace_enter!();
+    trace_var!(point);
+    let get_index = |value: f64| (value / self.sector_size + 0.5).floor() as i64;
+    let result = (get_index(point.0), get_index(point.1), get_index(point.2));
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Calculate the center of a sector, in our neighborhood's frame.
+  ///
+  /// Each coordinate is measured in light years.
+  #[named]
+  pub fn get_sector_center(&self, coordinates: (i64, i64, i64)) -> (f64, f64, f64) {
+    trace_enter!();
+    trace_var!(coordinates);
+    let result = (
+      coordinates.0 as f64 * self.sector_size,
+      coordinates.1 as f64 * self.sector_size,
+      coordinates.2 as f64 * self.sector_size,
+    );
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Adapt stellar neighborhood constraints to a particular sector.
+  ///
+  /// This sets the sector's shape and position, and the density and
+  /// metallicity appropriate to its place in the galaxy.
+  #[named]
+  pub fn get_sector_constraints(
+    &self,
+    constraints: StellarNeighborhoodConstraints,
+    coordinates: (i64, i64, i64),
+  ) -> StellarNeighborhoodConstraints {
+    trace_enter!();
+    trace_var!(coordinates);
+    let center = self.get_sector_center(coordinates);
+    trace_var!(center);
+    let (radius, azimuth, height) = self.get_galactocentric_position(center);
+    let result = StellarNeighborhoodConstraints {
+      center: Some(center),
+      sector_size: Some(self.sector_size),
+      density: Some(self.get_density_at(radius, azimuth, height)),
+      metallicity: Some(self.get_metallicity_at(radius, height)),
+      ..constraints
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Generate a sector from scratch.
+  ///
+  /// This doesn't touch any other sectors, and always gives the same result
+  /// for the same coordinates.  Our own neighborhood, at (0, 0, 0), may have
+  /// been generated with different constraints, so this just returns a copy
+  /// of it.
+  #[named]
+  pub fn generate_sector(&self, coordinates: (i64, i64, i64)) -> Result<StellarNeighborhood, Error> {
+    trace_enter!();
+    trace_var!(coordinates);
+    if coordinates == (0, 0, 0) {
+      return Ok(self.stellar_neighborhood.clone());
+    }
+    let constraints = self.get_sector_constraints(self.sector_constraints, coordinates);
+    trace_var!(constraints);
+    let mut rng = StdRng::seed_from_u64(get_sector_seed(self.seed, coordinates));
+    let result = constraints.generate(&mut rng)?;
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Retrieve a sector, generating it if it hasn't been generated yet.
+  #[named]
+  pub fn get_sector(&mut self, coordinates: (i64, i64, i64)) -> Result<&StellarNeighborhood, Error> {
+    trace_enter!();
+    trace_var!(coordinates);
+    if coordinates == (0, 0, 0) {
+      return Ok(&self.stellar_neighborhood);
+    }
+    if !self.sectors.contains_key(&coordinates) {
+      let sector = self.generate_sector(coordinates)?;
+      self.sectors.insert(coordinates, sector);
+    }
+    let result = &self.sectors[&coordinates];
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Retrieve or calculate the stellar density at a position.
+  ///
+  /// Radius and height are measured in light years, azimuth in degrees, and
+  /// the result in stars per cubic light year.
+  #[named]
+  pub fn get_density_at(&self, radius: f64, azimuth: f64, height: f64) -> f64 {
+    trace_enter!();
+    trace_var!(radius);
+    trace_var!(azimuth);
+    trace_var!(height);
+    let disk_density = match radius > self.radius {
+      true => 0.0,
+      false => get_disk_density(radius, height, self.disk_scale_length, self.disk_scale_height),
+    };
+    trace_var!(disk_density);
+    let distance_to_arm = get_distance_to_arm(radius, azimuth, self.arm_count, self.arm_pitch_angle, self.arm_offset);
+    trace_var!(distance_to_arm);
+    let bulge_density = get_bulge_density(radius, height, self.bulge_radius);
+    trace_var!(bulge_density);
+    let result = disk_density * get_arm_density_factor(distance_to_arm) + bulge_density;
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the typical metallicity at a position.
+  ///
+  /// Radius and height are measured in light years; the result in [Fe/H].
+  #[named]
+  pub fn get_metallicity_at(&self, radius: f64, height: f64) -> f64 {
+    trace_enter!();
+    trace_var!(radius);
+    trace_var!(height);
+    let result = get_metallicity(radius, height);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the stellar density around our neighborhood.
+  ///
+  /// Measured in stars per cubic light year.
+  #[named]
+  pub fn get_home_density(&self) -> f64 {
+    trace_enter!();
+    let result = self.get_density_at(self.home_radius, self.home_azimuth, self.home_height);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the typical metallicity around our neighborhood.
+  ///
+  /// Measured in [Fe/H].
+  #[named]
+  pub fn get_home_metallicity(&self) -> f64 {
+    trace_enter!();
+    let result = self.get_metallicity_at(self.home_radius, self.home_height);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Indicate whether our neighborhood lies within a spiral arm.
+  #[named]
+  pub fn is_home_in_spiral_arm(&self) -> bool {
+    trace_enter!();
+    let distance_to_arm = get_distance_to_arm(
+      self.home_radius,
+      self.home_azimuth,
+      self.arm_count,
+      self.arm_pitch_angle,
+      self.arm_offset,
+    );
+    trace_var!(distance_to_arm);
+    let result = distance_to_arm <= ARM_WIDTH;
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Calculate the brightness of the galaxy in a direction in the local sky.
+  ///
+  /// This is the number of stars along the line of sight, per square light
+  /// year; it's proportional to the surface brightness of the galactic band,
+  /// ignoring dust.
+  ///
+  /// Longitude and latitude are measured in degrees.
+  #[named]
+  pub fn get_galactic_band_brightness(&self, longitude: f64, latitude: f64) -> f64 {
+    trace_enter!();
+    trace_var!(longitude);
+    trace_var!(latitude);
+    let (longitude, latitude) = (longitude.to_radians(), latitude.to_radians());
+    let direction = (
+      latitude.cos() * longitude.cos(),
+      latitude.cos() * longitude.sin(),
+      latitude.sin(),
+    );
+    trace_var!(direction);
+    let steps = (2.0 * self.radius / LINE_OF_SIGHT_STEP) as usize;
+    let result = (0..steps)
+      .map(|step| {
+        let distance = (step as f64 + 0.5) * LINE_OF_SIGHT_STEP;
+        let point = (distance * direction.0, distance * direction.1, distance * direction.2);
+        let (radius, azimuth, height) = self.get_galactocentric_position(point);
+        self.get_density_at(radius, azimuth, height) * LINE_OF_SIGHT_STEP
+      })
+      .sum::<f64>();
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Calculate the direction of the galactic band in the local sky.
+  ///
+  /// The band follows the galactic equator, but since we're a little above or
+  /// below the midplane, it's tilted slightly toward the center; and spiral
+  /// arms can make some stretches brighter than others.
+  ///
+  /// Returns (longitude, latitude) of the brightest part of the band, in
+  /// degrees.  That's usually close to the galactic center.
+  #[named]
+  pub fn get_galactic_band_direction(&self) -> (f64, f64) {
+    trace_enter!();
+    let center_latitude = -(self.home_height / self.home_radius).atan().to_degrees();
+    trace_var!(center_latitude);
+    let result = (0..36)
+      .map(|step| step as f64 * 10.0)
+      .map(|longitude| {
+        let brightness = self.get_galactic_band_brightness(longitude, center_latitude);
+        (longitude, brightness)
+      })
+      .fold((0.0, f64::MIN), |best, candidate| match candidate.1 > best.1 {
+        true => candidate,
+        false => best,
+      });
+    trace_var!(result);
+    let result = (result.0, center_latitude);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use rand::prelude::*;
+
+  use super::constraints::Constraints;
+  use super::error::Error;
+  use crate::astronomy::stellar_neighbor::math::point::get_distance_between_points;
+  use crate::astronomy::stellar_neighborhood::constants::MINIMUM_NEIGHBOR_SEPARATION;
+  use crate::astronomy::stellar_neighborhood::constraints::Constraints as StellarNeighborhoodConstraints;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_sectors() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let mut galaxy = Constraints::default().generate(&mut rng)?;
+    let size = galaxy.sector_size;
+    assert_eq!(
+      (0, 0, 0),
+      galaxy.get_sector_coordinates((0.49 * size, -0.49 * size, 0.0))
+    );
+    assert_eq!(
+      (1, -1, 0),
+      galaxy.get_sector_coordinates((0.51 * size, -0.51 * size, 0.0))
+    );
+    let (radius, _, height) = galaxy.get_galactocentric_position((0.0, 0.0, 0.0));
+    assert_approx_eq!(radius, galaxy.home_radius);
+    assert_approx_eq!(height, galaxy.home_height);
+    let (radius, _, _) = galaxy.get_galactocentric_position((1_000.0, 0.0, 0.0));
+    assert_approx_eq!(radius, galaxy.home_radius - 1_000.0);
+    let sector = galaxy.generate_sector((3, -1, 2))?;
+    let center = galaxy.get_sector_center((3, -1, 2));
+    for neighbor in sector.neighbors.iter() {
+      assert_eq!((3, -1, 2), galaxy.get_sector_coordinates(neighbor.coordinates));
+      assert!((neighbor.coordinates.0 - center.0).abs() <= size / 2.0);
+    }
+    // Generating neighbors first doesn't change anything.
+    galaxy.get_sector((2, -1, 2))?;
+    galaxy.get_sector((4, -1, 2))?;
+    let cached = galaxy.get_sector((3, -1, 2))?;
+    assert_eq!(sector.neighbors.len(), cached.neighbors.len());
+    for (neighbor, other) in sector.neighbors.iter().zip(cached.neighbors.iter()) {
+      assert_eq!(neighbor.coordinates, other.coordinates);
+    }
+    assert_eq!(3, galaxy.sectors.len());
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_empty_sector() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let galaxy = Constraints::default().generate(&mut rng)?;
+    // Well beyond the edge of the disk.
+    let sector = galaxy.generate_sector((10_000, 0, 0))?;
+    trace_var!(sector);
+    assert_eq!(0.0, sector.density);
+    assert!(sector.neighbors.is_empty());
+    assert_eq!(0, sector.star_count);
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_sector_boundaries() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let sector_constraints = Some(StellarNeighborhoodConstraints {
+      minimum_separation: Some(MINIMUM_NEIGHBOR_SEPARATION),
+      ..StellarNeighborhoodConstraints::default()
+    });
+    let galaxy = Constraints {
+      sector_constraints,
+      ..Constraints::default()
+    }
+    .generate(&mut rng)?;
+    let sector = galaxy.generate_sector((1, 0, 0))?;
+    let adjacent = galaxy.generate_sector((2, 0, 0))?;
+    print_var!(sector.neighbors.len());
+    print_var!(adjacent.neighbors.len());
+    assert!(!sector.neighbors.is_empty());
+    for neighbor in sector.neighbors.iter() {
+      for other in adjacent.neighbors.iter() {
+        assert!(get_distance_between_points(neighbor.coordinates, other.coordinates) >= MINIMUM_NEIGHBOR_SEPARATION);
+      }
+    }
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_galactic_band() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let galaxy = Constraints::default().generate(&mut rng)?;
+    let toward_center = galaxy.get_galactic_band_brightness(0.0, 0.0);
+    let away_from_center = galaxy.get_galactic_band_brightness(180.0, 0.0);
+    let toward_pole = galaxy.get_galactic_band_brightness(0.0, 90.0);
+    print_var!(toward_center);
+    print_var!(away_from_center);
+    print_var!(toward_pole);
+    assert!(toward_center > away_from_center);
+    assert!(away_from_center > toward_pole);
+    let (longitude, latitude) = galaxy.get_galactic_band_direction();
+    print_var!(longitude);
+    print_var!(latitude);
+    assert!(latitude.abs() < 1.0);
+    print_var!(galaxy.is_home_in_spiral_arm());
+    trace_exit!();
+    Ok(())
+  }
 }
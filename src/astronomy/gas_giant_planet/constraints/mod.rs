@@ -30,7 +30,8 @@ impl Constraints {
     let maximum_mass = self.maximum_mass.unwrap_or(MAXIMUM_MASS);
     trace_var!(maximum_mass);
     let log_normal = LogNormal::new(0.2, 0.5).unwrap();
-    let mass = log_normal.sample(rng);
+    let mass: f64 = log_normal.sample(rng);
+    let mass = mass.clamp(minimum_mass, maximum_mass);
     trace_var!(mass);
     let mut result = GasGiantPlanet::from_mass(mass)?;
     result.semi_major_axis = distance;
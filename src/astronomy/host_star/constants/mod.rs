@@ -2,3 +2,14 @@
 ///
 /// This probability might be slightly lower than actual.
 pub const BINARY_STAR_PROBABILITY: f64 = 0.25;
+
+/// The probability that a given host star will be a stellar remnant.
+///
+/// Zero by default, so that we keep generating the usual main-sequence stars
+/// unless somebody asks otherwise.
+pub const REMNANT_PROBABILITY: f64 = 0.0;
+
+/// The probability that a given host star will be a brown dwarf.
+///
+/// Zero by default, for the same reason as above.
+pub const BROWN_DWARF_PROBABILITY: f64 = 0.0;
@@ -1,19 +1,39 @@
 use rand::prelude::*;
 use std::default::Default;
 
+use crate::astronomy::brown_dwarf::constraints::Constraints as BrownDwarfConstraints;
 use crate::astronomy::close_binary_star::constraints::Constraints as CloseBinaryStarConstraints;
 use crate::astronomy::host_star::constants::*;
 use crate::astronomy::host_star::error::Error;
 use crate::astronomy::host_star::HostStar;
+use crate::astronomy::remnant_binary_star::constraints::Constraints as RemnantBinaryStarConstraints;
 use crate::astronomy::star::constraints::Constraints as StarConstraints;
+use crate::astronomy::stellar_remnant::constraints::Constraints as StellarRemnantConstraints;
 
 /// Constraints for creating a main-sequence host star.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Constraints {
+  /// The probability that we generate a close binary star.
+  pub binary_probability: Option<f64>,
   /// Star constraints.
   pub star_constraints: Option<StarConstraints>,
   /// Close Binary Star constraints.
   pub close_binary_star_constraints: Option<CloseBinaryStarConstraints>,
+  /// The probability that we generate a stellar remnant (or a binary with
+  /// one) instead of a main-sequence star.
+  pub remnant_probability: Option<f64>,
+  /// Stellar Remnant constraints.
+  pub stellar_remnant_constraints: Option<StellarRemnantConstraints>,
+  /// Remnant Binary Star constraints.
+  pub remnant_binary_star_constraints: Option<RemnantBinaryStarConstraints>,
+  /// The probability that we generate a brown dwarf instead of a star.
+  pub brown_dwarf_probability: Option<f64>,
+  /// Brown Dwarf constraints.
+  pub brown_dwarf_constraints: Option<BrownDwarfConstraints>,
+  /// The metallicity ([Fe/H]) of the host star(s).
+  ///
+  /// This overrides whatever the individual constraints specify.
+  pub metallicity: Option<f64>,
 }
 
 impl Constraints {
@@ -38,15 +58,52 @@ impl Constraints {
   pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<HostStar, Error> {
     trace_enter!();
     use HostStar::*;
-    let is_solitary: bool = rng.gen_range(0.0..=1.0) > BINARY_STAR_PROBABILITY;
+    let binary_probability = self.binary_probability.unwrap_or(BINARY_STAR_PROBABILITY);
+    trace_var!(binary_probability);
+    let remnant_probability = self.remnant_probability.unwrap_or(REMNANT_PROBABILITY);
+    trace_var!(remnant_probability);
+    let is_remnant: bool = rng.gen_range(0.0..1.0) < remnant_probability;
+    let is_solitary: bool = rng.gen_range(0.0..=1.0) > binary_probability;
+    let brown_dwarf_probability = self.brown_dwarf_probability.unwrap_or(BROWN_DWARF_PROBABILITY);
+    trace_var!(brown_dwarf_probability);
+    let is_brown_dwarf: bool = rng.gen_range(0.0..1.0) < brown_dwarf_probability;
     let result;
-    if is_solitary {
+    if is_brown_dwarf {
+      let constraints = self.brown_dwarf_constraints.unwrap_or_default();
+      let constraints = BrownDwarfConstraints {
+        metallicity: self.metallicity.or(constraints.metallicity),
+        ..constraints
+      };
+      result = BrownDwarf(constraints.generate(rng)?);
+    } else if is_remnant && is_solitary {
+      let constraints = self.stellar_remnant_constraints.unwrap_or_default();
+      let constraints = StellarRemnantConstraints {
+        metallicity: self.metallicity.or(constraints.metallicity),
+        ..constraints
+      };
+      result = StellarRemnant(constraints.generate(rng)?);
+    } else if is_remnant {
+      let constraints = self.remnant_binary_star_constraints.unwrap_or_default();
+      let constraints = RemnantBinaryStarConstraints {
+        metallicity: self.metallicity.or(constraints.metallicity),
+        ..constraints
+      };
+      result = RemnantBinaryStar(constraints.generate(rng)?);
+    } else if is_solitary {
       let constraints = self.star_constraints.unwrap_or(StarConstraints::default());
+      let constraints = StarConstraints {
+        metallicity: self.metallicity.or(constraints.metallicity),
+        ..constraints
+      };
       result = Star(constraints.generate(rng)?);
     } else {
       let constraints = self
         .close_binary_star_constraints
         .unwrap_or(CloseBinaryStarConstraints::default());
+      let constraints = CloseBinaryStarConstraints {
+        metallicity: self.metallicity.or(constraints.metallicity),
+        ..constraints
+      };
       result = CloseBinaryStar(constraints.generate(rng)?);
     }
     trace_var!(result);
@@ -58,11 +115,25 @@ impl Constraints {
 impl Default for Constraints {
   /// No constraints, just let it all hang out.
   fn default() -> Self {
+    let binary_probability = Some(BINARY_STAR_PROBABILITY);
     let star_constraints = None;
     let close_binary_star_constraints = None;
+    let remnant_probability = Some(REMNANT_PROBABILITY);
+    let stellar_remnant_constraints = None;
+    let remnant_binary_star_constraints = None;
+    let brown_dwarf_probability = Some(BROWN_DWARF_PROBABILITY);
+    let brown_dwarf_constraints = None;
+    let metallicity = None;
     Self {
+      binary_probability,
       star_constraints,
       close_binary_star_constraints,
+      remnant_probability,
+      stellar_remnant_constraints,
+      remnant_binary_star_constraints,
+      brown_dwarf_probability,
+      brown_dwarf_constraints,
+      metallicity,
     }
   }
 }
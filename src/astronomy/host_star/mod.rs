@@ -1,12 +1,18 @@
+use crate::astronomy::brown_dwarf::BrownDwarf;
 use crate::astronomy::close_binary_star::CloseBinaryStar;
+use crate::astronomy::remnant_binary_star::RemnantBinaryStar;
+use crate::astronomy::star::math::magnitude::star_luminosity_and_temperature_to_absolute_visual_magnitude;
 use crate::astronomy::star::Star;
+use crate::astronomy::stellar_remnant::StellarRemnant;
 
 pub mod constants;
 pub mod constraints;
 pub mod error;
 use error::Error;
 
-/// A `HostStar` is either a `Star` or a `CloseBinaryStar`.
+/// A `HostStar` is either a `Star` or a `CloseBinaryStar`, or the remains of
+/// one: a `StellarRemnant` or a `RemnantBinaryStar`, or a lonely
+/// `BrownDwarf` that never quite made it.
 ///
 /// This may seem counterintuitive, but a `CloseBinaryStar` is actually more
 /// closely related to a `Star` than a `DistantBinaryStar`.  The reason for
@@ -20,6 +26,12 @@ pub enum HostStar {
   Star(Star),
   /// A close binary star.
   CloseBinaryStar(CloseBinaryStar),
+  /// A white dwarf, neutron star, or black hole.
+  StellarRemnant(StellarRemnant),
+  /// A star with a remnant companion.
+  RemnantBinaryStar(RemnantBinaryStar),
+  /// A brown dwarf.
+  BrownDwarf(BrownDwarf),
 }
 
 impl HostStar {
@@ -33,6 +45,26 @@ impl HostStar {
     let result = match &self {
       Star(star) => star.current_age,
       CloseBinaryStar(close_binary_star) => close_binary_star.get_current_age(),
+      StellarRemnant(stellar_remnant) => stellar_remnant.current_age,
+      RemnantBinaryStar(remnant_binary_star) => remnant_binary_star.get_current_age(),
+      BrownDwarf(brown_dwarf) => brown_dwarf.current_age,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the metallicity ([Fe/H]) of the stars.
+  #[named]
+  pub fn get_metallicity(&self) -> f64 {
+    trace_enter!();
+    use HostStar::*;
+    let result = match &self {
+      Star(star) => star.metallicity,
+      CloseBinaryStar(close_binary_star) => close_binary_star.get_metallicity(),
+      StellarRemnant(stellar_remnant) => stellar_remnant.metallicity,
+      RemnantBinaryStar(remnant_binary_star) => remnant_binary_star.get_metallicity(),
+      BrownDwarf(brown_dwarf) => brown_dwarf.metallicity,
     };
     trace_var!(result);
     trace_exit!();
@@ -49,6 +81,9 @@ impl HostStar {
     let result = match &self {
       Star(star) => star.mass,
       CloseBinaryStar(close_binary_star) => close_binary_star.get_stellar_mass(),
+      StellarRemnant(stellar_remnant) => stellar_remnant.mass,
+      RemnantBinaryStar(remnant_binary_star) => remnant_binary_star.get_stellar_mass(),
+      BrownDwarf(brown_dwarf) => brown_dwarf.get_stellar_mass(),
     };
     trace_var!(result);
     trace_exit!();
@@ -63,6 +98,9 @@ impl HostStar {
     let result = match &self {
       Star(_) => 1,
       CloseBinaryStar(_) => 2,
+      StellarRemnant(_) => 1,
+      RemnantBinaryStar(_) => 2,
+      BrownDwarf(_) => 1,
     };
     trace_var!(result);
     trace_exit!();
@@ -77,6 +115,9 @@ impl HostStar {
     let result = match &self {
       Star(star) => star.frost_line,
       CloseBinaryStar(close_binary_star) => close_binary_star.frost_line,
+      StellarRemnant(stellar_remnant) => stellar_remnant.frost_line,
+      RemnantBinaryStar(remnant_binary_star) => remnant_binary_star.frost_line,
+      BrownDwarf(brown_dwarf) => brown_dwarf.frost_line,
     };
     trace_var!(result);
     trace_exit!();
@@ -91,12 +132,33 @@ impl HostStar {
     let result = match &self {
       Star(star) => star.habitable_zone,
       CloseBinaryStar(close_binary_star) => close_binary_star.habitable_zone,
+      StellarRemnant(stellar_remnant) => stellar_remnant.habitable_zone,
+      RemnantBinaryStar(remnant_binary_star) => remnant_binary_star.habitable_zone,
+      BrownDwarf(brown_dwarf) => brown_dwarf.habitable_zone,
     };
     trace_var!(result);
     trace_exit!();
     result
   }
 
+  /// Retrieve or calculate the habitable zone at a given age, in AU.
+  #[named]
+  pub fn get_habitable_zone_at_age(&self, age: f64) -> Result<(f64, f64), Error> {
+    trace_enter!();
+    trace_var!(age);
+    use HostStar::*;
+    let result = match &self {
+      Star(star) => star.get_habitable_zone_at_age(age)?,
+      CloseBinaryStar(close_binary_star) => close_binary_star.get_habitable_zone_at_age(age)?,
+      StellarRemnant(stellar_remnant) => stellar_remnant.get_habitable_zone_at_age(age)?,
+      RemnantBinaryStar(remnant_binary_star) => remnant_binary_star.get_habitable_zone_at_age(age)?,
+      BrownDwarf(brown_dwarf) => brown_dwarf.get_habitable_zone_at_age(age),
+    };
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
   /// Retrieve or calculate the satellite zone.
   #[named]
   pub fn get_satellite_zone(&self) -> (f64, f64) {
@@ -105,6 +167,9 @@ impl HostStar {
     let result = match &self {
       Star(star) => star.satellite_zone,
       CloseBinaryStar(close_binary_star) => close_binary_star.satellite_zone,
+      StellarRemnant(stellar_remnant) => stellar_remnant.satellite_zone,
+      RemnantBinaryStar(remnant_binary_star) => remnant_binary_star.satellite_zone,
+      BrownDwarf(brown_dwarf) => brown_dwarf.satellite_zone,
     };
     trace_var!(result);
     trace_exit!();
@@ -119,6 +184,73 @@ impl HostStar {
     let result = match &self {
       Star(star) => star.luminosity,
       CloseBinaryStar(close_binary_star) => close_binary_star.get_luminosity(),
+      StellarRemnant(stellar_remnant) => stellar_remnant.luminosity,
+      RemnantBinaryStar(remnant_binary_star) => remnant_binary_star.get_luminosity(),
+      BrownDwarf(brown_dwarf) => brown_dwarf.luminosity,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the absolute visual (V band) magnitude.
+  ///
+  /// Our bolometric corrections aren't meant for remnants or brown dwarfs,
+  /// but they're so faint that it hardly matters.
+  #[named]
+  pub fn get_absolute_visual_magnitude(&self) -> f64 {
+    trace_enter!();
+    use HostStar::*;
+    let result = match &self {
+      Star(star) => star.get_absolute_visual_magnitude(),
+      CloseBinaryStar(close_binary_star) => close_binary_star.get_absolute_visual_magnitude(),
+      StellarRemnant(stellar_remnant) => star_luminosity_and_temperature_to_absolute_visual_magnitude(
+        stellar_remnant.luminosity,
+        stellar_remnant.temperature,
+      ),
+      RemnantBinaryStar(remnant_binary_star) => remnant_binary_star.get_absolute_visual_magnitude(),
+      BrownDwarf(brown_dwarf) => {
+        star_luminosity_and_temperature_to_absolute_visual_magnitude(brown_dwarf.luminosity, brown_dwarf.temperature)
+      },
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the XUV flux at a distance in AU, in erg/cm²/s.
+  ///
+  /// We don't model activity for remnants or brown dwarfs.
+  #[named]
+  pub fn get_xuv_flux(&self, distance: f64) -> f64 {
+    trace_enter!();
+    trace_var!(distance);
+    use HostStar::*;
+    let result = match &self {
+      Star(star) => star.get_xuv_flux(distance),
+      CloseBinaryStar(close_binary_star) => close_binary_star.get_xuv_flux(distance),
+      StellarRemnant(_) => 0.0,
+      RemnantBinaryStar(remnant_binary_star) => remnant_binary_star.get_xuv_flux(distance),
+      BrownDwarf(_) => 0.0,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the rate of flares at or above an energy in ergs,
+  /// in flares per year.
+  #[named]
+  pub fn get_flare_rate(&self, energy: f64) -> f64 {
+    trace_enter!();
+    trace_var!(energy);
+    use HostStar::*;
+    let result = match &self {
+      Star(star) => star.get_flare_rate(energy),
+      CloseBinaryStar(close_binary_star) => close_binary_star.get_flare_rate(energy),
+      StellarRemnant(_) => 0.0,
+      RemnantBinaryStar(remnant_binary_star) => remnant_binary_star.get_flare_rate(energy),
+      BrownDwarf(_) => 0.0,
     };
     trace_var!(result);
     trace_exit!();
@@ -133,6 +265,9 @@ impl HostStar {
     match &self {
       Star(star) => star.check_habitable()?,
       CloseBinaryStar(close_binary_star) => close_binary_star.check_habitable()?,
+      StellarRemnant(stellar_remnant) => stellar_remnant.check_habitable()?,
+      RemnantBinaryStar(remnant_binary_star) => remnant_binary_star.check_habitable()?,
+      BrownDwarf(brown_dwarf) => brown_dwarf.check_habitable()?,
     }
     let result = Ok(());
     trace_var!(result);
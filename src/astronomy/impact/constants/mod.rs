@@ -0,0 +1,21 @@
+/// How many times wider a crater is than what made it, about.
+pub const CRATER_TO_IMPACTOR_RATIO: f64 = 20.0;
+
+/// The density of a typical impactor, in kg/m^3; a stony asteroid.
+pub const IMPACTOR_DENSITY: f64 = 2_600.0;
+
+/// The speed of a typical impactor, in km/s.
+pub const IMPACTOR_VELOCITY: f64 = 20.0;
+
+/// Joules per megaton of TNT.
+pub const JOULES_PER_MEGATON: f64 = 4.184E15;
+
+/// The smallest impactor that darkens the whole sky, in km.
+pub const IMPACT_WINTER_IMPACTOR_DIAMETER: f64 = 1.0;
+
+/// The smallest impactor that causes a mass extinction, in km; Chicxulub's
+/// was about this big.
+pub const MASS_EXTINCTION_IMPACTOR_DIAMETER: f64 = 10.0;
+
+/// The smallest impactor that boils off an Earth-sized ocean, in km.
+pub const OCEAN_VAPORIZATION_IMPACTOR_DIAMETER: f64 = 400.0;
@@ -0,0 +1,40 @@
+use crate::astronomy::impact::constants::*;
+
+/// The `ImpactConsequence` type.
+///
+/// What an impact did to a world with life on it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ImpactConsequence {
+  /// Flattened everything for a few hundred kilometers, like Tunguska but
+  /// worse; nobody on the far side of the world would have noticed.
+  RegionalDevastation,
+  /// Threw up enough dust to darken the skies everywhere for a year or two.
+  ImpactWinter,
+  /// Set the world on fire and killed off most of what lived on it, like
+  /// Chicxulub.
+  MassExtinction,
+  /// Boiled the oceans away; anything that survived did so deep in the rock.
+  OceanVaporization,
+  /// Knocked loose enough of the planet to make a big moon, like our own.
+  MoonFormation,
+}
+
+impl ImpactConsequence {
+  /// Get the consequence of an ordinary impact, given the diameter of the
+  /// impactor in km.
+  #[named]
+  pub fn from_impactor_diameter(impactor_diameter: f64) -> Self {
+    trace_enter!();
+    trace_var!(impactor_diameter);
+    use ImpactConsequence::*;
+    let result = match impactor_diameter {
+      diameter if diameter >= OCEAN_VAPORIZATION_IMPACTOR_DIAMETER => OceanVaporization,
+      diameter if diameter >= MASS_EXTINCTION_IMPACTOR_DIAMETER => MassExtinction,
+      diameter if diameter >= IMPACT_WINTER_IMPACTOR_DIAMETER => ImpactWinter,
+      _ => RegionalDevastation,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
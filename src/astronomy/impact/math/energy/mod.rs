@@ -0,0 +1,36 @@
+use crate::astronomy::impact::constants::*;
+
+/// Get the kinetic energy of an impactor, in megatons of TNT, given its
+/// diameter in km.
+#[named]
+pub fn get_impact_energy(impactor_diameter: f64) -> f64 {
+  trace_enter!();
+  trace_var!(impactor_diameter);
+  let volume = std::f64::consts::PI / 6.0 * (impactor_diameter * 1_000.0).powf(3.0);
+  trace_var!(volume);
+  let mass = IMPACTOR_DENSITY * volume;
+  trace_var!(mass);
+  let result = 0.5 * mass * (IMPACTOR_VELOCITY * 1_000.0).powf(2.0) / JOULES_PER_MEGATON;
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_impact_energy() {
+    init();
+    trace_enter!();
+    // Chicxulub, give or take.
+    let energy = get_impact_energy(10.0);
+    print_var!(energy);
+    assert!(energy > 1.0E7 && energy < 1.0E9);
+    trace_exit!();
+  }
+}
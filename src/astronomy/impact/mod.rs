@@ -0,0 +1,52 @@
+pub mod constants;
+use constants::*;
+pub mod impact_consequence;
+use impact_consequence::ImpactConsequence;
+pub mod math;
+use math::energy::get_impact_energy;
+
+/// An `Impact` is something big hitting a world hard enough to leave a mark
+/// on its history.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Impact {
+  /// When it hit, in Gyr ago.
+  pub date: f64,
+  /// The diameter of the impactor, in km.
+  pub impactor_diameter: f64,
+  /// The diameter of the crater, in km.
+  pub crater_diameter: f64,
+  /// The energy released, in megatons of TNT.
+  pub energy: f64,
+  /// What it did.
+  pub consequence: ImpactConsequence,
+}
+
+impl Impact {
+  /// Create an impact, given when it hit in Gyr ago, the diameter of the
+  /// impactor in km, and the radius of the body it hit in km.
+  ///
+  /// Nothing leaves a crater wider than half the world.
+  #[named]
+  pub fn from_impactor_diameter(date: f64, impactor_diameter: f64, radius: f64) -> Impact {
+    trace_enter!();
+    trace_var!(date);
+    trace_var!(impactor_diameter);
+    trace_var!(radius);
+    let crater_diameter = (CRATER_TO_IMPACTOR_RATIO * impactor_diameter).min(std::f64::consts::PI * radius);
+    trace_var!(crater_diameter);
+    let energy = get_impact_energy(impactor_diameter);
+    trace_var!(energy);
+    let consequence = ImpactConsequence::from_impactor_diameter(impactor_diameter);
+    trace_var!(consequence);
+    let result = Impact {
+      date,
+      impactor_diameter,
+      crater_diameter,
+      energy,
+      consequence,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
@@ -0,0 +1,57 @@
+/// The age of the Moon, in Gyr, against which the lunar chronology is set.
+pub const CHRONOLOGY_REFERENCE_AGE: f64 = 4.5;
+
+/// The size of the heavy bombardment, in craters at least 1 km across per
+/// km^2.
+pub const CHRONOLOGY_DECAY_COEFFICIENT: f64 = 5.44E-14;
+
+/// How quickly the heavy bombardment tails off, per Gyr.
+pub const CHRONOLOGY_DECAY_RATE: f64 = 6.93;
+
+/// The steady trickle of craters at least 1 km across after the heavy
+/// bombardment, per km^2 per Gyr.
+pub const CHRONOLOGY_STEADY_RATE: f64 = 8.38E-4;
+
+/// How long the heavy bombardment lasts, in Gyr; nothing could settle down
+/// and evolve until it was over.
+pub const HEAVY_BOMBARDMENT_DURATION: f64 = 0.7;
+
+/// The most craters at least 1 km across a surface can hold, per km^2;
+/// past this, every new crater wipes out old ones.
+pub const SATURATION_CRATER_DENSITY: f64 = 0.1;
+
+/// The index of the cumulative size distribution of craters; there are
+/// about 4 times as many half the size.
+pub const CRATER_DIAMETER_INDEX: f64 = 2.0;
+
+/// The smallest crater we count, in km.
+pub const MINIMUM_CRATER_DIAMETER: f64 = 1.0;
+
+/// The least a system's bombardment is of ours.
+pub const MINIMUM_FLUX_FACTOR: f64 = 0.5;
+
+/// The most a system's bombardment is of ours.
+pub const MAXIMUM_FLUX_FACTOR: f64 = 2.0;
+
+/// The number of stretches of history we find the biggest impact in on a
+/// habitable world.
+pub const MAJOR_IMPACT_COUNT: usize = 5;
+
+/// The smallest impactor that counts as a major impact, in km.
+pub const MINIMUM_MAJOR_IMPACTOR_DIAMETER: f64 = 1.0;
+
+/// The longest a volcanically active surface lasts before it's paved over,
+/// in Gyr.
+pub const MAXIMUM_ACTIVE_SURFACE_AGE: f64 = 0.01;
+
+/// How soon after the system formed a big moon gets knocked loose, at the
+/// earliest, in Gyr.
+pub const MINIMUM_GIANT_IMPACT_TIME: f64 = 0.03;
+
+/// How soon after the system formed a big moon gets knocked loose, at the
+/// latest, in Gyr.
+pub const MAXIMUM_GIANT_IMPACT_TIME: f64 = 0.1;
+
+/// How big the body that hit the planet to make a big moon was, as a
+/// fraction of the planet's diameter; Theia was about the size of Mars.
+pub const GIANT_IMPACTOR_DIAMETER_FRACTION: f64 = 0.5;
@@ -0,0 +1,276 @@
+use rand::prelude::*;
+
+use crate::astronomy::host_star::HostStar;
+use crate::astronomy::impact::constants::CRATER_TO_IMPACTOR_RATIO;
+use crate::astronomy::impact::impact_consequence::ImpactConsequence;
+use crate::astronomy::impact::Impact;
+use crate::astronomy::impact_history::constants::*;
+use crate::astronomy::impact_history::error::Error;
+use crate::astronomy::impact_history::math::chronology::{get_crater_density, get_time_for_crater_density};
+use crate::astronomy::impact_history::ImpactHistory;
+use crate::astronomy::moon::constants::KM_PER_MOON_RADIUS;
+use crate::astronomy::moon::moon_origin::MoonOrigin;
+use crate::astronomy::moon::Moon;
+use crate::astronomy::moons::Moons;
+use crate::astronomy::planet::Planet;
+
+/// Constraints for creating an impact history.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Constraints {
+  /// The minimum bombardment, next to ours.
+  pub minimum_flux_factor: Option<f64>,
+  /// The maximum bombardment, next to ours.
+  pub maximum_flux_factor: Option<f64>,
+  /// The number of stretches of history to find the biggest impact in on a
+  /// habitable world.
+  pub major_impact_count: Option<usize>,
+}
+
+impl Constraints {
+  /// Generate, given the radius of the body in km, how long since its
+  /// surface was last paved over in Gyr, and whether it's airless and
+  /// habitable.
+  ///
+  /// Craters only last on an airless surface; anywhere else, the weather
+  /// gets them.  The major impacts are the biggest in each of a few equal
+  /// stretches of time since the heavy bombardment ended, since nothing
+  /// before that would have mattered to anyone.
+  #[named]
+  pub fn generate<R: Rng + ?Sized>(
+    &self,
+    rng: &mut R,
+    host_star: &HostStar,
+    radius: f64,
+    surface_age: f64,
+    is_airless: bool,
+    is_habitable: bool,
+  ) -> Result<ImpactHistory, Error> {
+    trace_enter!();
+    trace_var!(host_star);
+    trace_var!(radius);
+    trace_var!(surface_age);
+    trace_var!(is_airless);
+    trace_var!(is_habitable);
+    let age = host_star.get_current_age();
+    trace_var!(age);
+    let surface_age = surface_age.min(age);
+    trace_var!(surface_age);
+    let surface_area = 4.0 * std::f64::consts::PI * radius.powf(2.0);
+    trace_var!(surface_area);
+    let minimum_flux_factor = self.minimum_flux_factor.unwrap_or(MINIMUM_FLUX_FACTOR);
+    trace_var!(minimum_flux_factor);
+    let maximum_flux_factor = self.maximum_flux_factor.unwrap_or(MAXIMUM_FLUX_FACTOR);
+    trace_var!(maximum_flux_factor);
+    let flux_factor = match minimum_flux_factor < maximum_flux_factor {
+      true => rng.gen_range(minimum_flux_factor..maximum_flux_factor),
+      false => minimum_flux_factor,
+    };
+    trace_var!(flux_factor);
+    let mut crater_counts = vec![];
+    if is_airless {
+      let crater_density = (flux_factor * get_crater_density(age - surface_age, age)).min(SATURATION_CRATER_DENSITY);
+      trace_var!(crater_density);
+      let mut crater_diameter = MINIMUM_CRATER_DIAMETER;
+      while crater_diameter <= std::f64::consts::PI * radius {
+        let count = (crater_density * surface_area / crater_diameter.powf(CRATER_DIAMETER_INDEX)).round() as u64;
+        if count == 0 {
+          break;
+        }
+        crater_counts.push((crater_diameter, count));
+        crater_diameter *= 2.0;
+      }
+    }
+    trace_var!(crater_counts);
+    let mut major_impacts = vec![];
+    if is_habitable && age > HEAVY_BOMBARDMENT_DURATION {
+      let major_impact_count = self.major_impact_count.unwrap_or(MAJOR_IMPACT_COUNT);
+      trace_var!(major_impact_count);
+      let epoch_duration = (age - HEAVY_BOMBARDMENT_DURATION) / major_impact_count as f64;
+      trace_var!(epoch_duration);
+      for epoch in 0..major_impact_count {
+        let start = HEAVY_BOMBARDMENT_DURATION + epoch as f64 * epoch_duration;
+        let crater_density = get_crater_density(start, start + epoch_duration);
+        // The number of impactors at least 1 km across.
+        let impactor_count =
+          flux_factor * crater_density * surface_area / CRATER_TO_IMPACTOR_RATIO.powf(CRATER_DIAMETER_INDEX);
+        trace_var!(impactor_count);
+        // The count of anything bigger than the biggest is exponentially
+        // distributed.
+        let rank = -(1.0 - rng.gen_range(0.0..1.0_f64)).ln();
+        let impactor_diameter = (impactor_count / rank).powf(1.0 / CRATER_DIAMETER_INDEX);
+        trace_var!(impactor_diameter);
+        if impactor_diameter < MINIMUM_MAJOR_IMPACTOR_DIAMETER {
+          continue;
+        }
+        let time = get_time_for_crater_density(start, rng.gen_range(0.0..1.0) * crater_density);
+        trace_var!(time);
+        major_impacts.push(Impact::from_impactor_diameter(age - time, impactor_diameter, radius));
+      }
+      major_impacts.sort_by(|a, b| b.date.partial_cmp(&a.date).unwrap());
+    }
+    trace_var!(major_impacts);
+    let result = ImpactHistory {
+      age,
+      surface_area,
+      flux_factor,
+      surface_age,
+      crater_counts,
+      major_impacts,
+    };
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Generate for a planet, given its moons.
+  ///
+  /// Giants don't have a surface to keep craters on.  A moon that was
+  /// knocked loose from the planet leaves the biggest impact of all at the
+  /// very start of its history.
+  #[named]
+  pub fn generate_for_planet<R: Rng + ?Sized>(
+    &self,
+    rng: &mut R,
+    host_star: &HostStar,
+    planet: &Planet,
+    moons: &Moons,
+  ) -> Result<ImpactHistory, Error> {
+    trace_enter!();
+    trace_var!(host_star);
+    trace_var!(planet);
+    trace_var!(moons);
+    let radius = planet.get_radius_in_km();
+    trace_var!(radius);
+    let is_airless = match planet {
+      Planet::TerrestrialPlanet(terrestrial_planet) => !terrestrial_planet.is_atmospherically_stable,
+      _ => false,
+    };
+    trace_var!(is_airless);
+    let age = host_star.get_current_age();
+    trace_var!(age);
+    let mut result = self.generate(rng, host_star, radius, age, is_airless, planet.is_habitable())?;
+    if moons.moons.iter().any(|moon| moon.origin == MoonOrigin::GiantImpact) {
+      let time = rng
+        .gen_range(MINIMUM_GIANT_IMPACT_TIME..MAXIMUM_GIANT_IMPACT_TIME)
+        .min(age);
+      trace_var!(time);
+      let mut impact =
+        Impact::from_impactor_diameter(age - time, GIANT_IMPACTOR_DIAMETER_FRACTION * 2.0 * radius, radius);
+      impact.consequence = ImpactConsequence::MoonFormation;
+      result.major_impacts.insert(0, impact);
+    }
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Generate for a moon.
+  ///
+  /// Volcanoes pave over a surface about as fast as anything can crater it,
+  /// and a moon that can hold onto an atmosphere weathers its craters away.
+  #[named]
+  pub fn generate_for_moon<R: Rng + ?Sized>(
+    &self,
+    rng: &mut R,
+    host_star: &HostStar,
+    moon: &Moon,
+  ) -> Result<ImpactHistory, Error> {
+    trace_enter!();
+    trace_var!(host_star);
+    trace_var!(moon);
+    let radius = moon.radius * KM_PER_MOON_RADIUS;
+    trace_var!(radius);
+    let surface_age = match moon.is_volcanically_active {
+      true => rng.gen_range(0.0..MAXIMUM_ACTIVE_SURFACE_AGE),
+      false => host_star.get_current_age(),
+    };
+    trace_var!(surface_age);
+    let result = self.generate(
+      rng,
+      host_star,
+      radius,
+      surface_age,
+      !moon.is_atmospherically_stable,
+      moon.is_habitable(),
+    )?;
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+}
+
+impl Default for Constraints {
+  /// No constraints, just let it all hang out.
+  fn default() -> Self {
+    let minimum_flux_factor = None;
+    let maximum_flux_factor = None;
+    let major_impact_count = None;
+    Self {
+      minimum_flux_factor,
+      maximum_flux_factor,
+      major_impact_count,
+    }
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::astronomy::star::Star;
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_generate() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let mut star = Star::from_mass(&mut rng, 1.0).unwrap();
+    star.current_age = 4.5;
+    let host_star = &HostStar::Star(star);
+    let constraints = Constraints {
+      minimum_flux_factor: Some(1.0),
+      maximum_flux_factor: Some(1.0),
+      ..Constraints::default()
+    };
+    // Our Moon, all of it the same age.
+    let moon = constraints.generate(&mut rng, host_star, KM_PER_MOON_RADIUS, 4.5, true, false)?;
+    print_var!(moon.crater_counts);
+    assert!(moon.major_impacts.is_empty());
+    let (crater_diameter, count) = moon.crater_counts[0];
+    assert_eq!(crater_diameter, MINIMUM_CRATER_DIAMETER);
+    assert_eq!(count, (SATURATION_CRATER_DENSITY * moon.surface_area).round() as u64);
+    assert!(moon.crater_counts.windows(2).all(|pair| pair[1].1 < pair[0].1));
+    // Io, paved over all the time.
+    let io = constraints.generate(&mut rng, host_star, 1_821.6, 0.001, true, false)?;
+    print_var!(io.crater_counts);
+    assert!(io.crater_counts.is_empty() || io.crater_counts[0].1 < count / 1_000);
+    // The Earth.
+    let earth = constraints.generate(&mut rng, host_star, 6_371.0, 4.5, false, true)?;
+    print_var!(earth.major_impacts);
+    assert!(earth.crater_counts.is_empty());
+    assert_eq!(earth.major_impacts.len(), MAJOR_IMPACT_COUNT);
+    assert!(earth.major_impacts.windows(2).all(|pair| pair[0].date >= pair[1].date));
+    assert!(earth
+      .major_impacts
+      .iter()
+      .all(|impact| impact.date <= 4.5 - HEAVY_BOMBARDMENT_DURATION
+        && impact.consequence != ImpactConsequence::RegionalDevastation));
+    // Something like Chicxulub every hundred million years or so.
+    let interval = earth.get_impact_interval(10.0);
+    print_var!(interval);
+    assert!(interval > 3.0E7 && interval < 3.0E8);
+    // And much worse back when the system was young.
+    let mut star = Star::from_mass(&mut rng, 1.0).unwrap();
+    star.current_age = 0.5;
+    let young_earth = constraints.generate(&mut rng, &HostStar::Star(star), 6_371.0, 0.5, false, true)?;
+    assert!(young_earth.major_impacts.is_empty());
+    assert!(young_earth.get_impact_rate(10.0) > earth.get_impact_rate(10.0) * 100.0);
+    trace_exit!();
+    Ok(())
+  }
+}
@@ -0,0 +1,84 @@
+use crate::astronomy::impact_history::constants::*;
+
+/// Get the rate at which craters at least 1 km across pile up, per km^2 per
+/// Gyr, given the time since the system formed in Gyr.
+///
+/// This is the lunar chronology: a heavy bombardment that tails off over the
+/// first billion years or so as the leftovers get swept up, and then a slow,
+/// steady trickle.
+#[named]
+pub fn get_crater_rate(time: f64) -> f64 {
+  trace_enter!();
+  trace_var!(time);
+  let result = CHRONOLOGY_DECAY_COEFFICIENT
+    * CHRONOLOGY_DECAY_RATE
+    * (CHRONOLOGY_DECAY_RATE * (CHRONOLOGY_REFERENCE_AGE - time)).exp()
+    + CHRONOLOGY_STEADY_RATE;
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the number of craters at least 1 km across per km^2 that pile up
+/// between the start and the end, in Gyr since the system formed.
+#[named]
+pub fn get_crater_density(start: f64, end: f64) -> f64 {
+  trace_enter!();
+  trace_var!(start);
+  trace_var!(end);
+  let result = CHRONOLOGY_DECAY_COEFFICIENT
+    * ((CHRONOLOGY_DECAY_RATE * (CHRONOLOGY_REFERENCE_AGE - start)).exp()
+      - (CHRONOLOGY_DECAY_RATE * (CHRONOLOGY_REFERENCE_AGE - end)).exp())
+    + CHRONOLOGY_STEADY_RATE * (end - start);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the time, in Gyr since the system formed, by which the given number
+/// of craters at least 1 km across per km^2 have piled up since the start.
+///
+/// There's no neat way to turn it around, so we just narrow it down.
+#[named]
+pub fn get_time_for_crater_density(start: f64, density: f64) -> f64 {
+  trace_enter!();
+  trace_var!(start);
+  trace_var!(density);
+  let mut lower = start;
+  let mut upper = start + density / CHRONOLOGY_STEADY_RATE;
+  for _ in 0..100 {
+    let middle = (lower + upper) / 2.0;
+    match get_crater_density(start, middle) < density {
+      true => lower = middle,
+      false => upper = middle,
+    }
+  }
+  let result = (lower + upper) / 2.0;
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_crater_density() {
+    init();
+    trace_enter!();
+    // The lunar maria, about 3.5 Gyr old.
+    let maria = get_crater_density(1.0, 4.5);
+    print_var!(maria);
+    assert_approx_eq!(maria, 0.004_8, 0.000_5);
+    // The bombardment's long since over.
+    assert_approx_eq!(get_crater_rate(4.5), CHRONOLOGY_STEADY_RATE, 0.000_1);
+    assert!(get_crater_rate(0.5) > get_crater_rate(4.5) * 100.0);
+    let time = get_time_for_crater_density(1.0, maria);
+    assert_approx_eq!(time, 4.5, 0.001);
+    trace_exit!();
+  }
+}
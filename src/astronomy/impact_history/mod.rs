@@ -0,0 +1,60 @@
+use crate::astronomy::impact::constants::CRATER_TO_IMPACTOR_RATIO;
+use crate::astronomy::impact::Impact;
+
+pub mod constants;
+use constants::*;
+pub mod constraints;
+pub mod error;
+pub mod math;
+use math::chronology::get_crater_rate;
+
+/// An `ImpactHistory` is what's hit a world over the life of its system:
+/// the craters it's kept, the impacts that changed it, and what's still
+/// coming.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImpactHistory {
+  /// The age of the system, in Gyr.
+  pub age: f64,
+  /// The surface area, in km^2.
+  pub surface_area: f64,
+  /// How heavy the bombardment is, next to ours.
+  pub flux_factor: f64,
+  /// How long since the surface was last paved over, in Gyr.
+  pub surface_age: f64,
+  /// The number of craters at least each diameter in km, doubling, on an
+  /// airless surface; anywhere else, they've weathered away.
+  pub crater_counts: Vec<(f64, u64)>,
+  /// The impacts that left a mark on the history of a habitable world, in
+  /// order.
+  pub major_impacts: Vec<Impact>,
+}
+
+impl ImpactHistory {
+  /// Retrieve or calculate how many impactors at least the given diameter in
+  /// km hit each year these days.
+  #[named]
+  pub fn get_impact_rate(&self, impactor_diameter: f64) -> f64 {
+    trace_enter!();
+    trace_var!(impactor_diameter);
+    let crater_diameter = CRATER_TO_IMPACTOR_RATIO * impactor_diameter;
+    trace_var!(crater_diameter);
+    let result = self.flux_factor * get_crater_rate(self.age) * self.surface_area
+      / crater_diameter.powf(CRATER_DIAMETER_INDEX)
+      / 1.0E9;
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate how many years, on average, go by between
+  /// impactors at least the given diameter in km.
+  #[named]
+  pub fn get_impact_interval(&self, impactor_diameter: f64) -> f64 {
+    trace_enter!();
+    trace_var!(impactor_diameter);
+    let result = 1.0 / self.get_impact_rate(impactor_diameter);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
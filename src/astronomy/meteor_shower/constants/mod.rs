@@ -0,0 +1,19 @@
+/// The orbital velocity of Earth, in km/s.
+pub const KM_PER_SECOND_PER_EARTH_ORBITAL_VELOCITY: f64 = 29.78;
+
+/// The zenithal hourly rate of a stream straight through the middle, per
+/// square kilometer of active surface on the parent comet.
+pub const ZENITHAL_HOURLY_RATE_PER_ACTIVE_AREA: f64 = 12.0;
+
+/// How far from the middle of a stream its density falls off by a standard
+/// deviation, in AU; streams spread out as they age.
+pub const STREAM_WIDTH: f64 = 0.05;
+
+/// The farthest a planet can pass from a stream and still see a shower, in
+/// AU.
+pub const MAXIMUM_MISS_DISTANCE: f64 = 0.15;
+
+/// The longest period a comet can have and still have spread its dust out
+/// along its whole orbit, in years; a comet that's only been around a few
+/// times doesn't leave a stream to speak of.
+pub const MAXIMUM_PARENT_ORBITAL_PERIOD: f64 = 1.0E3;
@@ -0,0 +1,16 @@
+/// Meteor shower errors.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Error {
+  /// The comet's orbit doesn't come close enough to the planet's.
+  OrbitsDoNotIntersect,
+  /// The comet hasn't been around often enough to leave a stream.
+  OrbitalPeriodTooLongForStream,
+}
+
+honeyholt_define_brief!(Error, |error: &Error| {
+  use Error::*;
+  match error {
+    OrbitsDoNotIntersect => "the comet's orbit does not come close enough to the planet's".to_string(),
+    OrbitalPeriodTooLongForStream => "the comet has not been around often enough to leave a stream".to_string(),
+  }
+});
@@ -0,0 +1,3 @@
+pub mod radiant;
+pub mod velocity;
+pub mod zenithal_hourly_rate;
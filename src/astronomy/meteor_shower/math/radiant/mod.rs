@@ -0,0 +1,51 @@
+/// Get the radiant of a meteor shower, the point on the sky its meteors seem
+/// to come from, as a right ascension and declination in degrees, given the
+/// velocity of the meteoroids relative to the planet in any units along the
+/// x, y, and z axes, and the planet's axial tilt in degrees.
+///
+/// They come from wherever they're headed away from; the tilt turns that
+/// from the planets' plane to the planet's own sky.
+#[named]
+pub fn get_radiant(relative_velocity: (f64, f64, f64), axial_tilt: f64) -> (f64, f64) {
+  trace_enter!();
+  trace_var!(relative_velocity);
+  trace_var!(axial_tilt);
+  let (x, y, z) = relative_velocity;
+  let speed = (x * x + y * y + z * z).sqrt();
+  trace_var!(speed);
+  let longitude = (-y).atan2(-x);
+  trace_var!(longitude);
+  let latitude = (-z / speed).asin();
+  trace_var!(latitude);
+  let (sin_e, cos_e) = axial_tilt.to_radians().sin_cos();
+  let declination = (latitude.sin() * cos_e + latitude.cos() * sin_e * longitude.sin()).asin();
+  trace_var!(declination);
+  let right_ascension = (longitude.sin() * cos_e - latitude.tan() * sin_e).atan2(longitude.cos());
+  trace_var!(right_ascension);
+  let result = (right_ascension.to_degrees().rem_euclid(360.0), declination.to_degrees());
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_radiant() {
+    init();
+    trace_enter!();
+    // Coming straight down from the pole of the planets' plane.
+    let (_, declination) = get_radiant((0.0, 0.0, -1.0), 23.44);
+    assert_approx_eq!(declination, 66.56);
+    // Coming from the vernal equinox.
+    let (right_ascension, declination) = get_radiant((-1.0, 0.0, 0.0), 23.44);
+    assert_approx_eq!(right_ascension, 0.0);
+    assert_approx_eq!(declination, 0.0);
+    trace_exit!();
+  }
+}
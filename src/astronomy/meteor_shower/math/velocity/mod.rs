@@ -0,0 +1,101 @@
+use crate::astronomy::meteor_shower::constants::*;
+
+/// Get the velocity of something on an orbit, in km/s along the x, y, and z
+/// axes, given the mass of the star in Msol, the orbit's perihelion in AU and
+/// eccentricity, its inclination, longitude of ascending node, and argument
+/// of perihelion in degrees, and its true anomaly in degrees.
+///
+/// The x axis points at the vernal equinox and the z axis at the north pole
+/// of the planets' plane.
+#[named]
+pub fn get_orbital_velocity(
+  stellar_mass: f64,
+  perihelion: f64,
+  orbital_eccentricity: f64,
+  orbital_inclination: f64,
+  longitude_of_ascending_node: f64,
+  argument_of_perihelion: f64,
+  true_anomaly: f64,
+) -> (f64, f64, f64) {
+  trace_enter!();
+  trace_var!(stellar_mass);
+  trace_var!(perihelion);
+  trace_var!(orbital_eccentricity);
+  trace_var!(orbital_inclination);
+  trace_var!(longitude_of_ascending_node);
+  trace_var!(argument_of_perihelion);
+  trace_var!(true_anomaly);
+  let semi_latus_rectum = perihelion * (1.0 + orbital_eccentricity);
+  trace_var!(semi_latus_rectum);
+  let speed = KM_PER_SECOND_PER_EARTH_ORBITAL_VELOCITY * (stellar_mass / semi_latus_rectum).sqrt();
+  trace_var!(speed);
+  let (sin_i, cos_i) = orbital_inclination.to_radians().sin_cos();
+  let (sin_o, cos_o) = longitude_of_ascending_node.to_radians().sin_cos();
+  let (sin_w, cos_w) = argument_of_perihelion.to_radians().sin_cos();
+  let (sin_v, cos_v) = true_anomaly.to_radians().sin_cos();
+  // Toward perihelion, and a quarter turn ahead of it.
+  let p = (
+    cos_w * cos_o - sin_w * sin_o * cos_i,
+    cos_w * sin_o + sin_w * cos_o * cos_i,
+    sin_w * sin_i,
+  );
+  let q = (
+    -sin_w * cos_o - cos_w * sin_o * cos_i,
+    -sin_w * sin_o + cos_w * cos_o * cos_i,
+    cos_w * sin_i,
+  );
+  let along_p = -speed * sin_v;
+  let along_q = speed * (orbital_eccentricity + cos_v);
+  let result = (
+    along_p * p.0 + along_q * q.0,
+    along_p * p.1 + along_q * q.1,
+    along_p * p.2 + along_q * q.2,
+  );
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the velocity of a planet on a circular orbit, in km/s along the x, y,
+/// and z axes, given the mass of the star in Msol, the planet's distance
+/// from it in AU, and its longitude in degrees.
+#[named]
+pub fn get_circular_velocity(stellar_mass: f64, distance: f64, longitude: f64) -> (f64, f64, f64) {
+  trace_enter!();
+  trace_var!(stellar_mass);
+  trace_var!(distance);
+  trace_var!(longitude);
+  let speed = KM_PER_SECOND_PER_EARTH_ORBITAL_VELOCITY * (stellar_mass / distance).sqrt();
+  trace_var!(speed);
+  let (sin_l, cos_l) = longitude.to_radians().sin_cos();
+  let result = (-speed * sin_l, speed * cos_l, 0.0);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_orbital_velocity() {
+    init();
+    trace_enter!();
+    // A circular orbit is just a circular orbit.
+    let (x, y, z) = get_orbital_velocity(1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 90.0);
+    let (cx, cy, cz) = get_circular_velocity(1.0, 1.0, 90.0);
+    assert_approx_eq!(x, cx);
+    assert_approx_eq!(y, cy);
+    assert_approx_eq!(z, cz);
+    // Halley's Comet at perihelion, going backwards.
+    let (x, y, z) = get_orbital_velocity(1.0, 0.586, 0.967, 162.3, 58.4, 111.3, 0.0);
+    let speed = (x * x + y * y + z * z).sqrt();
+    print_var!(speed);
+    assert_approx_eq!(speed, 54.5, 0.5);
+    trace_exit!();
+  }
+}
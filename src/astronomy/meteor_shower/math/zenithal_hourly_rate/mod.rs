@@ -0,0 +1,47 @@
+use crate::astronomy::meteor_shower::constants::*;
+
+/// Get the zenithal hourly rate of a meteor shower, the number of meteors an
+/// observer would see in an hour under a dark sky with the radiant straight
+/// overhead, given the radius of the parent comet's nucleus in km, the
+/// fraction of its surface that's active, and how far the planet passes
+/// from the middle of the stream in AU.
+///
+/// The stream's about as dusty as the comet is active, and thins out
+/// toward the edges.
+#[named]
+pub fn get_zenithal_hourly_rate(nucleus_radius: f64, activity: f64, miss_distance: f64) -> f64 {
+  trace_enter!();
+  trace_var!(nucleus_radius);
+  trace_var!(activity);
+  trace_var!(miss_distance);
+  let result = ZENITHAL_HOURLY_RATE_PER_ACTIVE_AREA
+    * activity
+    * nucleus_radius.powf(2.0)
+    * (-0.5 * (miss_distance / STREAM_WIDTH).powf(2.0)).exp();
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_zenithal_hourly_rate() {
+    init();
+    trace_enter!();
+    // The Perseids, from Swift-Tuttle.
+    let perseids = get_zenithal_hourly_rate(13.0, 0.05, 0.01);
+    print_var!(perseids);
+    assert_approx_eq!(perseids, 100.0, 5.0);
+    // The Eta Aquariids, from Halley's Comet.
+    let eta_aquariids = get_zenithal_hourly_rate(5.5, 0.1, 0.065);
+    print_var!(eta_aquariids);
+    assert!(eta_aquariids > 10.0 && eta_aquariids < 50.0);
+    trace_exit!();
+  }
+}
@@ -0,0 +1,186 @@
+use crate::astronomy::comet::Comet;
+use crate::astronomy::host_star::HostStar;
+use crate::astronomy::planet::Planet;
+
+pub mod constants;
+use constants::*;
+pub mod error;
+use error::Error;
+pub mod math;
+use math::radiant::get_radiant;
+use math::velocity::{get_circular_velocity, get_orbital_velocity};
+use math::zenithal_hourly_rate::get_zenithal_hourly_rate;
+pub mod orbital_node;
+use orbital_node::OrbitalNode;
+
+/// A `MeteorShower` happens every year when a planet passes through the
+/// trail of dust a comet has left along its orbit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeteorShower {
+  /// Which of the comet's nodes the planet passes through.
+  pub orbital_node: OrbitalNode,
+  /// Where the star appears along the planets' plane from the planet at the
+  /// peak, in degrees from the vernal equinox.
+  pub solar_longitude: f64,
+  /// When it peaks, in years since the vernal equinox.
+  pub date: f64,
+  /// Where it seems to come from, as a right ascension and declination in
+  /// degrees.
+  pub radiant: (f64, f64),
+  /// How fast the meteors hit, in km/s, before the planet's gravity gets a
+  /// hold of them.
+  pub velocity: f64,
+  /// How many meteors you'd see in an hour at the peak, with the radiant
+  /// straight overhead under a dark sky.
+  pub zenithal_hourly_rate: f64,
+}
+
+impl MeteorShower {
+  /// Create a meteor shower from a comet's stream, if the planet passes
+  /// close enough to the given node.
+  ///
+  /// We take the planet's orbit to be circular and in the reference plane;
+  /// they're close enough to both that nobody would notice.  Giants don't
+  /// keep track of their tilt, so we stand them up straight.
+  #[named]
+  pub fn from_comet(
+    comet: &Comet,
+    orbital_node: OrbitalNode,
+    host_star: &HostStar,
+    planet: &Planet,
+  ) -> Result<MeteorShower, Error> {
+    trace_enter!();
+    trace_var!(comet);
+    trace_var!(orbital_node);
+    trace_var!(host_star);
+    trace_var!(planet);
+    if comet.orbital_period > MAXIMUM_PARENT_ORBITAL_PERIOD {
+      trace_exit!();
+      return Err(Error::OrbitalPeriodTooLongForStream);
+    }
+    let planet_distance = planet.get_semi_major_axis();
+    trace_var!(planet_distance);
+    let true_anomaly = orbital_node.get_true_anomaly(comet.argument_of_perihelion);
+    trace_var!(true_anomaly);
+    let node_distance = comet.perihelion * (1.0 + comet.orbital_eccentricity)
+      / (1.0 + comet.orbital_eccentricity * true_anomaly.to_radians().cos());
+    trace_var!(node_distance);
+    let miss_distance = (node_distance - planet_distance).abs();
+    trace_var!(miss_distance);
+    if miss_distance > MAXIMUM_MISS_DISTANCE {
+      trace_exit!();
+      return Err(Error::OrbitsDoNotIntersect);
+    }
+    let stellar_mass = host_star.get_stellar_mass();
+    trace_var!(stellar_mass);
+    let longitude = orbital_node.get_longitude(comet.longitude_of_ascending_node);
+    trace_var!(longitude);
+    let solar_longitude = (longitude + 180.0).rem_euclid(360.0);
+    trace_var!(solar_longitude);
+    let date = solar_longitude / 360.0 * planet.get_orbital_period();
+    trace_var!(date);
+    let comet_velocity = get_orbital_velocity(
+      stellar_mass,
+      comet.perihelion,
+      comet.orbital_eccentricity,
+      comet.orbital_inclination,
+      comet.longitude_of_ascending_node,
+      comet.argument_of_perihelion,
+      true_anomaly,
+    );
+    trace_var!(comet_velocity);
+    let planet_velocity = get_circular_velocity(stellar_mass, planet_distance, longitude);
+    trace_var!(planet_velocity);
+    let relative_velocity = (
+      comet_velocity.0 - planet_velocity.0,
+      comet_velocity.1 - planet_velocity.1,
+      comet_velocity.2 - planet_velocity.2,
+    );
+    trace_var!(relative_velocity);
+    let velocity =
+      (relative_velocity.0.powf(2.0) + relative_velocity.1.powf(2.0) + relative_velocity.2.powf(2.0)).sqrt();
+    trace_var!(velocity);
+    let axial_tilt = match planet {
+      Planet::TerrestrialPlanet(terrestrial_planet) => terrestrial_planet.axial_tilt,
+      _ => 0.0,
+    };
+    trace_var!(axial_tilt);
+    let radiant = get_radiant(relative_velocity, axial_tilt);
+    trace_var!(radiant);
+    let zenithal_hourly_rate = get_zenithal_hourly_rate(comet.nucleus_radius, comet.activity, miss_distance);
+    trace_var!(zenithal_hourly_rate);
+    let result = MeteorShower {
+      orbital_node,
+      solar_longitude,
+      date,
+      radiant,
+      velocity,
+      zenithal_hourly_rate,
+    };
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::astronomy::comet::comet_reservoir::CometReservoir;
+  use crate::astronomy::star::Star;
+  use crate::astronomy::terrestrial_planet::TerrestrialPlanet;
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_from_comet() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let host_star = &HostStar::Star(Star::from_mass(&mut rng, 1.0).unwrap());
+    let planet = &Planet::TerrestrialPlanet(TerrestrialPlanet::from_mass(1.0).unwrap());
+    // Swift-Tuttle.
+    let comet = Comet {
+      reservoir: CometReservoir::OortCloud,
+      perihelion: 0.9595,
+      semi_major_axis: 26.09,
+      orbital_eccentricity: 0.9632,
+      orbital_inclination: 113.45,
+      longitude_of_ascending_node: 139.38,
+      argument_of_perihelion: 152.98,
+      orbital_period: 133.28,
+      time_of_perihelion: 0.0,
+      nucleus_radius: 13.0,
+      activity: 0.05,
+      absolute_magnitude: 4.0,
+    };
+    let perseids = MeteorShower::from_comet(&comet, OrbitalNode::Descending, host_star, planet)?;
+    print_var!(perseids);
+    assert_approx_eq!(perseids.solar_longitude, 139.38);
+    assert_approx_eq!(perseids.date, 139.38 / 360.0);
+    let (right_ascension, declination) = perseids.radiant;
+    assert_approx_eq!(right_ascension, 48.0, 5.0);
+    assert_approx_eq!(declination, 58.0, 5.0);
+    assert_approx_eq!(perseids.zenithal_hourly_rate, 100.0, 5.0);
+    // The other node is way out past Jupiter.
+    assert_eq!(
+      MeteorShower::from_comet(&comet, OrbitalNode::Ascending, host_star, planet),
+      Err(Error::OrbitsDoNotIntersect)
+    );
+    // A comet that's only been through once hasn't left a trail.
+    let comet = Comet {
+      orbital_period: 1.0E5,
+      ..comet
+    };
+    assert_eq!(
+      MeteorShower::from_comet(&comet, OrbitalNode::Descending, host_star, planet),
+      Err(Error::OrbitalPeriodTooLongForStream)
+    );
+    trace_exit!();
+    Ok(())
+  }
+}
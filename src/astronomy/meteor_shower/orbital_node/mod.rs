@@ -0,0 +1,46 @@
+/// The `OrbitalNode` type.
+///
+/// Which of the two places a tilted orbit passes through the planets' plane.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OrbitalNode {
+  /// Where it climbs up through the plane.
+  Ascending,
+  /// Where it comes back down through the plane.
+  Descending,
+}
+
+impl OrbitalNode {
+  /// Get the true anomaly of the node, in degrees, given the argument of
+  /// perihelion in degrees.
+  #[named]
+  pub fn get_true_anomaly(&self, argument_of_perihelion: f64) -> f64 {
+    trace_enter!();
+    trace_var!(argument_of_perihelion);
+    use OrbitalNode::*;
+    let result = match self {
+      Ascending => -argument_of_perihelion,
+      Descending => 180.0 - argument_of_perihelion,
+    }
+    .rem_euclid(360.0);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Get the longitude of the node, in degrees, given the longitude of the
+  /// ascending node in degrees.
+  #[named]
+  pub fn get_longitude(&self, longitude_of_ascending_node: f64) -> f64 {
+    trace_enter!();
+    trace_var!(longitude_of_ascending_node);
+    use OrbitalNode::*;
+    let result = match self {
+      Ascending => longitude_of_ascending_node,
+      Descending => longitude_of_ascending_node + 180.0,
+    }
+    .rem_euclid(360.0);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
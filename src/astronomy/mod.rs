@@ -6,28 +6,45 @@
 /// MOONS = [MOON]
 /// TERRESTRIAL_PLANET = ()
 /// GAS_GIANT_PLANET = ()
-/// PLANET = GAS_GIANT_PLANET | TERRESTRIAL_PLANET
-/// SATELLITE_SYSTEM = (PLANET, MOONS)
+/// BROWN_DWARF = ()
+/// PLANET = BROWN_DWARF | GAS_GIANT_PLANET | TERRESTRIAL_PLANET
+/// RINGS = ()
+/// TROJANS = (PLANET?)
+/// IMPACT = ()
+/// IMPACT_HISTORY = ([IMPACT])
+/// SATELLITE_SYSTEM = (PLANET, MOONS, RINGS?, [TROJANS], IMPACT_HISTORY, [IMPACT_HISTORY])
 /// SATELLITE_SYSTEMS = [SATELLITE_SYSTEM]
 /// STAR = ()
+/// STELLAR_REMNANT = ()
 /// CLOSE_BINARY_STAR = (STAR, STAR)
-/// HOST_STAR = CLOSE_BINARY_STAR | STAR
-/// PLANETARY_SYSTEM = (HOST_STAR, SATELLITE_SYSTEMS)
+/// REMNANT_BINARY_STAR = (STAR, STELLAR_REMNANT)
+/// HOST_STAR = BROWN_DWARF | CLOSE_BINARY_STAR | REMNANT_BINARY_STAR | STAR | STELLAR_REMNANT
+/// COMET = ()
+/// COMETS = [COMET]
+/// PLANETARY_SYSTEM = (HOST_STAR, SATELLITE_SYSTEMS, COMETS)
 /// DISTANT_BINARY_STAR = (PLANETARY_SYSTEM, PLANETARY_SYSTEM)
 /// STAR_SUBSYSTEM = DISTANT_BINARY_STAR | PLANETARY_SYSTEM
 /// STAR_SYSTEM = (STAR_SUBSYSTEM)
 /// STELLAR_NEIGHBOR = (STAR_SYSTEM)
 /// STELLAR_NEIGHBORHOOD = [STELLAR_NEIGHBOR]
 /// GALAXY = (STELLAR_NEIGHBORHOOD)
+pub mod brown_dwarf;
 pub mod close_binary_star;
+pub mod comet;
+pub mod comets;
 pub mod distant_binary_star;
 pub mod galaxy;
 pub mod gas_giant_planet;
 pub mod host_star;
+pub mod impact;
+pub mod impact_history;
+pub mod meteor_shower;
 pub mod moon;
 pub mod moons;
 pub mod planet;
 pub mod planetary_system;
+pub mod remnant_binary_star;
+pub mod rings;
 pub mod satellite_system;
 pub mod satellite_systems;
 pub mod star;
@@ -35,4 +52,6 @@ pub mod star_subsystem;
 pub mod star_system;
 pub mod stellar_neighbor;
 pub mod stellar_neighborhood;
+pub mod stellar_remnant;
 pub mod terrestrial_planet;
+pub mod trojans;
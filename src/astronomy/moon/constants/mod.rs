@@ -9,3 +9,125 @@ pub const MINIMUM_ALBEDO: f64 = 0.25;
 
 /// Maximum albedo.
 pub const MAXIMUM_ALBEDO: f64 = 1.00;
+
+/// Minimum density of a rocky moon, in g/cm³; Europa's about as light as
+/// they get.
+pub const MINIMUM_ROCKY_DENSITY: f64 = 2.9;
+
+/// Maximum density of a rocky moon, in g/cm³; Io, give or take.
+pub const MAXIMUM_ROCKY_DENSITY: f64 = 3.6;
+
+/// Minimum density of an icy moon, in g/cm³; Tethys is almost pure ice.
+pub const MINIMUM_ICY_DENSITY: f64 = 1.0;
+
+/// Maximum density of an icy moon, in g/cm³; Ganymede is about half rock.
+pub const MAXIMUM_ICY_DENSITY: f64 = 2.0;
+
+/// Minimum Bond albedo of a rocky moon; dark, like our own.
+pub const MINIMUM_ROCKY_BOND_ALBEDO: f64 = 0.05;
+
+/// Maximum Bond albedo of a rocky moon.
+pub const MAXIMUM_ROCKY_BOND_ALBEDO: f64 = 0.2;
+
+/// Minimum Bond albedo of an icy moon; old, dirty ice, like Callisto.
+pub const MINIMUM_ICY_BOND_ALBEDO: f64 = 0.1;
+
+/// Maximum Bond albedo of an icy moon; fresh snow, like Enceladus.
+pub const MAXIMUM_ICY_BOND_ALBEDO: f64 = 0.8;
+
+/// Minimum rotation period of a moon that isn't tidally locked, in days.
+pub const MINIMUM_ROTATION_PERIOD: f64 = 0.2;
+
+/// Maximum rotation period of a moon that isn't tidally locked, in days.
+pub const MAXIMUM_ROTATION_PERIOD: f64 = 3.0;
+
+/// Mearth per Mmoon.
+pub const EARTH_MASS_PER_MOON_MASS: f64 = 0.0123;
+
+/// The greatest orbital eccentricity of a regular moon.
+pub const MAXIMUM_REGULAR_ECCENTRICITY: f64 = 0.01;
+
+/// The greatest orbital inclination of a regular moon, in degrees.
+pub const MAXIMUM_REGULAR_INCLINATION: f64 = 0.5;
+
+/// Minimum mass of an irregular moon; they're mostly small captured rocks.
+pub const MINIMUM_IRREGULAR_MASS: f64 = 0.000_001;
+
+/// Maximum mass of an irregular moon.
+pub const MAXIMUM_IRREGULAR_MASS: f64 = 0.01;
+
+/// The least orbital eccentricity of an irregular moon.
+pub const MINIMUM_IRREGULAR_ECCENTRICITY: f64 = 0.1;
+
+/// The greatest orbital eccentricity of an irregular moon.
+pub const MAXIMUM_IRREGULAR_ECCENTRICITY: f64 = 0.6;
+
+/// The probability that an irregular moon orbits backwards.
+pub const IRREGULAR_RETROGRADE_PROBABILITY: f64 = 0.7;
+
+/// The least orbital inclination of a prograde irregular moon, in degrees;
+/// retrograde ones are tilted this far from upside-down.
+pub const MINIMUM_IRREGULAR_INCLINATION: f64 = 20.0;
+
+/// The greatest orbital inclination of a prograde irregular moon, in degrees.
+pub const MAXIMUM_IRREGULAR_INCLINATION: f64 = 60.0;
+
+/// The greatest orbital eccentricity of a giant-impact moon.
+pub const MAXIMUM_GIANT_IMPACT_ECCENTRICITY: f64 = 0.06;
+
+/// The greatest orbital inclination of a giant-impact moon, in degrees.
+pub const MAXIMUM_GIANT_IMPACT_INCLINATION: f64 = 6.0;
+
+/// Kilometers per Rmoon.
+pub const KM_PER_MOON_RADIUS: f64 = 1_737.4;
+
+/// KM/sec per Vearth, for escape velocity.
+pub const KM_PER_SECOND_PER_EARTH_ESCAPE_VELOCITY: f64 = 11.186;
+
+/// Kilograms per Mearth.
+pub const KG_PER_EARTH_MASS: f64 = 5.972E24;
+
+/// The gravitational constant, in m³/kg/s².
+pub const GRAVITATIONAL_CONSTANT: f64 = 6.674E-11;
+
+/// How readily a moon turns tidal flexing into heat, i.e. its Love number k2
+/// divided by its tidal quality factor Q; this is about Io's.
+pub const TIDAL_DISSIPATION_FACTOR: f64 = 0.015;
+
+/// The greenhouse effect of a moon with an atmosphere, relative to Earth's.
+pub const GREENHOUSE_EFFECT: f64 = 1.0;
+
+/// Minimum mass for a habitable moon, in Mmoon; about 0.75 Mearth.
+pub const MINIMUM_HABITABLE_MASS: f64 = 61.0;
+
+/// Maximum mass for a habitable moon, in Mmoon; about 1.5 Mearth.
+pub const MAXIMUM_HABITABLE_MASS: f64 = 122.0;
+
+/// Seconds per day.
+pub const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Seconds per Gyr.
+pub const SECONDS_PER_GIGAYEAR: f64 = 3.15576E16;
+
+/// How quickly a newborn moon spins, in days, before the planet's tides
+/// get hold of it.
+pub const INITIAL_ROTATION_PERIOD: f64 = 0.5;
+
+/// A moon's moment of inertia as a fraction of MR²; a uniform sphere.
+pub const MOMENT_OF_INERTIA_FACTOR: f64 = 0.4;
+
+/// Planets spin themselves down to within this fraction of the moon's
+/// orbital period, and then they're locked.
+pub const SYNCHRONOUS_ROTATION_TOLERANCE: f64 = 0.01;
+
+/// Tidal evolution moves a moon no more than this fraction of its orbit in
+/// a single step.
+pub const TIDAL_EVOLUTION_STEP_FRACTION: f64 = 0.01;
+
+/// Above this tidal heat flux, in W/m², a moon is covered in volcanoes, like
+/// Io (about 2.5).
+pub const VOLCANISM_HEAT_FLUX: f64 = 1.0;
+
+/// The tidal heat flux, in W/m², at which an icy moon is as likely as not to
+/// keep an ocean under its ice; Europa and Enceladus manage on less than 0.1.
+pub const SUBSURFACE_OCEAN_HEAT_FLUX: f64 = 0.02;
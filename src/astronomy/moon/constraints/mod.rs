@@ -3,6 +3,8 @@ use rand::prelude::*;
 use crate::astronomy::host_star::HostStar;
 use crate::astronomy::moon::constants::*;
 use crate::astronomy::moon::error::Error;
+use crate::astronomy::moon::moon_origin::MoonOrigin;
+use crate::astronomy::moon::rotation_direction::RotationDirection;
 use crate::astronomy::moon::Moon;
 use crate::astronomy::planet::Planet;
 
@@ -13,10 +15,48 @@ pub struct Constraints {
   pub minimum_mass: Option<f64>,
   /// The maximum mass, in Mmoon.
   pub maximum_mass: Option<f64>,
+  /// How the moon came to orbit the planet; regular, if not given.
+  pub origin: Option<MoonOrigin>,
+  /// The minimum density, in g/cm³.
+  pub minimum_density: Option<f64>,
+  /// The maximum density, in g/cm³.
+  pub maximum_density: Option<f64>,
+  /// The minimum Bond albedo.
+  pub minimum_bond_albedo: Option<f64>,
+  /// The maximum Bond albedo.
+  pub maximum_bond_albedo: Option<f64>,
+  /// The minimum orbital eccentricity.
+  pub minimum_orbital_eccentricity: Option<f64>,
+  /// The maximum orbital eccentricity.
+  pub maximum_orbital_eccentricity: Option<f64>,
+  /// The minimum orbital inclination, in degrees.
+  pub minimum_orbital_inclination: Option<f64>,
+  /// The maximum orbital inclination, in degrees.
+  pub maximum_orbital_inclination: Option<f64>,
+  /// The minimum rotation period, in days, if not tidally locked.
+  pub minimum_rotation_period: Option<f64>,
+  /// The maximum rotation period, in days, if not tidally locked.
+  pub maximum_rotation_period: Option<f64>,
 }
 
 impl Constraints {
+  /// A regular moon big enough to be habitable, if it's in the right place.
+  pub fn habitable() -> Self {
+    let minimum_mass = Some(MINIMUM_HABITABLE_MASS);
+    let maximum_mass = Some(MAXIMUM_HABITABLE_MASS);
+    let origin = Some(MoonOrigin::Regular);
+    Self {
+      minimum_mass,
+      maximum_mass,
+      origin,
+      ..Constraints::default()
+    }
+  }
+
   /// Generate.
+  ///
+  /// Beyond the frost line, moons are light and bright with ice; inside it,
+  /// they're dense, dark rock.  Their orbits depend on how they got there.
   #[named]
   pub fn generate<R: Rng + ?Sized>(
     &self,
@@ -31,13 +71,136 @@ impl Constraints {
     trace_var!(star_distance);
     trace_var!(planet);
     trace_var!(planet_distance);
-    let minimum_mass = self.minimum_mass.unwrap_or(MINIMUM_MASS);
+    let origin = self.origin.unwrap_or(MoonOrigin::Regular);
+    trace_var!(origin);
+    let (default_minimum_mass, default_maximum_mass) = match origin {
+      MoonOrigin::Irregular => (MINIMUM_IRREGULAR_MASS, MAXIMUM_IRREGULAR_MASS),
+      _ => (MINIMUM_MASS, MAXIMUM_MASS),
+    };
+    let minimum_mass = self.minimum_mass.unwrap_or(default_minimum_mass);
     trace_var!(minimum_mass);
-    let maximum_mass = self.maximum_mass.unwrap_or(MAXIMUM_MASS);
+    let maximum_mass = self.maximum_mass.unwrap_or(default_maximum_mass);
     trace_var!(maximum_mass);
-    let mass = rng.gen_range(minimum_mass..maximum_mass);
+    let mass = match minimum_mass < maximum_mass {
+      true => rng.gen_range(minimum_mass..maximum_mass),
+      false => minimum_mass,
+    };
     trace_var!(mass);
-    let result = Moon::from_environment(mass, host_star, star_distance, planet, planet_distance)?;
+    let is_icy = star_distance >= host_star.get_frost_line();
+    trace_var!(is_icy);
+    let (default_minimum_density, default_maximum_density, default_minimum_bond_albedo, default_maximum_bond_albedo) =
+      match is_icy {
+        true => (
+          MINIMUM_ICY_DENSITY,
+          MAXIMUM_ICY_DENSITY,
+          MINIMUM_ICY_BOND_ALBEDO,
+          MAXIMUM_ICY_BOND_ALBEDO,
+        ),
+        false => (
+          MINIMUM_ROCKY_DENSITY,
+          MAXIMUM_ROCKY_DENSITY,
+          MINIMUM_ROCKY_BOND_ALBEDO,
+          MAXIMUM_ROCKY_BOND_ALBEDO,
+        ),
+      };
+    let minimum_density = self.minimum_density.unwrap_or(default_minimum_density);
+    trace_var!(minimum_density);
+    let maximum_density = self.maximum_density.unwrap_or(default_maximum_density);
+    trace_var!(maximum_density);
+    let density = match minimum_density < maximum_density {
+      true => rng.gen_range(minimum_density..maximum_density),
+      false => minimum_density,
+    };
+    trace_var!(density);
+    let minimum_bond_albedo = self.minimum_bond_albedo.unwrap_or(default_minimum_bond_albedo);
+    trace_var!(minimum_bond_albedo);
+    let maximum_bond_albedo = self.maximum_bond_albedo.unwrap_or(default_maximum_bond_albedo);
+    trace_var!(maximum_bond_albedo);
+    let bond_albedo = match minimum_bond_albedo < maximum_bond_albedo {
+      true => rng.gen_range(minimum_bond_albedo..maximum_bond_albedo),
+      false => minimum_bond_albedo,
+    };
+    trace_var!(bond_albedo);
+    let mut result = Moon::from_environment(
+      mass,
+      density,
+      bond_albedo,
+      host_star,
+      star_distance,
+      planet,
+      planet_distance,
+    )?;
+    let (default_minimum_orbital_eccentricity, default_maximum_orbital_eccentricity) = match origin {
+      MoonOrigin::Regular | MoonOrigin::Trojan(_) => (0.0, MAXIMUM_REGULAR_ECCENTRICITY),
+      MoonOrigin::Irregular => (MINIMUM_IRREGULAR_ECCENTRICITY, MAXIMUM_IRREGULAR_ECCENTRICITY),
+      MoonOrigin::GiantImpact => (0.0, MAXIMUM_GIANT_IMPACT_ECCENTRICITY),
+    };
+    let minimum_orbital_eccentricity = self
+      .minimum_orbital_eccentricity
+      .unwrap_or(default_minimum_orbital_eccentricity);
+    trace_var!(minimum_orbital_eccentricity);
+    let maximum_orbital_eccentricity = self
+      .maximum_orbital_eccentricity
+      .unwrap_or(default_maximum_orbital_eccentricity);
+    trace_var!(maximum_orbital_eccentricity);
+    let orbital_eccentricity = match minimum_orbital_eccentricity < maximum_orbital_eccentricity {
+      true => rng.gen_range(minimum_orbital_eccentricity..maximum_orbital_eccentricity),
+      false => minimum_orbital_eccentricity,
+    };
+    let (default_minimum_orbital_inclination, default_maximum_orbital_inclination) = match origin {
+      MoonOrigin::Regular | MoonOrigin::Trojan(_) => (0.0, MAXIMUM_REGULAR_INCLINATION),
+      MoonOrigin::Irregular => (MINIMUM_IRREGULAR_INCLINATION, MAXIMUM_IRREGULAR_INCLINATION),
+      MoonOrigin::GiantImpact => (0.0, MAXIMUM_GIANT_IMPACT_INCLINATION),
+    };
+    let minimum_orbital_inclination = self
+      .minimum_orbital_inclination
+      .unwrap_or(default_minimum_orbital_inclination);
+    trace_var!(minimum_orbital_inclination);
+    let maximum_orbital_inclination = self
+      .maximum_orbital_inclination
+      .unwrap_or(default_maximum_orbital_inclination);
+    trace_var!(maximum_orbital_inclination);
+    let orbital_inclination = match minimum_orbital_inclination < maximum_orbital_inclination {
+      true => rng.gen_range(minimum_orbital_inclination..maximum_orbital_inclination),
+      false => minimum_orbital_inclination,
+    };
+    // Most captured moons go the wrong way around.
+    let orbital_inclination =
+      match origin == MoonOrigin::Irregular && rng.gen_range(0.0..1.0) < IRREGULAR_RETROGRADE_PROBABILITY {
+        true => 180.0 - orbital_inclination,
+        false => orbital_inclination,
+      };
+    result.orbital_eccentricity = orbital_eccentricity;
+    trace_var!(orbital_eccentricity);
+    let periapsis = (1.0 - orbital_eccentricity) * planet_distance;
+    result.periapsis = periapsis;
+    trace_var!(periapsis);
+    let apoapsis = (1.0 + orbital_eccentricity) * planet_distance;
+    result.apoapsis = apoapsis;
+    trace_var!(apoapsis);
+    result.orbital_inclination = orbital_inclination;
+    trace_var!(orbital_inclination);
+    let rotation_direction = match orbital_inclination < 90.0 {
+      true => RotationDirection::Prograde,
+      false => RotationDirection::Retrograde,
+    };
+    result.rotation_direction = rotation_direction;
+    trace_var!(rotation_direction);
+    result.origin = origin;
+    if !result.is_moon_tidally_locked {
+      let minimum_rotation_period = self.minimum_rotation_period.unwrap_or(MINIMUM_ROTATION_PERIOD);
+      trace_var!(minimum_rotation_period);
+      let maximum_rotation_period = self.maximum_rotation_period.unwrap_or(MAXIMUM_ROTATION_PERIOD);
+      trace_var!(maximum_rotation_period);
+      let rotation_period = match minimum_rotation_period < maximum_rotation_period {
+        true => rng.gen_range(minimum_rotation_period..maximum_rotation_period),
+        false => minimum_rotation_period,
+      };
+      result.rotation_period = rotation_period;
+      trace_var!(rotation_period);
+    }
+    // The tides depend on the orbit, so the temperature has to catch up.
+    result.update_temperature(host_star.get_luminosity(), star_distance, planet);
     trace_var!(result);
     trace_exit!();
     Ok(result)
@@ -49,9 +212,31 @@ impl Default for Constraints {
   fn default() -> Self {
     let minimum_mass = None;
     let maximum_mass = None;
+    let origin = None;
+    let minimum_density = None;
+    let maximum_density = None;
+    let minimum_bond_albedo = None;
+    let maximum_bond_albedo = None;
+    let minimum_orbital_eccentricity = None;
+    let maximum_orbital_eccentricity = None;
+    let minimum_orbital_inclination = None;
+    let maximum_orbital_inclination = None;
+    let minimum_rotation_period = None;
+    let maximum_rotation_period = None;
     Self {
       minimum_mass,
       maximum_mass,
+      origin,
+      minimum_density,
+      maximum_density,
+      minimum_bond_albedo,
+      maximum_bond_albedo,
+      minimum_orbital_eccentricity,
+      maximum_orbital_eccentricity,
+      minimum_orbital_inclination,
+      maximum_orbital_inclination,
+      minimum_rotation_period,
+      maximum_rotation_period,
     }
   }
 }
@@ -87,4 +272,77 @@ pub mod test {
     trace_exit!();
     Ok(())
   }
+
+  #[named]
+  #[test]
+  pub fn test_composition() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let host_star = &HostStarConstraints::habitable().generate(&mut rng)?;
+    trace_var!(host_star);
+    let planet_constraints = PlanetConstraints {
+      mass: Some(317.8),
+      ..PlanetConstraints::default()
+    };
+    let star_distance = host_star.get_frost_line() * 2.0;
+    let planet = &planet_constraints.generate(&mut rng, host_star, star_distance)?;
+    let moon = &Constraints::default().generate(&mut rng, host_star, star_distance, planet, 1_000_000.0)?;
+    print_var!(moon);
+    assert!(moon.density >= MINIMUM_ICY_DENSITY && moon.density <= MAXIMUM_ICY_DENSITY);
+    assert!(moon.bond_albedo >= MINIMUM_ICY_BOND_ALBEDO && moon.bond_albedo <= MAXIMUM_ICY_BOND_ALBEDO);
+    let star_distance = host_star.get_frost_line() / 2.0;
+    let planet = &planet_constraints.generate(&mut rng, host_star, star_distance)?;
+    let constraints = Constraints {
+      minimum_orbital_eccentricity: Some(0.2),
+      maximum_orbital_eccentricity: Some(0.2),
+      minimum_orbital_inclination: Some(120.0),
+      maximum_orbital_inclination: Some(120.0),
+      ..Constraints::default()
+    };
+    let moon = &constraints.generate(&mut rng, host_star, star_distance, planet, 1_000_000.0)?;
+    print_var!(moon);
+    assert!(moon.density >= MINIMUM_ROCKY_DENSITY && moon.density <= MAXIMUM_ROCKY_DENSITY);
+    assert!(moon.bond_albedo >= MINIMUM_ROCKY_BOND_ALBEDO && moon.bond_albedo <= MAXIMUM_ROCKY_BOND_ALBEDO);
+    assert_approx_eq!(moon.orbital_eccentricity, 0.2);
+    assert_approx_eq!(moon.orbital_inclination, 120.0);
+    assert_eq!(moon.rotation_direction, RotationDirection::Retrograde);
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_evolve_tides() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let host_star = &HostStarConstraints::habitable().generate(&mut rng)?;
+    trace_var!(host_star);
+    let star_distance = host_star.get_luminosity().sqrt();
+    let planet_constraints = PlanetConstraints {
+      mass: Some(1.0),
+      ..PlanetConstraints::default()
+    };
+    let planet = &planet_constraints.generate(&mut rng, host_star, star_distance)?;
+    // Something like our own Moon, just after the impact.
+    let constraints = Constraints {
+      minimum_mass: Some(1.0),
+      maximum_mass: Some(1.0),
+      origin: Some(MoonOrigin::GiantImpact),
+      ..Constraints::default()
+    };
+    let mut moon = constraints.generate(&mut rng, host_star, star_distance, planet, 150_000.0)?;
+    let rotation_period = moon.evolve_tides(host_star, star_distance, planet, 0.25)?;
+    print_var!(moon);
+    print_var!(rotation_period);
+    assert!(moon.semi_major_axis > 150_000.0);
+    assert!(rotation_period > 0.25);
+    assert!(moon.is_moon_tidally_locked);
+    assert!(rotation_period <= moon.sidereal_orbital_period * 1.01);
+    trace_exit!();
+    Ok(())
+  }
 }
@@ -8,6 +8,26 @@ pub enum Error {
   HostStarError(HostStarError),
   /// Planet Error.
   PlanetError(PlanetError),
+  /// Frozen solid.
+  TooColdToSupportConventionalLife,
+  /// Baked, by the star or the planet's tides.
+  TooHotToSupportConventionalLife,
+  /// Hard to fight when people keep floating off into space.
+  GravityTooLowToSupportConventionalLife,
+  /// Just sounds kinda lame.
+  GravityTooHighToSupportConventionalLife,
+  /// Oxygen unstable in this atmosphere.
+  AtmosphereUnstableForOxygen,
+  /// Carbon Dioxide unstable in this atmosphere.
+  AtmosphereUnstableForCarbonDioxide,
+  /// Argon unstable in this atmosphere.
+  AtmosphereUnstableForArgon,
+  /// Nitrogen unstable in this atmosphere.
+  AtmosphereUnstableForNitrogen,
+  /// The host star is busy sandblasting the atmosphere away.
+  XuvFluxTooHighToSupportConventionalLife,
+  /// The host star keeps blowing off the ozone layer.
+  FlaresTooFrequentToSupportConventionalLife,
 }
 
 honeyholt_define_brief!(Error, |error: &Error| {
@@ -18,6 +38,16 @@ honeyholt_define_brief!(Error, |error: &Error| {
       honeyholt_brief!(host_star_error)
     ),
     PlanetError(planet_error) => format!("an error occurred in the planet ({})", honeyholt_brief!(planet_error)),
+    TooColdToSupportConventionalLife => "not habitable because it is too cold".to_string(),
+    TooHotToSupportConventionalLife => "not habitable because it is too hot".to_string(),
+    GravityTooLowToSupportConventionalLife => "not habitable because its gravity is too low".to_string(),
+    GravityTooHighToSupportConventionalLife => "not habitable because its gravity is too high".to_string(),
+    AtmosphereUnstableForOxygen => "not habitable because it cannot retain oxygen".to_string(),
+    AtmosphereUnstableForCarbonDioxide => "not habitable because it cannot retain carbon dioxide".to_string(),
+    AtmosphereUnstableForArgon => "not habitable because it cannot retain argon".to_string(),
+    AtmosphereUnstableForNitrogen => "not habitable because it cannot retain nitrogen".to_string(),
+    XuvFluxTooHighToSupportConventionalLife => "not habitable because its star's XUV flux is too high".to_string(),
+    FlaresTooFrequentToSupportConventionalLife => "not habitable because its star flares too often".to_string(),
   }
 });
 
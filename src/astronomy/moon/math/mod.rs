@@ -1 +1,5 @@
+pub mod orbital_period;
+pub mod temperature;
+pub mod tidal_evolution;
+pub mod tidal_heating;
 pub mod tides;
@@ -0,0 +1,43 @@
+use std::f64::consts::PI;
+
+use crate::astronomy::moon::constants::*;
+
+/// Get the sidereal orbital period of a moon, in days.
+///
+/// `planet_mass` - mass of the planet, in Mearth.
+/// `moon_mass` - mass of the moon, in Mmoon.
+/// `semi_major_axis` - semi-major axis of the moon's orbit, in KM.
+#[named]
+pub fn get_sidereal_orbital_period(planet_mass: f64, moon_mass: f64, semi_major_axis: f64) -> f64 {
+  trace_enter!();
+  trace_var!(planet_mass);
+  trace_var!(moon_mass);
+  trace_var!(semi_major_axis);
+  let total_mass = (planet_mass + moon_mass * EARTH_MASS_PER_MOON_MASS) * KG_PER_EARTH_MASS;
+  trace_var!(total_mass);
+  let semi_major_axis = semi_major_axis * 1_000.0;
+  let result = 2.0 * PI * (semi_major_axis.powf(3.0) / (GRAVITATIONAL_CONSTANT * total_mass)).sqrt() / SECONDS_PER_DAY;
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_sidereal_orbital_period() {
+    init();
+    trace_enter!();
+    let actual = get_sidereal_orbital_period(1.0, 1.0, 384_400.0);
+    print_var!(actual);
+    assert_approx_eq!(actual, 27.32, 0.1);
+    // Io goes around Jupiter in under two days.
+    assert_approx_eq!(get_sidereal_orbital_period(317.8, 1.215, 421_700.0), 1.769, 0.01);
+    trace_exit!();
+  }
+}
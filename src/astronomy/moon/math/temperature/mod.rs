@@ -0,0 +1,71 @@
+use crate::astronomy::terrestrial_planet::constants::STEFAN_BOLTZMANN_CONSTANT;
+use crate::astronomy::terrestrial_planet::math::temperature::{
+  get_equilibrium_temperature as get_planet_equilibrium_temperature, GREENHOUSE_EFFECT as GREENHOUSE_FACTOR,
+};
+
+/// Calculate the equilibrium temperature of a moon, in Kelvin.
+///
+/// This starts from what the star alone would give it, same as for a planet
+/// (star luminosity in Lsol, distance in AU), then adds the light the planet
+/// reflects and re-radiates onto it (planet radius and moon distance in KM),
+/// and the tidal heat welling up from inside (in W/m²).
+#[named]
+pub fn get_equilibrium_temperature(
+  bond_albedo: f64,
+  greenhouse_effect: f64,
+  star_luminosity: f64,
+  star_distance: f64,
+  planet_radius: f64,
+  planet_distance: f64,
+  tidal_heat_flux: f64,
+) -> f64 {
+  trace_enter!();
+  trace_var!(bond_albedo);
+  trace_var!(greenhouse_effect);
+  trace_var!(star_luminosity);
+  trace_var!(star_distance);
+  trace_var!(planet_radius);
+  trace_var!(planet_distance);
+  trace_var!(tidal_heat_flux);
+  let stellar_temperature =
+    get_planet_equilibrium_temperature(bond_albedo, greenhouse_effect, star_luminosity, star_distance);
+  trace_var!(stellar_temperature);
+  // The planet re-emits everything it intercepts, spread over a sphere the
+  // size of the moon's orbit.
+  let planetary_factor = 1.0 + (planet_radius / planet_distance).powf(2.0) / 4.0;
+  trace_var!(planetary_factor);
+  // The same greenhouse and surface corrections as for starlight; the flux is
+  // converted from W/m² to erg/cm²/s.
+  let greenhouse_factor = (1.0 + 3.0 * greenhouse_effect * GREENHOUSE_FACTOR / 4.0) / 0.9;
+  trace_var!(greenhouse_factor);
+  let tidal_term = greenhouse_factor * tidal_heat_flux * 1_000.0 / STEFAN_BOLTZMANN_CONSTANT;
+  trace_var!(tidal_term);
+  let result = (stellar_temperature.powf(4.0) * planetary_factor + tidal_term).powf(1.0 / 4.0);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_equilibrium_temperature() {
+    init();
+    trace_enter!();
+    let far = get_equilibrium_temperature(0.3, 1.0, 1.0, 1.0, 70_000.0, 1.0E12, 0.0);
+    let planet = get_planet_equilibrium_temperature(0.3, 1.0, 1.0, 1.0);
+    assert_approx_eq!(far, planet, 0.01);
+    // Close to the planet, and squeezed by it, it's warmer.
+    let near = get_equilibrium_temperature(0.3, 1.0, 1.0, 1.0, 70_000.0, 400_000.0, 0.0);
+    assert!(near > far);
+    let heated = get_equilibrium_temperature(0.3, 1.0, 1.0, 1.0, 70_000.0, 400_000.0, 2.0);
+    print_var!((far, near, heated));
+    assert!(heated > near);
+    trace_exit!();
+  }
+}
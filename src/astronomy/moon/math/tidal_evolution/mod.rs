@@ -0,0 +1,161 @@
+use std::f64::consts::PI;
+
+use crate::astronomy::moon::constants::*;
+use crate::astronomy::moon::math::orbital_period::get_sidereal_orbital_period;
+
+/// Get how quickly a moon's orbit is moving, in KM/Gyr, because of the tides
+/// it raises on the planet (Murray & Dermott, 1999).
+///
+/// `planet_mass` - mass of the planet, in Mearth.
+/// `planet_radius` - radius of the planet, in KM.
+/// `tidal_dissipation_factor` - the planet's k2/Q.
+/// `moon_mass` - mass of the moon, in Mmoon.
+/// `semi_major_axis` - semi-major axis of the moon's orbit, in KM.
+/// `rotation_period` - the planet's rotation period, in days.
+///
+/// A planet spinning faster than the moon goes around drags it outward, like
+/// ours; one spinning slower pulls it in, like Mars and Phobos.
+#[named]
+pub fn get_migration_rate(
+  planet_mass: f64,
+  planet_radius: f64,
+  tidal_dissipation_factor: f64,
+  moon_mass: f64,
+  semi_major_axis: f64,
+  rotation_period: f64,
+) -> f64 {
+  trace_enter!();
+  trace_var!(planet_mass);
+  trace_var!(planet_radius);
+  trace_var!(tidal_dissipation_factor);
+  trace_var!(moon_mass);
+  trace_var!(semi_major_axis);
+  trace_var!(rotation_period);
+  let orbital_period = get_sidereal_orbital_period(planet_mass, moon_mass, semi_major_axis);
+  trace_var!(orbital_period);
+  let direction = match rotation_period < orbital_period {
+    true => 1.0,
+    false => -1.0,
+  };
+  trace_var!(direction);
+  let mean_motion = 2.0 * PI / (orbital_period * SECONDS_PER_DAY);
+  trace_var!(mean_motion);
+  let mass_ratio = moon_mass * EARTH_MASS_PER_MOON_MASS / planet_mass;
+  trace_var!(mass_ratio);
+  // In m/s, then KM/Gyr.
+  let rate = direction
+    * 3.0
+    * tidal_dissipation_factor
+    * mass_ratio
+    * (planet_radius / semi_major_axis).powf(5.0)
+    * mean_motion
+    * semi_major_axis
+    * 1_000.0;
+  let result = rate * SECONDS_PER_GIGAYEAR / 1_000.0;
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the spin angular momentum of a planet, in kg·m²/s, given its mass in
+/// Mearth, radius in KM, moment of inertia factor, and rotation period in
+/// days.
+#[named]
+pub fn get_spin_angular_momentum(
+  planet_mass: f64,
+  planet_radius: f64,
+  moment_of_inertia_factor: f64,
+  rotation_period: f64,
+) -> f64 {
+  trace_enter!();
+  trace_var!(planet_mass);
+  trace_var!(planet_radius);
+  trace_var!(moment_of_inertia_factor);
+  trace_var!(rotation_period);
+  let moment_of_inertia =
+    moment_of_inertia_factor * planet_mass * KG_PER_EARTH_MASS * (planet_radius * 1_000.0).powf(2.0);
+  trace_var!(moment_of_inertia);
+  let result = moment_of_inertia * 2.0 * PI / (rotation_period * SECONDS_PER_DAY);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the rotation period of a planet, in days, given its mass in Mearth,
+/// radius in KM, moment of inertia factor, and spin angular momentum in
+/// kg·m²/s.
+#[named]
+pub fn get_rotation_period(
+  planet_mass: f64,
+  planet_radius: f64,
+  moment_of_inertia_factor: f64,
+  spin_angular_momentum: f64,
+) -> f64 {
+  trace_enter!();
+  trace_var!(planet_mass);
+  trace_var!(planet_radius);
+  trace_var!(moment_of_inertia_factor);
+  trace_var!(spin_angular_momentum);
+  let moment_of_inertia =
+    moment_of_inertia_factor * planet_mass * KG_PER_EARTH_MASS * (planet_radius * 1_000.0).powf(2.0);
+  trace_var!(moment_of_inertia);
+  let result = moment_of_inertia * 2.0 * PI / (spin_angular_momentum * SECONDS_PER_DAY);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the orbital angular momentum of a moon, in kg·m²/s, given the planet's
+/// mass in Mearth, the moon's mass in Mmoon, and its distance in KM.
+#[named]
+pub fn get_orbital_angular_momentum(planet_mass: f64, moon_mass: f64, semi_major_axis: f64) -> f64 {
+  trace_enter!();
+  trace_var!(planet_mass);
+  trace_var!(moon_mass);
+  trace_var!(semi_major_axis);
+  let planet_mass = planet_mass * KG_PER_EARTH_MASS;
+  let moon_mass = moon_mass * EARTH_MASS_PER_MOON_MASS * KG_PER_EARTH_MASS;
+  let reduced_mass = planet_mass * moon_mass / (planet_mass + moon_mass);
+  trace_var!(reduced_mass);
+  let result = reduced_mass * (GRAVITATIONAL_CONSTANT * (planet_mass + moon_mass) * semi_major_axis * 1_000.0).sqrt();
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_migration_rate() {
+    init();
+    trace_enter!();
+    // Our Moon is backing away at a bit under 4 cm/year.
+    let rate = get_migration_rate(1.0, 6_371.0, 0.025, 1.0, 384_400.0, 1.0);
+    print_var!(rate);
+    assert_approx_eq!(rate / 38_000.0, 1.0, 0.1);
+    // Phobos is falling in.
+    assert!(get_migration_rate(0.107, 3_390.0, 0.025, 1.45E-7, 9_376.0, 1.026) < 0.0);
+    trace_exit!();
+  }
+
+  #[named]
+  #[test]
+  pub fn test_angular_momentum() {
+    init();
+    trace_enter!();
+    let spin_angular_momentum = get_spin_angular_momentum(1.0, 6_371.0, 0.33, 0.997);
+    print_var!(spin_angular_momentum);
+    assert_approx_eq!(spin_angular_momentum / 5.86E33, 1.0, 0.01);
+    assert_approx_eq!(get_rotation_period(1.0, 6_371.0, 0.33, spin_angular_momentum), 0.997);
+    // The Moon carries most of the Earth-Moon system's angular momentum.
+    let orbital_angular_momentum = get_orbital_angular_momentum(1.0, 1.0, 384_400.0);
+    print_var!(orbital_angular_momentum);
+    assert_approx_eq!(orbital_angular_momentum / 2.87E34, 1.0, 0.05);
+    trace_exit!();
+  }
+}
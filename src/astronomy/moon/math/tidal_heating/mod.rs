@@ -0,0 +1,103 @@
+use crate::astronomy::moon::constants::*;
+
+/// Get the heat, in watts, raised in a moon by the planet flexing it over
+/// the course of an eccentric orbit.
+///
+/// `planet_mass` - mass of the planet, in Mearth.
+/// `moon_radius` - radius of the moon, in KM.
+/// `semi_major_axis` - semi-major axis of the moon's orbit, in KM.
+/// `orbital_eccentricity` - eccentricity of the moon's orbit.
+///
+/// This is the usual formula for a synchronously rotating moon (Segatz et
+/// al., 1988); a perfectly circular orbit raises no heat at all.
+#[named]
+pub fn get_tidal_heating(planet_mass: f64, moon_radius: f64, semi_major_axis: f64, orbital_eccentricity: f64) -> f64 {
+  trace_enter!();
+  trace_var!(planet_mass);
+  trace_var!(moon_radius);
+  trace_var!(semi_major_axis);
+  trace_var!(orbital_eccentricity);
+  let planet_mass = planet_mass * KG_PER_EARTH_MASS;
+  let moon_radius = moon_radius * 1_000.0;
+  let semi_major_axis = semi_major_axis * 1_000.0;
+  let mean_motion = (GRAVITATIONAL_CONSTANT * planet_mass / semi_major_axis.powf(3.0)).sqrt();
+  trace_var!(mean_motion);
+  let result = 10.5
+    * TIDAL_DISSIPATION_FACTOR
+    * GRAVITATIONAL_CONSTANT
+    * planet_mass.powf(2.0)
+    * moon_radius.powf(5.0)
+    * mean_motion
+    * orbital_eccentricity.powf(2.0)
+    / semi_major_axis.powf(6.0);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the tidal heat flux through a moon's surface, in W/m², given the tidal
+/// heating in watts and its radius in KM.
+#[named]
+pub fn get_tidal_heat_flux(tidal_heating: f64, moon_radius: f64) -> f64 {
+  trace_enter!();
+  trace_var!(tidal_heating);
+  trace_var!(moon_radius);
+  let result = tidal_heating / (4.0 * std::f64::consts::PI * (moon_radius * 1_000.0).powf(2.0));
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Indicate whether a moon's tidal heat flux, in W/m², is enough to keep
+/// volcanoes going all over it, like Io.
+#[named]
+pub fn is_volcanically_active(tidal_heat_flux: f64) -> bool {
+  trace_enter!();
+  trace_var!(tidal_heat_flux);
+  let result = tidal_heat_flux >= VOLCANISM_HEAT_FLUX;
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the likelihood that an icy moon keeps liquid water under its ice,
+/// given its tidal heat flux in W/m².
+#[named]
+pub fn get_subsurface_ocean_probability(tidal_heat_flux: f64) -> f64 {
+  trace_enter!();
+  trace_var!(tidal_heat_flux);
+  let result = tidal_heat_flux / (tidal_heat_flux + SUBSURFACE_OCEAN_HEAT_FLUX);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_tidal_heating() {
+    init();
+    trace_enter!();
+    // Io puts out about 10^14 W, or 2-3 W/m².
+    let tidal_heating = get_tidal_heating(317.8, 1_821.6, 421_700.0, 0.0041);
+    print_var!(tidal_heating);
+    assert!(tidal_heating > 5.0E13 && tidal_heating < 2.0E14);
+    let tidal_heat_flux = get_tidal_heat_flux(tidal_heating, 1_821.6);
+    print_var!(tidal_heat_flux);
+    assert!(tidal_heat_flux > 1.0 && tidal_heat_flux < 5.0);
+    assert!(is_volcanically_active(tidal_heat_flux));
+    assert_eq!(get_tidal_heating(317.8, 1_821.6, 421_700.0, 0.0), 0.0);
+    // Europa gets a few percent of that, but it's enough for an ocean.
+    let tidal_heat_flux = get_tidal_heat_flux(get_tidal_heating(317.8, 1_560.8, 671_100.0, 0.009), 1_560.8);
+    print_var!(tidal_heat_flux);
+    assert!(!is_volcanically_active(tidal_heat_flux));
+    assert!(get_subsurface_ocean_probability(tidal_heat_flux) > 0.5);
+    assert_eq!(get_subsurface_ocean_probability(0.0), 0.0);
+    trace_exit!();
+  }
+}
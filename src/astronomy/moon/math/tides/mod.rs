@@ -1,3 +1,5 @@
+use crate::astronomy::moon::constants::*;
+
 /// Calculate the magnitude of the lunar tide.
 /// `lunar_mass` - mass of the moon, in Mmoon.
 /// `planet_radius`  - radius of the planet, in Rearth.
@@ -77,29 +79,114 @@ pub fn get_neap_tide(lunar_tide: f64, solar_tide: f64) -> f64 {
   result
 }
 
-/// Determine whether the planet is tidally locked.
+/// Calculate how long it takes a body's tides to slow its spin to match its
+/// orbit, in Gyr (Gladman et al., 1996).
+///
+/// `rotation_period` - its initial rotation period, in days.
+/// `mass` - its mass, in Mearth.
+/// `radius` - its radius, in KM.
+/// `moment_of_inertia_factor` - its moment of inertia, as a fraction of MR².
+/// `tidal_dissipation_factor` - its k2/Q.
+/// `perturber_mass` - mass of the body raising the tides, in Mearth.
+/// `semi_major_axis` - the distance between them, in KM.
 #[named]
-pub fn is_planet_tidally_locked(lunar_tide: f64, solar_tide: f64, star_age: f64, planet_mass: f64) -> bool {
+pub fn get_tidal_locking_timescale(
+  rotation_period: f64,
+  mass: f64,
+  radius: f64,
+  moment_of_inertia_factor: f64,
+  tidal_dissipation_factor: f64,
+  perturber_mass: f64,
+  semi_major_axis: f64,
+) -> f64 {
   trace_enter!();
-  trace_var!(lunar_tide);
-  trace_var!(solar_tide);
-  trace_var!(star_age);
-  trace_var!(planet_mass);
-  let result = ((lunar_tide + solar_tide) * star_age / planet_mass) > 50.0;
+  trace_var!(rotation_period);
+  trace_var!(mass);
+  trace_var!(radius);
+  trace_var!(moment_of_inertia_factor);
+  trace_var!(tidal_dissipation_factor);
+  trace_var!(perturber_mass);
+  trace_var!(semi_major_axis);
+  let spin_rate = 2.0 * std::f64::consts::PI / (rotation_period * SECONDS_PER_DAY);
+  let mass = mass * KG_PER_EARTH_MASS;
+  let radius = radius * 1_000.0;
+  let perturber_mass = perturber_mass * KG_PER_EARTH_MASS;
+  let semi_major_axis = semi_major_axis * 1_000.0;
+  let seconds = spin_rate * semi_major_axis.powf(6.0) * moment_of_inertia_factor * mass
+    / (3.0 * GRAVITATIONAL_CONSTANT * perturber_mass.powf(2.0) * tidal_dissipation_factor * radius.powf(3.0));
+  let result = seconds / SECONDS_PER_GIGAYEAR;
   trace_var!(result);
   trace_exit!();
   result
 }
 
-/// Determine whether the moon is tidally locked.
+/// Determine whether the planet is tidally locked to the moon, given the
+/// planet's mass (in Mearth), radius (in KM), moment of inertia factor, and
+/// k2/Q, the moon's mass (in Mmoon) and distance (in KM), and the age of the
+/// system (in Gyr).
+///
+/// This assumes the planet started out spinning as fast as a newborn moon;
+/// the tidal evolution of the satellite system does better.
 #[named]
-pub fn is_moon_tidally_locked(solar_tide: f64, planet_tide: f64, star_age: f64, moon_mass: f64) -> bool {
+pub fn is_planet_tidally_locked(
+  planet_mass: f64,
+  planet_radius: f64,
+  moment_of_inertia_factor: f64,
+  tidal_dissipation_factor: f64,
+  moon_mass: f64,
+  semi_major_axis: f64,
+  star_age: f64,
+) -> bool {
   trace_enter!();
-  trace_var!(solar_tide);
-  trace_var!(planet_tide);
+  trace_var!(planet_mass);
+  trace_var!(planet_radius);
+  trace_var!(moon_mass);
+  trace_var!(semi_major_axis);
   trace_var!(star_age);
+  let timescale = get_tidal_locking_timescale(
+    INITIAL_ROTATION_PERIOD,
+    planet_mass,
+    planet_radius,
+    moment_of_inertia_factor,
+    tidal_dissipation_factor,
+    moon_mass * EARTH_MASS_PER_MOON_MASS,
+    semi_major_axis,
+  );
+  trace_var!(timescale);
+  let result = timescale < star_age;
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Determine whether the moon is tidally locked to the planet, given the
+/// planet's mass (in Mearth), the moon's mass (in Mmoon), radius (in Rmoon),
+/// and distance (in KM), and the age of the system (in Gyr).
+#[named]
+pub fn is_moon_tidally_locked(
+  planet_mass: f64,
+  moon_mass: f64,
+  moon_radius: f64,
+  semi_major_axis: f64,
+  star_age: f64,
+) -> bool {
+  trace_enter!();
+  trace_var!(planet_mass);
   trace_var!(moon_mass);
-  let result = (((solar_tide + planet_tide) * star_age) / (moon_mass * 0.0123)) > 50.0;
+  trace_var!(moon_radius);
+  trace_var!(semi_major_axis);
+  trace_var!(star_age);
+  let timescale = get_tidal_locking_timescale(
+    INITIAL_ROTATION_PERIOD,
+    moon_mass * EARTH_MASS_PER_MOON_MASS,
+    moon_radius * KM_PER_MOON_RADIUS,
+    MOMENT_OF_INERTIA_FACTOR,
+    TIDAL_DISSIPATION_FACTOR,
+    planet_mass,
+    semi_major_axis,
+  );
+  trace_var!(timescale);
+  let result = timescale < star_age;
   trace_var!(result);
   trace_exit!();
   result
@@ -175,4 +262,23 @@ pub mod test {
     assert_approx_eq!(expected, actual, 0.001);
     trace_exit!();
   }
+
+  #[named]
+  #[test]
+  pub fn test_tidal_locking() {
+    init();
+    trace_enter!();
+    // Our Moon locked up in short order, but the Earth is still spinning; its
+    // tides have been weaker on average than they are today.
+    let timescale = get_tidal_locking_timescale(0.5, 0.0123, 1_737.4, 0.4, 0.015, 1.0, 384_400.0);
+    print_var!(timescale);
+    assert!(timescale < 0.1);
+    assert!(is_moon_tidally_locked(1.0, 1.0, 1.0, 384_400.0, 4.6));
+    assert!(!is_planet_tidally_locked(1.0, 6_371.0, 0.33, 0.01, 1.0, 384_400.0, 4.6));
+    // Pluto and Charon have locked each other.
+    assert!(is_planet_tidally_locked(
+      0.0022, 1_188.0, 0.33, 0.025, 0.0216, 19_591.0, 4.6
+    ));
+    trace_exit!();
+  }
 }
@@ -1,31 +1,48 @@
 use crate::astronomy::host_star::HostStar;
+use crate::astronomy::planet::constants::KM_PER_EARTH_RADIUS;
 use crate::astronomy::planet::Planet;
+use crate::astronomy::star::math::activity::get_flare_energy_for_fluence;
+use crate::astronomy::terrestrial_planet::constants::{
+  HAZARDOUS_FLARE_FLUENCE, MAXIMUM_HABITABLE_GRAVITY, MAXIMUM_HABITABLE_HAZARDOUS_FLARE_RATE,
+  MAXIMUM_HABITABLE_TEMPERATURE, MAXIMUM_HABITABLE_XUV_FLUX, MINIMUM_HABITABLE_GRAVITY, MINIMUM_HABITABLE_TEMPERATURE,
+};
+use crate::astronomy::terrestrial_planet::math::atmospheric_stability::{
+  is_argon_stable, is_atmospherically_stable, is_carbon_dioxide_stable, is_nitrogen_stable, is_oxygen_stable,
+};
 
 pub mod constants;
+use constants::*;
 pub mod constraints;
 pub mod error;
 use error::Error;
 pub mod math;
+use math::orbital_period::get_sidereal_orbital_period;
+use math::temperature::get_equilibrium_temperature;
+use math::tidal_evolution::{
+  get_migration_rate, get_orbital_angular_momentum, get_rotation_period, get_spin_angular_momentum,
+};
+use math::tidal_heating::{
+  get_subsurface_ocean_probability, get_tidal_heat_flux, get_tidal_heating, is_volcanically_active,
+};
 use math::tides::{
   get_lunar_tide, get_neap_tide, get_planetary_tide, get_solar_tide, get_spring_tide, is_moon_tidally_locked,
   is_planet_tidally_locked,
 };
+pub mod moon_origin;
+use moon_origin::MoonOrigin;
 pub mod rotation_direction;
 use rotation_direction::RotationDirection;
 
 /// A `Moon`, mercifully, is a fairly simple concept.
 ///
-/// It's possible that at some point, we might make moons habitable.
-///
-/// For instance, a habitable moon of a hot jupiter gas giant.
-///
-/// But for now, we're just staying with terrestrial planets, and we'll assume
-/// that moons are just celestial features.
+/// Most are just celestial features, but a big enough moon of a giant in the
+/// habitable zone can hold on to an atmosphere, and the planet warms it a bit
+/// with reflected light and a lot with tidal flexing.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Moon {
   /// The mass of this moon, in Mmoon.
   pub mass: f64,
-  /// The density of this moon, in Dmoon.
+  /// The density of this moon, in g/cm³.
   pub density: f64,
   /// The radius of this moon, in Rmoon.
   pub radius: f64,
@@ -47,11 +64,11 @@ pub struct Moon {
   pub orbital_inclination: f64,
   /// Rotation direction.
   pub rotation_direction: RotationDirection,
-  /// Sidereal orbital period.
+  /// Sidereal orbital period, in days.
   pub sidereal_orbital_period: f64,
-  /// Normal orbital period.
+  /// Normal orbital period, in days.
   pub orbital_period: f64,
-  /// Rotational period.
+  /// Rotational period, in days.
   pub rotation_period: f64,
   /// Lunar tide.
   pub lunar_tide: f64,
@@ -67,12 +84,35 @@ pub struct Moon {
   pub is_planet_tidally_locked: bool,
   /// If the moon is tidally locked to the planet.
   pub is_moon_tidally_locked: bool,
+  /// How the moon came to orbit the planet.
+  pub origin: MoonOrigin,
+  /// Heat raised by the planet's tides, in watts.
+  pub tidal_heating: f64,
+  /// Whether the tides keep it covered in volcanoes.
+  pub is_volcanically_active: bool,
+  /// The likelihood of an ocean under the ice, if it's icy.
+  pub subsurface_ocean_probability: f64,
+  /// Greenhouse effect.
+  pub greenhouse_effect: f64,
+  /// Equilibrium temperature, in Kelvin.
+  pub equilibrium_temperature: f64,
+  /// Whether we can retain the gases necessary for conventional life.
+  pub is_atmospherically_stable: bool,
+  /// X-ray and extreme UV flux from the host star, in erg/cm²/s.
+  pub xuv_flux: f64,
+  /// The rate of flares from the host star that are hazardous at this
+  /// distance, per year.
+  pub hazardous_flare_rate: f64,
 }
 
 impl Moon {
+  /// Calculate a moon in a circular, flat orbit, given its mass in Mmoon,
+  /// density in g/cm³, and Bond albedo.
   #[named]
   pub fn from_environment(
     mass: f64,
+    density: f64,
+    bond_albedo: f64,
     host_star: &HostStar,
     star_distance: f64,
     planet: &Planet,
@@ -83,8 +123,9 @@ impl Moon {
     trace_var!(star_distance);
     trace_var!(planet);
     trace_var!(planet_distance);
-    let density = 3.34;
+    trace_var!(mass);
     trace_var!(density);
+    trace_var!(bond_albedo);
     let radius = (mass / (density / 3.34)).powf(1.0 / 3.0);
     trace_var!(radius);
     // This gives gravity in Earth equivalents, since other units are relative
@@ -94,33 +135,38 @@ impl Moon {
     // This is in KM/sec.
     let escape_velocity = (mass / radius).sqrt() * 2.380;
     trace_var!(escape_velocity);
-    // Peg this to the albedo of the Moon for the time being.
-    let bond_albedo = 0.136;
-    trace_var!(bond_albedo);
     let semi_major_axis = planet_distance;
     trace_var!(semi_major_axis);
-    // Pegged for the time being.
-    let orbital_eccentricity = 0.05;
+    let orbital_eccentricity = 0.0;
     trace_var!(orbital_eccentricity);
     let periapsis = (1.0 - orbital_eccentricity) * semi_major_axis;
     trace_var!(periapsis);
     let apoapsis = (1.0 + orbital_eccentricity) * semi_major_axis;
     trace_var!(apoapsis);
-    // Pegged.
-    let orbital_inclination = 5.15;
+    let orbital_inclination = 0.0;
     trace_var!(orbital_inclination);
     let rotation_direction = RotationDirection::Prograde;
     trace_var!(rotation_direction);
-    let sidereal_orbital_period =
-      0.0588 * ((semi_major_axis / 12_742.0 * 2.0).powf(3.0) / (planet.get_mass() + mass * 0.0123)).sqrt();
+    let origin = MoonOrigin::Regular;
+    trace_var!(origin);
+    let planet_mass = planet.get_mass_in_earth_masses();
+    trace_var!(planet_mass);
+    // This is in km; the tides below want it in Rearth, so they convert it.
+    let planet_radius = planet.get_radius_in_km();
+    trace_var!(planet_radius);
+    let sidereal_orbital_period = get_sidereal_orbital_period(planet_mass, mass, semi_major_axis);
     trace_var!(sidereal_orbital_period);
     let earth_orbital_period = planet.get_orbital_period() * 365.265;
     trace_var!(earth_orbital_period);
     let orbital_period = earth_orbital_period / (earth_orbital_period / sidereal_orbital_period - 1.0);
     trace_var!(orbital_period);
-    let lunar_tide = get_lunar_tide(mass, planet.get_radius(), semi_major_axis);
+    let lunar_tide = get_lunar_tide(mass, planet_radius / KM_PER_EARTH_RADIUS, semi_major_axis);
     trace_var!(lunar_tide);
-    let solar_tide = get_solar_tide(host_star.get_stellar_mass(), planet.get_radius(), star_distance);
+    let solar_tide = get_solar_tide(
+      host_star.get_stellar_mass(),
+      planet_radius / KM_PER_EARTH_RADIUS,
+      star_distance,
+    );
     trace_var!(solar_tide);
     let planetary_tide = get_planetary_tide(mass, radius, semi_major_axis);
     trace_var!(planetary_tide);
@@ -128,18 +174,66 @@ impl Moon {
     trace_var!(spring_tide_magnitude);
     let neap_tide_magnitude = get_neap_tide(lunar_tide, solar_tide);
     trace_var!(neap_tide_magnitude);
-    let is_planet_tidally_locked =
-      is_planet_tidally_locked(lunar_tide, solar_tide, host_star.get_current_age(), planet.get_mass());
+    let is_planet_tidally_locked = is_planet_tidally_locked(
+      planet_mass,
+      planet_radius,
+      planet.get_moment_of_inertia_factor(),
+      planet.get_tidal_dissipation_factor(),
+      mass,
+      semi_major_axis,
+      host_star.get_current_age(),
+    );
     trace_var!(is_planet_tidally_locked);
-    let is_moon_tidally_locked = is_moon_tidally_locked(solar_tide, planetary_tide, host_star.get_current_age(), mass);
+    let is_moon_tidally_locked =
+      is_moon_tidally_locked(planet_mass, mass, radius, semi_major_axis, host_star.get_current_age());
     trace_var!(is_moon_tidally_locked);
     let rotation_period;
     if is_moon_tidally_locked {
       rotation_period = orbital_period;
     } else {
-      rotation_period = 3.0;
+      // Whatever it was born with; the constraints can do better.
+      rotation_period = MAXIMUM_ROTATION_PERIOD;
     }
     trace_var!(rotation_period);
+    let tidal_heating = get_tidal_heating(
+      planet_mass,
+      radius * KM_PER_MOON_RADIUS,
+      semi_major_axis,
+      orbital_eccentricity,
+    );
+    trace_var!(tidal_heating);
+    let tidal_heat_flux = get_tidal_heat_flux(tidal_heating, radius * KM_PER_MOON_RADIUS);
+    trace_var!(tidal_heat_flux);
+    let is_volcanically_active = is_volcanically_active(tidal_heat_flux);
+    trace_var!(is_volcanically_active);
+    let subsurface_ocean_probability = match density < MINIMUM_ROCKY_DENSITY {
+      true => get_subsurface_ocean_probability(tidal_heat_flux),
+      false => 0.0,
+    };
+    trace_var!(subsurface_ocean_probability);
+    let greenhouse_effect = GREENHOUSE_EFFECT;
+    trace_var!(greenhouse_effect);
+    let equilibrium_temperature = get_equilibrium_temperature(
+      bond_albedo,
+      greenhouse_effect,
+      host_star.get_luminosity(),
+      star_distance,
+      planet_radius,
+      semi_major_axis,
+      tidal_heat_flux,
+    );
+    trace_var!(equilibrium_temperature);
+    let is_atmospherically_stable = is_atmospherically_stable(
+      equilibrium_temperature,
+      escape_velocity / KM_PER_SECOND_PER_EARTH_ESCAPE_VELOCITY,
+    );
+    trace_var!(is_atmospherically_stable);
+    let xuv_flux = host_star.get_xuv_flux(star_distance);
+    trace_var!(xuv_flux);
+    let hazardous_flare_energy = get_flare_energy_for_fluence(HAZARDOUS_FLARE_FLUENCE, star_distance);
+    trace_var!(hazardous_flare_energy);
+    let hazardous_flare_rate = host_star.get_flare_rate(hazardous_flare_energy);
+    trace_var!(hazardous_flare_rate);
     let result = Moon {
       mass,
       density,
@@ -163,9 +257,265 @@ impl Moon {
       neap_tide_magnitude,
       is_planet_tidally_locked,
       is_moon_tidally_locked,
+      origin,
+      tidal_heating,
+      is_volcanically_active,
+      subsurface_ocean_probability,
+      greenhouse_effect,
+      equilibrium_temperature,
+      is_atmospherically_stable,
+      xuv_flux,
+      hazardous_flare_rate,
+    };
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Recalculate the tidal heating and everything that depends on it, after
+  /// the orbit has changed; needs the star's luminosity in Lsol and distance
+  /// in AU, and the planet.
+  #[named]
+  pub fn update_temperature(&mut self, star_luminosity: f64, star_distance: f64, planet: &Planet) {
+    trace_enter!();
+    trace_var!(star_luminosity);
+    trace_var!(star_distance);
+    trace_var!(planet);
+    let radius = self.radius * KM_PER_MOON_RADIUS;
+    trace_var!(radius);
+    let tidal_heating = get_tidal_heating(
+      planet.get_mass_in_earth_masses(),
+      radius,
+      self.semi_major_axis,
+      self.orbital_eccentricity,
+    );
+    self.tidal_heating = tidal_heating;
+    trace_var!(tidal_heating);
+    let tidal_heat_flux = get_tidal_heat_flux(tidal_heating, radius);
+    trace_var!(tidal_heat_flux);
+    let is_volcanically_active = is_volcanically_active(tidal_heat_flux);
+    self.is_volcanically_active = is_volcanically_active;
+    trace_var!(is_volcanically_active);
+    let subsurface_ocean_probability = match self.density < MINIMUM_ROCKY_DENSITY {
+      true => get_subsurface_ocean_probability(tidal_heat_flux),
+      false => 0.0,
+    };
+    self.subsurface_ocean_probability = subsurface_ocean_probability;
+    trace_var!(subsurface_ocean_probability);
+    let equilibrium_temperature = get_equilibrium_temperature(
+      self.bond_albedo,
+      self.greenhouse_effect,
+      star_luminosity,
+      star_distance,
+      planet.get_radius_in_km(),
+      self.semi_major_axis,
+      tidal_heat_flux,
+    );
+    self.equilibrium_temperature = equilibrium_temperature;
+    trace_var!(equilibrium_temperature);
+    let is_atmospherically_stable =
+      is_atmospherically_stable(equilibrium_temperature, self.get_escape_velocity_in_earth_units());
+    self.is_atmospherically_stable = is_atmospherically_stable;
+    trace_var!(is_atmospherically_stable);
+    trace_exit!();
+  }
+
+  /// Move the moon to a new orbit, in KM, and recalculate everything that
+  /// depends on it; the shape and tilt of the orbit stay the same.
+  #[named]
+  pub fn update_orbit(
+    &mut self,
+    semi_major_axis: f64,
+    host_star: &HostStar,
+    star_distance: f64,
+    planet: &Planet,
+  ) -> Result<(), Error> {
+    trace_enter!();
+    trace_var!(semi_major_axis);
+    let moon = Moon::from_environment(
+      self.mass,
+      self.density,
+      self.bond_albedo,
+      host_star,
+      star_distance,
+      planet,
+      semi_major_axis,
+    )?;
+    trace_var!(moon);
+    let rotation_period = match moon.is_moon_tidally_locked {
+      true => moon.orbital_period,
+      false => self.rotation_period,
     };
+    *self = Moon {
+      orbital_eccentricity: self.orbital_eccentricity,
+      periapsis: (1.0 - self.orbital_eccentricity) * semi_major_axis,
+      apoapsis: (1.0 + self.orbital_eccentricity) * semi_major_axis,
+      orbital_inclination: self.orbital_inclination,
+      rotation_direction: self.rotation_direction,
+      rotation_period,
+      origin: self.origin,
+      ..moon
+    };
+    self.update_temperature(host_star.get_luminosity(), star_distance, planet);
+    trace_var!(self);
+    trace_exit!();
+    Ok(())
+  }
+
+  /// Let the tides this moon raises on the planet work over the age of the
+  /// star, and return the planet's rotation period afterward, in days.
+  ///
+  /// The tidal bulge on a planet spinning faster than the moon goes around
+  /// pulls the moon forward, so it slowly climbs outward while the planet
+  /// spins down, until the planet's day matches the moon's month.  Angular
+  /// momentum is conserved the whole way.  The moon might end up inside its
+  /// Roche limit or beyond the planet's grip; that's for the caller to sort
+  /// out.
+  #[named]
+  pub fn evolve_tides(
+    &mut self,
+    host_star: &HostStar,
+    star_distance: f64,
+    planet: &Planet,
+    planet_rotation_period: f64,
+  ) -> Result<f64, Error> {
+    trace_enter!();
+    trace_var!(planet_rotation_period);
+    let age = host_star.get_current_age();
+    trace_var!(age);
+    let planet_mass = planet.get_mass_in_earth_masses();
+    trace_var!(planet_mass);
+    let planet_radius = planet.get_radius_in_km();
+    trace_var!(planet_radius);
+    let moment_of_inertia_factor = planet.get_moment_of_inertia_factor();
+    trace_var!(moment_of_inertia_factor);
+    let tidal_dissipation_factor = planet.get_tidal_dissipation_factor();
+    trace_var!(tidal_dissipation_factor);
+    let roche_limit = planet.get_roche_limit(self.density);
+    trace_var!(roche_limit);
+    let angular_momentum = get_spin_angular_momentum(
+      planet_mass,
+      planet_radius,
+      moment_of_inertia_factor,
+      planet_rotation_period,
+    ) + get_orbital_angular_momentum(planet_mass, self.mass, self.semi_major_axis);
+    trace_var!(angular_momentum);
+    let mut semi_major_axis = self.semi_major_axis;
+    let mut rotation_period = planet_rotation_period;
+    let mut is_planet_tidally_locked = false;
+    let mut time = 0.0;
+    while time < age && semi_major_axis > roche_limit {
+      let orbital_period = get_sidereal_orbital_period(planet_mass, self.mass, semi_major_axis);
+      if (rotation_period / orbital_period - 1.0).abs() < SYNCHRONOUS_ROTATION_TOLERANCE {
+        is_planet_tidally_locked = true;
+        rotation_period = orbital_period;
+        break;
+      }
+      let migration_rate = get_migration_rate(
+        planet_mass,
+        planet_radius,
+        tidal_dissipation_factor,
+        self.mass,
+        semi_major_axis,
+        rotation_period,
+      );
+      let step = (TIDAL_EVOLUTION_STEP_FRACTION * semi_major_axis / migration_rate.abs()).min(age - time);
+      let next_semi_major_axis = semi_major_axis + migration_rate * step;
+      let next_rotation_period = get_rotation_period(
+        planet_mass,
+        planet_radius,
+        moment_of_inertia_factor,
+        angular_momentum - get_orbital_angular_momentum(planet_mass, self.mass, next_semi_major_axis),
+      );
+      // If we stepped right past synchronous rotation, then we've arrived.
+      let next_orbital_period = get_sidereal_orbital_period(planet_mass, self.mass, next_semi_major_axis);
+      if (next_rotation_period < next_orbital_period) != (rotation_period < orbital_period) {
+        is_planet_tidally_locked = true;
+        rotation_period = orbital_period;
+        break;
+      }
+      semi_major_axis = next_semi_major_axis;
+      rotation_period = next_rotation_period;
+      time += step;
+    }
+    trace_var!(time);
+    trace_var!(semi_major_axis);
+    trace_var!(rotation_period);
+    trace_var!(is_planet_tidally_locked);
+    self.update_orbit(semi_major_axis, host_star, star_distance, planet)?;
+    self.is_planet_tidally_locked = is_planet_tidally_locked;
+    let result = rotation_period;
     trace_var!(result);
     trace_exit!();
     Ok(result)
   }
+
+  /// Retrieve or calculate the escape velocity, in Vearth, as the atmosphere
+  /// math wants it.
+  #[named]
+  pub fn get_escape_velocity_in_earth_units(&self) -> f64 {
+    trace_enter!();
+    let result = self.escape_velocity / KM_PER_SECOND_PER_EARTH_ESCAPE_VELOCITY;
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Indicate whether this moon is capable of supporting conventional life.
+  ///
+  /// The same tests as for a terrestrial planet.
+  #[named]
+  pub fn check_habitable(&self) -> Result<(), Error> {
+    trace_enter!();
+    let result = {
+      let escape_velocity = self.get_escape_velocity_in_earth_units();
+      if self.equilibrium_temperature <= MINIMUM_HABITABLE_TEMPERATURE {
+        return Err(Error::TooColdToSupportConventionalLife);
+      }
+      if self.equilibrium_temperature >= MAXIMUM_HABITABLE_TEMPERATURE {
+        return Err(Error::TooHotToSupportConventionalLife);
+      }
+      if self.gravity <= MINIMUM_HABITABLE_GRAVITY {
+        return Err(Error::GravityTooLowToSupportConventionalLife);
+      }
+      if self.gravity >= MAXIMUM_HABITABLE_GRAVITY {
+        return Err(Error::GravityTooHighToSupportConventionalLife);
+      }
+      if !is_oxygen_stable(self.equilibrium_temperature, escape_velocity) {
+        return Err(Error::AtmosphereUnstableForOxygen);
+      }
+      if !is_carbon_dioxide_stable(self.equilibrium_temperature, escape_velocity) {
+        return Err(Error::AtmosphereUnstableForCarbonDioxide);
+      }
+      if !is_argon_stable(self.equilibrium_temperature, escape_velocity) {
+        return Err(Error::AtmosphereUnstableForArgon);
+      }
+      if !is_nitrogen_stable(self.equilibrium_temperature, escape_velocity) {
+        return Err(Error::AtmosphereUnstableForNitrogen);
+      }
+      if self.xuv_flux >= MAXIMUM_HABITABLE_XUV_FLUX {
+        return Err(Error::XuvFluxTooHighToSupportConventionalLife);
+      }
+      if self.hazardous_flare_rate >= MAXIMUM_HABITABLE_HAZARDOUS_FLARE_RATE {
+        return Err(Error::FlaresTooFrequentToSupportConventionalLife);
+      }
+      Ok(())
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Indicate whether this moon is capable of supporting conventional life.
+  #[named]
+  pub fn is_habitable(&self) -> bool {
+    trace_enter!();
+    let result = match self.check_habitable() {
+      Ok(()) => true,
+      Err(_) => false,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
 }
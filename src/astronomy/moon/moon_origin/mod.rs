@@ -0,0 +1,20 @@
+use crate::astronomy::trojans::lagrange_point::LagrangePoint;
+
+/// The `MoonOrigin` type.
+///
+/// How a moon came to orbit its planet, which decides what kind of orbit it
+/// has.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MoonOrigin {
+  /// Formed from the disk around the planet, like the Galilean moons; close
+  /// in, circular, and in the planet's equatorial plane.
+  Regular,
+  /// Captured from a passing orbit, like Phoebe; far out, eccentric, tilted,
+  /// and often backwards.
+  Irregular,
+  /// Knocked loose by a collision, like our own Moon.
+  GiantImpact,
+  /// Sharing a bigger moon's orbit at one of its Lagrange points, like
+  /// Telesto and Calypso with Tethys.
+  Trojan(LagrangePoint),
+}
@@ -9,3 +9,66 @@ pub const MINIMUM_GAS_GIANT_MOONS: usize = 8;
 
 /// The maximum number of moons we'll generate for a gas giant plant.
 pub const MAXIMUM_GAS_GIANT_MOONS: usize = 20;
+
+/// Moons much further out than this fraction of the planet's Hill sphere get
+/// pulled away by the star (Domingos et al., 2006).
+pub const STABLE_HILL_SPHERE_FRACTION: f64 = 0.49;
+
+/// The density of a rocky moon, in g/cm³.
+pub const ROCKY_MOON_DENSITY: f64 = 3.35;
+
+/// The minimum number of regular moons we'll generate for a gas giant.
+pub const MINIMUM_REGULAR_MOONS: usize = 2;
+
+/// The maximum number of regular moons we'll generate for a gas giant.
+pub const MAXIMUM_REGULAR_MOONS: usize = 8;
+
+/// Regular moons form within this fraction of the planet's Hill sphere.
+pub const REGULAR_HILL_SPHERE_FRACTION: f64 = 0.05;
+
+/// Irregular moons are captured beyond this fraction of the planet's Hill
+/// sphere.
+pub const IRREGULAR_HILL_SPHERE_FRACTION: f64 = 0.1;
+
+/// The least period ratio of neighboring regular moons.
+pub const MINIMUM_REGULAR_PERIOD_RATIO: f64 = 1.4;
+
+/// The greatest period ratio of neighboring regular moons.
+pub const MAXIMUM_REGULAR_PERIOD_RATIO: f64 = 2.5;
+
+/// The probability that a terrestrial planet has a large moon left over from
+/// a giant impact.
+pub const GIANT_IMPACT_MOON_PROBABILITY: f64 = 0.25;
+
+/// The least mass of a giant-impact moon, as a fraction of its planet's.
+pub const MINIMUM_GIANT_IMPACT_MASS_FRACTION: f64 = 0.002;
+
+/// The greatest mass of a giant-impact moon, as a fraction of its planet's.
+pub const MAXIMUM_GIANT_IMPACT_MASS_FRACTION: f64 = 0.02;
+
+/// The least orbit of a giant-impact moon, in Roche limits, after its tides
+/// have pushed it outward.
+pub const MINIMUM_GIANT_IMPACT_ORBIT: f64 = 10.0;
+
+/// The greatest orbit of a giant-impact moon, in Roche limits.
+pub const MAXIMUM_GIANT_IMPACT_ORBIT: f64 = 25.0;
+
+/// The closest a habitable moon can orbit, in planet radii; any closer, and
+/// the tides would cook it like Io.
+pub const MINIMUM_HABITABLE_ORBIT: f64 = 20.0;
+
+/// The least mass of a moon, in Mmoon, with room for trojans of its own;
+/// Tethys is about this size.
+pub const MINIMUM_TROJAN_HOST_MASS: f64 = 0.005;
+
+/// The probability that each of a big regular moon's Lagrange points holds a
+/// little moon.
+pub const TROJAN_MOON_PROBABILITY: f64 = 0.1;
+
+/// The least mass of a trojan moon, as a fraction of the moon it shares an
+/// orbit with.
+pub const MINIMUM_TROJAN_MOON_MASS_FRACTION: f64 = 1.0E-6;
+
+/// The greatest mass of a trojan moon, as a fraction of the moon it shares
+/// an orbit with.
+pub const MAXIMUM_TROJAN_MOON_MASS_FRACTION: f64 = 1.0E-4;
@@ -1,21 +1,48 @@
 use rand::prelude::*;
 
 use crate::astronomy::host_star::HostStar;
+use crate::astronomy::moon::constants::{
+  EARTH_MASS_PER_MOON_MASS, MAXIMUM_HABITABLE_MASS as MAXIMUM_HABITABLE_MOON_MASS, MAXIMUM_MASS as MAXIMUM_MOON_MASS,
+  MINIMUM_ICY_DENSITY as MINIMUM_ICY_MOON_DENSITY,
+};
 use crate::astronomy::moon::constraints::Constraints as MoonConstraints;
+use crate::astronomy::moon::moon_origin::MoonOrigin;
 use crate::astronomy::moons::constants::*;
 use crate::astronomy::moons::error::Error;
 use crate::astronomy::moons::Moons;
+use crate::astronomy::planet::math::mass::EARTH_MASS_PER_SOLAR_MASS;
 use crate::astronomy::planet::Planet;
+use crate::astronomy::satellite_systems::constants::MINIMUM_STABLE_SEPARATION;
+use crate::astronomy::satellite_systems::math::formation::get_mutual_hill_radius;
+use crate::astronomy::trojans::lagrange_point::LagrangePoint;
 
 /// Constraints for creating a moon.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Constraints {
+  /// The minimum number to generate.
+  pub minimum_count: Option<usize>,
+  /// The maximum number to generate.
+  pub maximum_count: Option<usize>,
   /// A constraint for moons.
   pub moon_constraints: Option<MoonConstraints>,
+  /// Add a moon big enough to be habitable, if the planet is a giant.
+  pub generate_habitable: bool,
 }
 
 impl Constraints {
   /// Generate.
+  ///
+  /// Moons orbit between the planet's Roche limit and the stable part of its
+  /// Hill sphere.  If there's no room between them, the planet goes without,
+  /// unless we asked for some, in which case that's an error.
+  ///
+  /// Giants get a few regular moons, spaced out close in, and a swarm of
+  /// captured irregular moons far out.  Terrestrial planets might have a big
+  /// moon from a giant impact, and maybe a captured rock or two.
+  ///
+  /// If we want a habitable moon, it goes just past the regular moons, but
+  /// far enough out that the tides don't cook it.  Now and then, one of the
+  /// big ones has a little trojan moon or two sharing its orbit.
   #[named]
   pub fn generate<R: Rng + ?Sized>(
     &self,
@@ -28,44 +55,178 @@ impl Constraints {
     trace_var!(planet);
     trace_var!(host_star);
     trace_var!(star_distance);
-    let minimum_count;
-    let maximum_count;
+    let mut minimum_count;
+    let mut maximum_count;
     use Planet::*;
     match planet {
       TerrestrialPlanet(_) => {
         minimum_count = MINIMUM_TERRESTRIAL_MOONS;
         maximum_count = MAXIMUM_TERRESTRIAL_MOONS;
       },
-      GasGiantPlanet(_) => {
+      GasGiantPlanet(_) | BrownDwarf(_) => {
         minimum_count = MINIMUM_GAS_GIANT_MOONS;
         maximum_count = MAXIMUM_GAS_GIANT_MOONS;
       },
     }
+    minimum_count = self.minimum_count.unwrap_or(minimum_count);
+    maximum_count = self.maximum_count.unwrap_or(maximum_count).max(minimum_count);
     trace_var!(minimum_count);
     trace_var!(maximum_count);
-    let moon_constraints = self.moon_constraints.unwrap_or(MoonConstraints::default());
+    let moon_constraints = self.moon_constraints.unwrap_or_default();
     trace_var!(moon_constraints);
-    let rocky_moon_density = 3.35;
-    trace_var!(rocky_moon_density);
+    // Icy moons are lighter, so they come apart farther out.
     let satellite_zone = {
-      let inner = 2.44 * planet.get_radius() * 6_371.0 * (planet.get_density() / rocky_moon_density).powf(1.0 / 3.0);
-      // @todo: improve this.
-      let outer = 20.0 * inner;
+      let inner = planet.get_roche_limit(match star_distance >= host_star.get_frost_line() {
+        true => MINIMUM_ICY_MOON_DENSITY,
+        false => ROCKY_MOON_DENSITY,
+      });
+      let outer = STABLE_HILL_SPHERE_FRACTION * planet.get_hill_sphere(host_star.get_stellar_mass());
       (inner, outer)
     };
     trace_var!(satellite_zone);
-    let moons = {
-      let count = rng.gen_range(minimum_count..=maximum_count);
-      trace_var!(count);
-      let mut moons = vec![];
-      for _ in 1..count {
-        let planet_distance = rng.gen_range(satellite_zone.0..satellite_zone.1);
-        let moon = moon_constraints.generate(rng, host_star, star_distance, planet, planet_distance)?;
-        trace_var!(moon);
-        moons.push(moon);
+    if satellite_zone.0 >= satellite_zone.1 {
+      if self.minimum_count.is_some_and(|minimum_count| minimum_count > 0) {
+        return Err(Error::HillSphereTooSmall);
       }
-      moons
+      let result = Moons { moons: vec![] };
+      trace_var!(result);
+      trace_exit!();
+      return Ok(result);
+    }
+    let hill_sphere = planet.get_hill_sphere(host_star.get_stellar_mass());
+    trace_var!(hill_sphere);
+    let count = rng.gen_range(minimum_count..=maximum_count);
+    trace_var!(count);
+    let mut moons = vec![];
+    let irregular_count = match planet {
+      TerrestrialPlanet(_) => {
+        if rng.gen_range(0.0..1.0) < GIANT_IMPACT_MOON_PROBABILITY {
+          let mass = planet.get_mass_in_earth_masses()
+            * rng.gen_range(MINIMUM_GIANT_IMPACT_MASS_FRACTION..MAXIMUM_GIANT_IMPACT_MASS_FRACTION)
+            / EARTH_MASS_PER_MOON_MASS;
+          trace_var!(mass);
+          let planet_distance =
+            match satellite_zone.0 * rng.gen_range(MINIMUM_GIANT_IMPACT_ORBIT..MAXIMUM_GIANT_IMPACT_ORBIT) {
+              planet_distance if planet_distance < satellite_zone.1 => planet_distance,
+              _ => rng.gen_range(satellite_zone.0..satellite_zone.1),
+            };
+          trace_var!(planet_distance);
+          let constraints = MoonConstraints {
+            minimum_mass: Some(mass),
+            maximum_mass: Some(mass),
+            origin: Some(MoonOrigin::GiantImpact),
+            ..moon_constraints
+          };
+          let moon = constraints.generate(rng, host_star, star_distance, planet, planet_distance)?;
+          trace_var!(moon);
+          moons.push(moon);
+        }
+        count.saturating_sub(moons.len())
+      },
+      GasGiantPlanet(_) | BrownDwarf(_) => {
+        let regular_count = count.min(rng.gen_range(MINIMUM_REGULAR_MOONS..=MAXIMUM_REGULAR_MOONS));
+        trace_var!(regular_count);
+        let constraints = MoonConstraints {
+          origin: Some(MoonOrigin::Regular),
+          ..moon_constraints
+        };
+        // Space them out for the heaviest moon we might make, so that
+        // neighbors never get in each other's way.
+        let maximum_mass = constraints.maximum_mass.unwrap_or(MAXIMUM_MOON_MASS) * EARTH_MASS_PER_MOON_MASS;
+        trace_var!(maximum_mass);
+        let planet_mass = planet.get_mass_in_earth_masses() / EARTH_MASS_PER_SOLAR_MASS;
+        trace_var!(planet_mass);
+        let outermost_orbit = satellite_zone.1.min(REGULAR_HILL_SPHERE_FRACTION * hill_sphere);
+        trace_var!(outermost_orbit);
+        let mut planet_distance = satellite_zone.0 * rng.gen_range(1.1..1.5);
+        while moons.len() < regular_count && planet_distance < outermost_orbit {
+          let moon = constraints.generate(rng, host_star, star_distance, planet, planet_distance)?;
+          trace_var!(moon);
+          moons.push(moon);
+          let period_ratio: f64 = rng.gen_range(MINIMUM_REGULAR_PERIOD_RATIO..MAXIMUM_REGULAR_PERIOD_RATIO);
+          let mutual_hill_radius = get_mutual_hill_radius(
+            maximum_mass,
+            maximum_mass,
+            planet_distance,
+            planet_distance,
+            planet_mass,
+          );
+          planet_distance = (planet_distance * period_ratio.powf(2.0 / 3.0))
+            .max(planet_distance + MINIMUM_STABLE_SEPARATION * mutual_hill_radius);
+        }
+        if self.generate_habitable {
+          let habitable_mass = MAXIMUM_HABITABLE_MOON_MASS * EARTH_MASS_PER_MOON_MASS;
+          trace_var!(habitable_mass);
+          // It's a lot heavier than its neighbors, so it needs more room.
+          let planet_distance = match moons.last() {
+            Some(moon) => planet_distance.max(
+              moon.semi_major_axis
+                + MINIMUM_STABLE_SEPARATION
+                  * get_mutual_hill_radius(
+                    maximum_mass,
+                    habitable_mass,
+                    moon.semi_major_axis,
+                    moon.semi_major_axis,
+                    planet_mass,
+                  ),
+            ),
+            None => planet_distance,
+          }
+          .max(MINIMUM_HABITABLE_ORBIT * planet.get_radius_in_km());
+          trace_var!(planet_distance);
+          if planet_distance >= satellite_zone.1 {
+            return Err(Error::NoRoomForHabitableMoon);
+          }
+          let moon = MoonConstraints::habitable().generate(rng, host_star, star_distance, planet, planet_distance)?;
+          trace_var!(moon);
+          moons.push(moon);
+        }
+        // Big moons might herd little ones around their Lagrange points.
+        let trojan_hosts: Vec<(f64, f64)> = moons
+          .iter()
+          .filter(|moon| moon.mass >= MINIMUM_TROJAN_HOST_MASS)
+          .map(|moon| (moon.mass, moon.semi_major_axis))
+          .collect();
+        trace_var!(trojan_hosts);
+        for (mass, planet_distance) in trojan_hosts {
+          for lagrange_point in [LagrangePoint::L4, LagrangePoint::L5] {
+            if rng.gen_range(0.0..1.0) >= TROJAN_MOON_PROBABILITY {
+              continue;
+            }
+            let mass = mass * rng.gen_range(MINIMUM_TROJAN_MOON_MASS_FRACTION..MAXIMUM_TROJAN_MOON_MASS_FRACTION);
+            trace_var!(mass);
+            let constraints = MoonConstraints {
+              minimum_mass: Some(mass),
+              maximum_mass: Some(mass),
+              origin: Some(MoonOrigin::Trojan(lagrange_point)),
+              ..moon_constraints
+            };
+            let moon = constraints.generate(rng, host_star, star_distance, planet, planet_distance)?;
+            trace_var!(moon);
+            moons.push(moon);
+          }
+        }
+        count.saturating_sub(moons.len())
+      },
     };
+    trace_var!(irregular_count);
+    let constraints = MoonConstraints {
+      origin: Some(MoonOrigin::Irregular),
+      ..moon_constraints
+    };
+    let innermost_irregular_orbit =
+      (IRREGULAR_HILL_SPHERE_FRACTION * hill_sphere).clamp(satellite_zone.0, satellite_zone.1);
+    trace_var!(innermost_irregular_orbit);
+    for _ in 0..irregular_count {
+      let planet_distance = match innermost_irregular_orbit < satellite_zone.1 {
+        true => rng.gen_range(innermost_irregular_orbit..satellite_zone.1),
+        false => rng.gen_range(satellite_zone.0..satellite_zone.1),
+      };
+      let moon = constraints.generate(rng, host_star, star_distance, planet, planet_distance)?;
+      trace_var!(moon);
+      moons.push(moon);
+    }
+    moons.sort_by(|a, b| a.semi_major_axis.partial_cmp(&b.semi_major_axis).unwrap());
     trace_var!(moons);
     let result = Moons { moons };
     trace_var!(result);
@@ -77,8 +238,16 @@ impl Constraints {
 impl Default for Constraints {
   /// No constraints, just let it all hang out.
   fn default() -> Self {
+    let minimum_count = None;
+    let maximum_count = None;
     let moon_constraints = None;
-    Self { moon_constraints }
+    let generate_habitable = false;
+    Self {
+      minimum_count,
+      maximum_count,
+      moon_constraints,
+      generate_habitable,
+    }
   }
 }
 
@@ -113,4 +282,109 @@ pub mod test {
     trace_exit!();
     Ok(())
   }
+
+  #[named]
+  #[test]
+  pub fn test_satellite_zone() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let host_star = &HostStarConstraints::default().generate(&mut rng)?;
+    trace_var!(host_star);
+    let stellar_mass = host_star.get_stellar_mass();
+    // A giant far out has plenty of room.
+    let planet_constraints = PlanetConstraints {
+      mass: Some(317.8),
+      ..PlanetConstraints::default()
+    };
+    let planet = &planet_constraints.generate(&mut rng, host_star, 5.0)?;
+    let moons = &Constraints::default().generate(&mut rng, host_star, 5.0, planet)?;
+    print_var!(moons);
+    assert!(moons.moons.iter().all(|moon| {
+      moon.semi_major_axis > planet.get_roche_limit(ROCKY_MOON_DENSITY)
+        && moon.semi_major_axis < STABLE_HILL_SPHERE_FRACTION * planet.get_hill_sphere(stellar_mass)
+    }));
+    // An Earth hugging its star has none.
+    let planet_constraints = PlanetConstraints {
+      mass: Some(1.0),
+      ..PlanetConstraints::default()
+    };
+    let star_distance = 0.002 * stellar_mass.powf(1.0 / 3.0);
+    let planet = &planet_constraints.generate(&mut rng, host_star, star_distance)?;
+    let moons = &Constraints::default().generate(&mut rng, host_star, star_distance, planet)?;
+    assert!(moons.moons.is_empty());
+    let constraints = Constraints {
+      minimum_count: Some(1),
+      ..Constraints::default()
+    };
+    assert_eq!(
+      constraints.generate(&mut rng, host_star, star_distance, planet),
+      Err(Error::HillSphereTooSmall)
+    );
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_origins() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let host_star = &HostStarConstraints::default().generate(&mut rng)?;
+    trace_var!(host_star);
+    let planet_constraints = PlanetConstraints {
+      mass: Some(317.8),
+      ..PlanetConstraints::default()
+    };
+    let planet = &planet_constraints.generate(&mut rng, host_star, 5.0)?;
+    let moons = &Constraints::default().generate(&mut rng, host_star, 5.0, planet)?;
+    print_var!(moons);
+    assert!(moons
+      .moons
+      .windows(2)
+      .all(|pair| pair[0].semi_major_axis <= pair[1].semi_major_axis));
+    let regular_moons: Vec<_> = moons
+      .moons
+      .iter()
+      .filter(|moon| moon.origin == MoonOrigin::Regular)
+      .collect();
+    assert!(!regular_moons.is_empty());
+    assert!(regular_moons.iter().all(|moon| moon.orbital_inclination < 1.0));
+    assert!(regular_moons
+      .windows(2)
+      .all(|pair| (pair[1].semi_major_axis / pair[0].semi_major_axis).powf(1.5) >= MINIMUM_REGULAR_PERIOD_RATIO));
+    assert!(moons
+      .moons
+      .iter()
+      .filter(|moon| moon.origin == MoonOrigin::Irregular)
+      .all(|moon| moon.orbital_eccentricity >= 0.1 && moon.orbital_inclination >= 20.0));
+    assert!(moons
+      .moons
+      .iter()
+      .filter(|moon| matches!(moon.origin, MoonOrigin::Trojan(_)))
+      .all(|trojan| regular_moons
+        .iter()
+        .any(|moon| moon.semi_major_axis == trojan.semi_major_axis && moon.mass > trojan.mass)));
+    let planet_constraints = PlanetConstraints {
+      mass: Some(1.0),
+      ..PlanetConstraints::default()
+    };
+    let planet = &planet_constraints.generate(&mut rng, host_star, 1.0)?;
+    let moons = &Constraints::default().generate(&mut rng, host_star, 1.0, planet)?;
+    print_var!(moons);
+    assert!(moons.moons.iter().all(|moon| moon.origin != MoonOrigin::Regular));
+    assert!(
+      moons
+        .moons
+        .iter()
+        .filter(|moon| moon.origin == MoonOrigin::GiantImpact)
+        .count()
+        <= 1
+    );
+    trace_exit!();
+    Ok(())
+  }
 }
@@ -11,6 +11,13 @@ pub enum Error {
   MoonError(MoonError),
   /// Planet Error.
   PlanetError(PlanetError),
+  /// The planet's Hill sphere doesn't reach past its Roche limit, so there's
+  /// nowhere for a moon to go.
+  HillSphereTooSmall,
+  /// There's no stable orbit far enough out for a habitable moon.
+  NoRoomForHabitableMoon,
+  /// None of the moons are habitable.
+  NoHabitableMoonsFound,
 }
 
 honeyholt_define_brief!(Error, |error: &Error| {
@@ -22,6 +29,9 @@ honeyholt_define_brief!(Error, |error: &Error| {
     ),
     MoonError(moon_error) => format!("an error occurred in the moon ({})", honeyholt_brief!(moon_error)),
     PlanetError(planet_error) => format!("an error occurred in the planet ({})", honeyholt_brief!(planet_error)),
+    HillSphereTooSmall => "the planet's hill sphere is too small to hold any moons".to_string(),
+    NoRoomForHabitableMoon => "the planet has no room for a habitable moon".to_string(),
+    NoHabitableMoonsFound => "none of the moons are habitable".to_string(),
   }
 });
 
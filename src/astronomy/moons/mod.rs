@@ -3,9 +3,40 @@ use crate::astronomy::moon::Moon;
 pub mod constants;
 pub mod constraints;
 pub mod error;
+use error::Error;
 
 /// The `Moons` object is a wrapper around a list of `Moon` objects.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Moons {
   pub moons: Vec<Moon>,
 }
+
+impl Moons {
+  /// Indicate whether any of these moons is capable of supporting
+  /// conventional life.
+  #[named]
+  pub fn check_habitable(&self) -> Result<(), Error> {
+    trace_enter!();
+    let result = match self.moons.iter().any(|moon| moon.is_habitable()) {
+      true => Ok(()),
+      false => Err(Error::NoHabitableMoonsFound),
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Indicate whether any of these moons is capable of supporting
+  /// conventional life.
+  #[named]
+  pub fn is_habitable(&self) -> bool {
+    trace_enter!();
+    let result = match self.check_habitable() {
+      Ok(()) => true,
+      Err(_) => false,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
@@ -1 +1,47 @@
+/// The probability that a planet beyond the frost line of a star with solar
+/// metallicity is a gas giant, rather than a rocky or icy body.
+pub const GAS_GIANT_PROBABILITY: f64 = 0.8;
 
+/// How strongly metallicity affects giant planet occurrence.
+///
+/// Occurrence scales as 10^(2 [Fe/H]) (Fischer & Valenti, 2005); a star with
+/// twice the Sun's iron is about four times as likely to host a giant.
+pub const GAS_GIANT_METALLICITY_EXPONENT: f64 = 2.0;
+
+/// How strongly low metallicity suppresses rocky planet occurrence.
+///
+/// Rocky planets are much less picky than giants, but a metal-poor disk
+/// still has less solid material to build them from.
+pub const ROCKY_PLANET_METALLICITY_EXPONENT: f64 = 0.5;
+
+/// How strongly low metallicity limits rocky planet mass.
+pub const ROCKY_PLANET_MASS_METALLICITY_EXPONENT: f64 = 0.5;
+
+/// Kilometers per AU.
+pub const KM_PER_AU: f64 = 149_597_870.7;
+
+/// Kilometers per Rearth.
+pub const KM_PER_EARTH_RADIUS: f64 = 6_371.0;
+
+/// Kilometers per Rjupiter.
+pub const KM_PER_JUPITER_RADIUS: f64 = 69_911.0;
+
+/// The density of Jupiter, in g/cm³.
+pub const JUPITER_DENSITY: f64 = 1.326;
+
+/// A satellite held together only by its own gravity comes apart inside this
+/// many planetary radii, scaled by the cube root of the density ratio.
+pub const FLUID_ROCHE_LIMIT_FACTOR: f64 = 2.44;
+
+/// How readily a rocky planet turns tides into heat, i.e. its Love number k2
+/// divided by its tidal quality factor Q; about Earth's, oceans and all.
+pub const TERRESTRIAL_TIDAL_DISSIPATION_FACTOR: f64 = 0.025;
+
+/// The same for a giant; Jupiter's Q is in the tens of thousands.
+pub const GIANT_TIDAL_DISSIPATION_FACTOR: f64 = 1.0E-5;
+
+/// A rocky planet's moment of inertia as a fraction of MR²; Earth's.
+pub const TERRESTRIAL_MOMENT_OF_INERTIA_FACTOR: f64 = 0.33;
+
+/// A giant's moment of inertia as a fraction of MR²; Jupiter's.
+pub const GIANT_MOMENT_OF_INERTIA_FACTOR: f64 = 0.25;
@@ -1,18 +1,34 @@
 use rand::prelude::*;
 
+use crate::astronomy::brown_dwarf::constants::COMPANION_PROBABILITY as BROWN_DWARF_PROBABILITY;
+use crate::astronomy::brown_dwarf::constraints::Constraints as BrownDwarfConstraints;
 use crate::astronomy::gas_giant_planet::constraints::Constraints as GasGiantPlanetConstraints;
 use crate::astronomy::host_star::HostStar;
+use crate::astronomy::planet::constants::*;
 use crate::astronomy::planet::error::Error;
+use crate::astronomy::planet::math::mass::earth_mass_to_jupiter_mass;
+use crate::astronomy::planet::math::occurrence::get_gas_giant_probability;
 use crate::astronomy::planet::Planet;
+use crate::astronomy::terrestrial_planet::constants::MAXIMUM_MASS as TERRESTRIAL_PLANET_MAXIMUM_MASS;
 use crate::astronomy::terrestrial_planet::constraints::Constraints as TerrestrialPlanetConstraints;
 
 /// Constraints for creating a planet.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Constraints {
+  /// The probability that a planet beyond the frost line is a brown dwarf.
+  pub brown_dwarf_probability: Option<f64>,
+  /// Brown dwarf constraints.
+  pub brown_dwarf_constraints: Option<BrownDwarfConstraints>,
+  /// The probability that a planet beyond the frost line is a gas giant, at
+  /// solar metallicity.  This rises steeply with the host star's metallicity.
+  pub gas_giant_probability: Option<f64>,
   /// Gas Giant planet constraints.
   pub gas_giant_planet_constraints: Option<GasGiantPlanetConstraints>,
   /// Terrestrial planet constraints.
   pub terrestrial_planet_constraints: Option<TerrestrialPlanetConstraints>,
+  /// The mass of the planet in Mearth, if we already know it, e.g. from a
+  /// formation model.  This decides whether it's a gas giant.
+  pub mass: Option<f64>,
 }
 
 impl Constraints {
@@ -31,7 +47,40 @@ impl Constraints {
     trace_enter!();
     use Planet::*;
     let result = {
-      if distance >= host_star.get_frost_line() {
+      let brown_dwarf_probability = self.brown_dwarf_probability.unwrap_or(BROWN_DWARF_PROBABILITY);
+      trace_var!(brown_dwarf_probability);
+      let gas_giant_probability = get_gas_giant_probability(
+        self.gas_giant_probability.unwrap_or(GAS_GIANT_PROBABILITY),
+        host_star.get_metallicity(),
+      );
+      trace_var!(gas_giant_probability);
+      if let Some(mass) = self.mass {
+        trace_var!(mass);
+        match mass > TERRESTRIAL_PLANET_MAXIMUM_MASS {
+          true => {
+            let mass = earth_mass_to_jupiter_mass(mass);
+            let constraints = GasGiantPlanetConstraints {
+              minimum_mass: Some(mass),
+              maximum_mass: Some(mass),
+            };
+            trace_var!(constraints);
+            GasGiantPlanet(constraints.generate(rng, host_star, distance)?)
+          },
+          false => {
+            let constraints = TerrestrialPlanetConstraints {
+              minimum_mass: Some(mass),
+              maximum_mass: Some(mass),
+              ..self.terrestrial_planet_constraints.unwrap_or_default()
+            };
+            trace_var!(constraints);
+            TerrestrialPlanet(constraints.generate(rng, host_star, distance)?)
+          },
+        }
+      } else if distance >= host_star.get_frost_line() && rng.gen_range(0.0..1.0) < brown_dwarf_probability {
+        let constraints = self.brown_dwarf_constraints.unwrap_or_default();
+        trace_var!(constraints);
+        BrownDwarf(constraints.generate_companion(rng, host_star, distance)?)
+      } else if distance >= host_star.get_frost_line() && rng.gen_range(0.0..1.0) < gas_giant_probability {
         let constraints = self
           .gas_giant_planet_constraints
           .unwrap_or(GasGiantPlanetConstraints::default());
@@ -54,11 +103,19 @@ impl Constraints {
 impl Default for Constraints {
   /// No constraints, just let it all hang out.
   fn default() -> Self {
+    let brown_dwarf_probability = Some(BROWN_DWARF_PROBABILITY);
+    let brown_dwarf_constraints = None;
+    let gas_giant_probability = Some(GAS_GIANT_PROBABILITY);
     let gas_giant_planet_constraints = None;
     let terrestrial_planet_constraints = None;
+    let mass = None;
     Self {
+      brown_dwarf_probability,
+      brown_dwarf_constraints,
+      gas_giant_probability,
       gas_giant_planet_constraints,
       terrestrial_planet_constraints,
+      mass,
     }
   }
 }
@@ -1,3 +1,4 @@
+use crate::astronomy::brown_dwarf::error::Error as BrownDwarfError;
 use crate::astronomy::gas_giant_planet::error::Error as GasGiantPlanetError;
 use crate::astronomy::host_star::error::Error as HostStarError;
 use crate::astronomy::terrestrial_planet::error::Error as TerrestrialPlanetError;
@@ -5,6 +6,8 @@ use crate::astronomy::terrestrial_planet::error::Error as TerrestrialPlanetError
 /// Planet errors.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Error {
+  /// BrownDwarf error.
+  BrownDwarfError(BrownDwarfError),
   /// GasGiantPlanet error.
   GasGiantPlanetError(GasGiantPlanetError),
   /// HostStar error.
@@ -18,6 +21,10 @@ pub enum Error {
 honeyholt_define_brief!(Error, |error: &Error| {
   use Error::*;
   match error {
+    BrownDwarfError(brown_dwarf_error) => format!(
+      "an error occurred in the brown dwarf ({})",
+      honeyholt_brief!(brown_dwarf_error)
+    ),
     GasGiantPlanetError(gas_giant_planet_error) => format!(
       "an error occurred in the gas giant planet ({})",
       honeyholt_brief!(gas_giant_planet_error)
@@ -54,3 +61,10 @@ impl From<TerrestrialPlanetError> for Error {
     Error::TerrestrialPlanetError(error)
   }
 }
+
+impl From<BrownDwarfError> for Error {
+  #[named]
+  fn from(error: BrownDwarfError) -> Self {
+    Error::BrownDwarfError(error)
+  }
+}
@@ -0,0 +1,40 @@
+use crate::astronomy::planet::math::mass::EARTH_MASS_PER_SOLAR_MASS;
+
+/// Get the radius of the Hill sphere of a planet (mass in Mearth) at a
+/// distance in AU, with a given orbital eccentricity, from a star of a given
+/// mass in Msol.
+///
+/// The result is in AU.  Inside this, the planet's gravity wins out over the
+/// star's; we measure at periapsis, where the star pulls hardest.
+#[named]
+pub fn get_hill_sphere(mass: f64, distance: f64, orbital_eccentricity: f64, stellar_mass: f64) -> f64 {
+  trace_enter!();
+  trace_var!(mass);
+  trace_var!(distance);
+  trace_var!(orbital_eccentricity);
+  trace_var!(stellar_mass);
+  let result =
+    distance * (1.0 - orbital_eccentricity) * (mass / (3.0 * stellar_mass * EARTH_MASS_PER_SOLAR_MASS)).powf(1.0 / 3.0);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_hill_sphere() {
+    init();
+    trace_enter!();
+    // Earth's is about 0.01 AU, or 1.5 million KM.
+    let hill_sphere = get_hill_sphere(1.0, 1.0, 0.0167, 1.0);
+    print_var!(hill_sphere);
+    assert_approx_eq!(hill_sphere, 0.0098, 0.0002);
+    trace_exit!();
+  }
+}
@@ -1,4 +1,5 @@
 pub const EARTH_MASS_PER_JUPITER_MASS: f64 = 317.8;
+pub const EARTH_MASS_PER_SOLAR_MASS: f64 = 332_946.0;
 
 /// Convert from Mearth to Mjupiter.
 #[named]
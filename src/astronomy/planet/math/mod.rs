@@ -1 +1,4 @@
+pub mod hill_sphere;
 pub mod mass;
+pub mod occurrence;
+pub mod roche_limit;
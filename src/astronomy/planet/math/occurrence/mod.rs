@@ -0,0 +1,67 @@
+use crate::astronomy::planet::constants::*;
+
+/// Get the probability that a planet beyond the frost line is a gas giant,
+/// given the probability at solar metallicity and the actual metallicity
+/// ([Fe/H]) of the host star.
+#[named]
+pub fn get_gas_giant_probability(solar_probability: f64, metallicity: f64) -> f64 {
+  trace_enter!();
+  trace_var!(solar_probability);
+  trace_var!(metallicity);
+  let result = (solar_probability * 10.0_f64.powf(GAS_GIANT_METALLICITY_EXPONENT * metallicity)).min(1.0);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the probability that a rocky planet forms in a given orbit, given the
+/// metallicity ([Fe/H]) of the host star.
+///
+/// Metal-rich disks don't make any more rocky planets than the Sun's did;
+/// metal-poor disks make fewer.
+#[named]
+pub fn get_rocky_planet_probability(metallicity: f64) -> f64 {
+  trace_enter!();
+  trace_var!(metallicity);
+  let result = 10.0_f64.powf(ROCKY_PLANET_METALLICITY_EXPONENT * metallicity.min(0.0));
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the factor by which the metallicity ([Fe/H]) of the host star limits
+/// the mass of rocky planets.
+///
+/// As above, this only comes into play for metal-poor systems.
+#[named]
+pub fn get_rocky_planet_mass_factor(metallicity: f64) -> f64 {
+  trace_enter!();
+  trace_var!(metallicity);
+  let result = 10.0_f64.powf(ROCKY_PLANET_MASS_METALLICITY_EXPONENT * metallicity.min(0.0));
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_occurrence() {
+    init();
+    trace_enter!();
+    assert_approx_eq!(get_gas_giant_probability(0.1, 0.0), 0.1);
+    assert_approx_eq!(get_gas_giant_probability(0.1, 0.3), 0.398, 0.001);
+    assert_approx_eq!(get_gas_giant_probability(0.8, 0.5), 1.0);
+    assert!(get_gas_giant_probability(0.8, -0.5) < 0.1);
+    assert_approx_eq!(get_rocky_planet_probability(0.3), 1.0);
+    assert!(get_rocky_planet_probability(-0.5) < 1.0);
+    assert_approx_eq!(get_rocky_planet_mass_factor(0.0), 1.0);
+    assert!(get_rocky_planet_mass_factor(-1.0) < 0.5);
+    trace_exit!();
+  }
+}
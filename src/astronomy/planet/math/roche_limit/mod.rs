@@ -0,0 +1,38 @@
+use crate::astronomy::planet::constants::FLUID_ROCHE_LIMIT_FACTOR;
+
+/// Get the Roche limit of a planet with a given radius and density, for a
+/// satellite of a given density.
+///
+/// The result is in the same units as the radius; the densities just need to
+/// be in the same units as each other.  Inside this, a loose pile of rubble
+/// or ice gets pulled apart, and that's where rings live.
+#[named]
+pub fn get_roche_limit(radius: f64, density: f64, satellite_density: f64) -> f64 {
+  trace_enter!();
+  trace_var!(radius);
+  trace_var!(density);
+  trace_var!(satellite_density);
+  let result = FLUID_ROCHE_LIMIT_FACTOR * radius * (density / satellite_density).powf(1.0 / 3.0);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_roche_limit() {
+    init();
+    trace_enter!();
+    // Saturn's main rings end at about the Roche limit for ice.
+    let roche_limit = get_roche_limit(58_232.0, 0.687, 0.9);
+    print_var!(roche_limit);
+    assert_approx_eq!(roche_limit / 136_775.0, 1.0, 0.1);
+    trace_exit!();
+  }
+}
@@ -1,4 +1,9 @@
+use crate::astronomy::brown_dwarf::BrownDwarf;
 use crate::astronomy::gas_giant_planet::GasGiantPlanet;
+use crate::astronomy::planet::constants::*;
+use crate::astronomy::planet::math::hill_sphere::get_hill_sphere;
+use crate::astronomy::planet::math::mass::jupiter_mass_to_earth_mass;
+use crate::astronomy::planet::math::roche_limit::get_roche_limit;
 use crate::astronomy::terrestrial_planet::TerrestrialPlanet;
 
 pub mod constants;
@@ -10,6 +15,8 @@ pub mod math;
 /// The `Planet` class.  This will get complicated.
 #[derive(Clone, Debug, PartialEq)]
 pub enum Planet {
+  /// Brown Dwarf.
+  BrownDwarf(BrownDwarf),
   /// Gas Giant Planet.
   GasGiantPlanet(GasGiantPlanet),
   /// Terrestrial Planet.
@@ -25,6 +32,7 @@ impl Planet {
     let result = match &self {
       TerrestrialPlanet(terrestrial_planet) => terrestrial_planet.density,
       GasGiantPlanet(gas_giant_planet) => gas_giant_planet.density,
+      BrownDwarf(brown_dwarf) => brown_dwarf.density,
     };
     trace_var!(result);
     trace_exit!();
@@ -39,6 +47,22 @@ impl Planet {
     let result = match &self {
       TerrestrialPlanet(terrestrial_planet) => terrestrial_planet.mass,
       GasGiantPlanet(gas_giant_planet) => gas_giant_planet.mass,
+      BrownDwarf(brown_dwarf) => brown_dwarf.mass,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Get mass of the planet in Mearth, whatever units it's kept in.
+  #[named]
+  pub fn get_mass_in_earth_masses(&self) -> f64 {
+    trace_enter!();
+    use Planet::*;
+    let result = match &self {
+      TerrestrialPlanet(terrestrial_planet) => terrestrial_planet.mass,
+      GasGiantPlanet(gas_giant_planet) => jupiter_mass_to_earth_mass(gas_giant_planet.mass),
+      BrownDwarf(brown_dwarf) => jupiter_mass_to_earth_mass(brown_dwarf.mass),
     };
     trace_var!(result);
     trace_exit!();
@@ -53,12 +77,134 @@ impl Planet {
     let result = match &self {
       TerrestrialPlanet(terrestrial_planet) => terrestrial_planet.radius,
       GasGiantPlanet(gas_giant_planet) => gas_giant_planet.radius,
+      BrownDwarf(brown_dwarf) => brown_dwarf.radius,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Get radius of the planet in KM, whatever units it's kept in.
+  #[named]
+  pub fn get_radius_in_km(&self) -> f64 {
+    trace_enter!();
+    use Planet::*;
+    let result = match &self {
+      TerrestrialPlanet(terrestrial_planet) => terrestrial_planet.radius * KM_PER_EARTH_RADIUS,
+      GasGiantPlanet(gas_giant_planet) => gas_giant_planet.radius * KM_PER_JUPITER_RADIUS,
+      BrownDwarf(brown_dwarf) => brown_dwarf.radius * KM_PER_JUPITER_RADIUS,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Get how readily the planet turns tides into heat (k2/Q).
+  #[named]
+  pub fn get_tidal_dissipation_factor(&self) -> f64 {
+    trace_enter!();
+    use Planet::*;
+    let result = match &self {
+      TerrestrialPlanet(_) => TERRESTRIAL_TIDAL_DISSIPATION_FACTOR,
+      GasGiantPlanet(_) | BrownDwarf(_) => GIANT_TIDAL_DISSIPATION_FACTOR,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Get the planet's moment of inertia as a fraction of MR².
+  #[named]
+  pub fn get_moment_of_inertia_factor(&self) -> f64 {
+    trace_enter!();
+    use Planet::*;
+    let result = match &self {
+      TerrestrialPlanet(_) => TERRESTRIAL_MOMENT_OF_INERTIA_FACTOR,
+      GasGiantPlanet(_) | BrownDwarf(_) => GIANT_MOMENT_OF_INERTIA_FACTOR,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Get density of the planet in g/cm³, whatever units it's kept in.
+  #[named]
+  pub fn get_density_in_grams_per_cubic_centimeter(&self) -> f64 {
+    trace_enter!();
+    use Planet::*;
+    let result = match &self {
+      TerrestrialPlanet(terrestrial_planet) => terrestrial_planet.density,
+      GasGiantPlanet(gas_giant_planet) => gas_giant_planet.density * JUPITER_DENSITY,
+      BrownDwarf(brown_dwarf) => brown_dwarf.density * JUPITER_DENSITY,
     };
     trace_var!(result);
     trace_exit!();
     result
   }
 
+  /// Get the Roche limit of the planet in KM, for a satellite with a given
+  /// density in g/cm³.
+  #[named]
+  pub fn get_roche_limit(&self, satellite_density: f64) -> f64 {
+    trace_enter!();
+    trace_var!(satellite_density);
+    let result = get_roche_limit(
+      self.get_radius_in_km(),
+      self.get_density_in_grams_per_cubic_centimeter(),
+      satellite_density,
+    );
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Get the semi-major axis of the planet's orbit, in AU.
+  #[named]
+  pub fn get_semi_major_axis(&self) -> f64 {
+    trace_enter!();
+    use Planet::*;
+    let result = match &self {
+      TerrestrialPlanet(terrestrial_planet) => terrestrial_planet.semi_major_axis,
+      GasGiantPlanet(gas_giant_planet) => gas_giant_planet.semi_major_axis,
+      BrownDwarf(brown_dwarf) => brown_dwarf.semi_major_axis,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Get the orbital eccentricity of the planet.
+  #[named]
+  pub fn get_orbital_eccentricity(&self) -> f64 {
+    trace_enter!();
+    use Planet::*;
+    let result = match &self {
+      TerrestrialPlanet(terrestrial_planet) => terrestrial_planet.orbital_eccentricity,
+      GasGiantPlanet(gas_giant_planet) => gas_giant_planet.orbital_eccentricity,
+      BrownDwarf(brown_dwarf) => brown_dwarf.orbital_eccentricity,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Get the radius of the planet's Hill sphere in KM, around a star of a
+  /// given mass in Msol.
+  #[named]
+  pub fn get_hill_sphere(&self, stellar_mass: f64) -> f64 {
+    trace_enter!();
+    trace_var!(stellar_mass);
+    let result = get_hill_sphere(
+      self.get_mass_in_earth_masses(),
+      self.get_semi_major_axis(),
+      self.get_orbital_eccentricity(),
+      stellar_mass,
+    ) * KM_PER_AU;
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
   /// Get the orbital period of the planet.
   #[named]
   pub fn get_orbital_period(&self) -> f64 {
@@ -67,6 +213,7 @@ impl Planet {
     let result = match &self {
       TerrestrialPlanet(terrestrial_planet) => terrestrial_planet.orbital_period,
       GasGiantPlanet(gas_giant_planet) => gas_giant_planet.orbital_period,
+      BrownDwarf(brown_dwarf) => brown_dwarf.orbital_period,
     };
     trace_var!(result);
     trace_exit!();
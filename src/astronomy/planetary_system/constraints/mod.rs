@@ -1,6 +1,7 @@
 use rand::prelude::*;
 use std::default::Default;
 
+use crate::astronomy::comets::constraints::Constraints as CometsConstraints;
 use crate::astronomy::host_star::constraints::Constraints as HostStarConstraints;
 use crate::astronomy::planetary_system::error::Error;
 use crate::astronomy::planetary_system::PlanetarySystem;
@@ -13,6 +14,10 @@ pub struct Constraints {
   pub host_star_constraints: Option<HostStarConstraints>,
   /// Satellite Systems constraints.
   pub satellite_systems_constraints: Option<SatelliteSystemsConstraints>,
+  /// Comets constraints.
+  pub comets_constraints: Option<CometsConstraints>,
+  /// The metallicity ([Fe/H]) of the host star(s).
+  pub metallicity: Option<f64>,
 }
 
 impl Constraints {
@@ -37,18 +42,30 @@ impl Constraints {
   pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<PlanetarySystem, Error> {
     trace_enter!();
     let host_star_constraints = self.host_star_constraints.unwrap_or(HostStarConstraints::default());
+    let host_star_constraints = HostStarConstraints {
+      metallicity: self.metallicity.or(host_star_constraints.metallicity),
+      ..host_star_constraints
+    };
     trace_var!(host_star_constraints);
     let satellite_systems_constraints = self
       .satellite_systems_constraints
       .unwrap_or(SatelliteSystemsConstraints::default());
     trace_var!(satellite_systems_constraints);
+    let comets_constraints = self.comets_constraints.unwrap_or_default();
+    trace_var!(comets_constraints);
     let host_star = host_star_constraints.generate(rng)?;
     trace_var!(host_star);
-    let satellite_systems = satellite_systems_constraints.generate(rng, &host_star)?;
+    let mut satellite_systems = satellite_systems_constraints.generate(rng, &host_star)?;
     trace_var!(satellite_systems);
+    let migrations = satellite_systems_constraints.migrate(rng, &host_star, &mut satellite_systems)?;
+    trace_var!(migrations);
+    let comets = comets_constraints.generate(rng, &host_star)?;
+    trace_var!(comets);
     let result = PlanetarySystem {
       host_star,
       satellite_systems,
+      migrations,
+      comets,
     };
     trace_var!(result);
     trace_exit!();
@@ -61,9 +78,13 @@ impl Default for Constraints {
   fn default() -> Self {
     let host_star_constraints = None;
     let satellite_systems_constraints = None;
+    let comets_constraints = None;
+    let metallicity = None;
     Self {
       host_star_constraints,
       satellite_systems_constraints,
+      comets_constraints,
+      metallicity,
     }
   }
 }
@@ -95,6 +116,16 @@ pub mod test {
     print_var!(planetary_system);
     planetary_system.check_habitable()?;
     assert!(planetary_system.is_habitable());
+    let visible_comets = planetary_system.get_visible_comets(0.0, 100.0);
+    print_var!(visible_comets);
+    assert!(visible_comets.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+    let meteor_showers = planetary_system.get_meteor_showers();
+    print_var!(meteor_showers);
+    let sporadic_hourly_rate = planetary_system.get_sporadic_hourly_rate();
+    print_var!(sporadic_hourly_rate);
+    let impact_history = planetary_system.get_habitable_impact_history().unwrap();
+    print_var!(impact_history.major_impacts);
+    print_var!(impact_history.get_impact_interval(10.0));
     trace_exit!();
     Ok(())
   }
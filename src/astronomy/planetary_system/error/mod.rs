@@ -1,9 +1,12 @@
+use crate::astronomy::comets::error::Error as CometsError;
 use crate::astronomy::host_star::error::Error as HostStarError;
 use crate::astronomy::satellite_systems::error::Error as SatelliteSystemsError;
 
 /// Star system errors.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Error {
+  /// Comets
+  CometsError(CometsError),
   /// Host Star
   HostStarError(HostStarError),
   /// Satellite Systems
@@ -13,6 +16,7 @@ pub enum Error {
 honeyholt_define_brief!(Error, |error: &Error| {
   use Error::*;
   match error {
+    CometsError(comets_error) => format!("an error occurred in the comets ({})", honeyholt_brief!(comets_error)),
     HostStarError(host_star_error) => format!(
       "an error occurred in the host star ({})",
       honeyholt_brief!(host_star_error)
@@ -24,6 +28,13 @@ honeyholt_define_brief!(Error, |error: &Error| {
   }
 });
 
+impl From<CometsError> for Error {
+  #[named]
+  fn from(error: CometsError) -> Self {
+    Error::CometsError(error)
+  }
+}
+
 impl From<HostStarError> for Error {
   #[named]
   fn from(error: HostStarError) -> Self {
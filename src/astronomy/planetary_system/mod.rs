@@ -1,11 +1,18 @@
+use crate::astronomy::comet::Comet;
+use crate::astronomy::comets::Comets;
 use crate::astronomy::host_star::HostStar;
+use crate::astronomy::impact_history::ImpactHistory;
+use crate::astronomy::meteor_shower::MeteorShower;
+use crate::astronomy::planet::Planet;
+use crate::astronomy::satellite_systems::migration::Migration;
 use crate::astronomy::satellite_systems::SatelliteSystems;
 
 pub mod constraints;
 pub mod error;
 use error::Error;
 
-/// A `PlanetarySystem` is a `HostStar` and 0+ `SatelliteSystem` objects.
+/// A `PlanetarySystem` is a `HostStar`, 0+ `SatelliteSystem` objects, and
+/// the `Comets` left over from when they formed.
 ///
 /// So a `PlanetarySystem` does not necessarily include planets.  This is
 /// confusing and I don't really like it, but I don't have a better name
@@ -14,6 +21,10 @@ use error::Error;
 pub struct PlanetarySystem {
   pub host_star: HostStar,
   pub satellite_systems: SatelliteSystems,
+  /// The giants that moved inward after they formed.
+  pub migrations: Vec<Migration>,
+  /// The icy leftovers, and the ones falling inward.
+  pub comets: Comets,
 }
 
 impl PlanetarySystem {
@@ -44,6 +55,103 @@ impl PlanetarySystem {
     result
   }
 
+  /// Retrieve the first habitable planet, or the first planet with a
+  /// habitable moon, if there is one.
+  #[named]
+  pub fn get_habitable_planet(&self) -> Option<&Planet> {
+    trace_enter!();
+    let result = self
+      .satellite_systems
+      .satellite_systems
+      .iter()
+      .find(|satellite_system| satellite_system.is_habitable())
+      .map(|satellite_system| &satellite_system.planet);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the comets that will be visible to the naked eye
+  /// from the habitable planet between the start and the end, in years from
+  /// now.
+  ///
+  /// If there's nobody around to look up, there's nothing to see.
+  #[named]
+  pub fn get_visible_comets(&self, start: f64, end: f64) -> Vec<(f64, &Comet)> {
+    trace_enter!();
+    trace_var!(start);
+    trace_var!(end);
+    let result = match self.get_habitable_planet() {
+      Some(planet) => self.comets.get_visible_comets(
+        self.host_star.get_luminosity(),
+        planet.get_semi_major_axis(),
+        start,
+        end,
+      ),
+      None => vec![],
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the meteor showers the habitable planet sees
+  /// each year, in order through the year.
+  #[named]
+  pub fn get_meteor_showers(&self) -> Vec<MeteorShower> {
+    trace_enter!();
+    let result = match self.get_habitable_planet() {
+      Some(planet) => self.comets.get_meteor_showers(&self.host_star, planet),
+      None => vec![],
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the hourly rate of sporadic meteors seen from the
+  /// habitable planet.
+  #[named]
+  pub fn get_sporadic_hourly_rate(&self) -> f64 {
+    trace_enter!();
+    let result = match self.get_habitable_planet() {
+      Some(planet) => self
+        .comets
+        .get_sporadic_hourly_rate(self.host_star.get_luminosity(), planet.get_semi_major_axis()),
+      None => 0.0,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve the impact history of the first habitable planet, or of the
+  /// first habitable moon, if there is one.
+  ///
+  /// This is where to look for the impacts that shaped its past, and how
+  /// often to expect the next one.
+  #[named]
+  pub fn get_habitable_impact_history(&self) -> Option<&ImpactHistory> {
+    trace_enter!();
+    let result = self
+      .satellite_systems
+      .satellite_systems
+      .iter()
+      .find_map(|satellite_system| match satellite_system.planet.is_habitable() {
+        true => Some(&satellite_system.impact_history),
+        false => satellite_system
+          .moons
+          .moons
+          .iter()
+          .zip(satellite_system.moon_impact_histories.iter())
+          .find(|(moon, _)| moon.is_habitable())
+          .map(|(_, impact_history)| impact_history),
+      });
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
   /// Retrieve or calculate the total mass of the stars.
   ///
   /// Calculated in Msol.
@@ -56,6 +164,17 @@ impl PlanetarySystem {
     result
   }
 
+  /// Retrieve or calculate the absolute visual (V band) magnitude of the
+  /// stars.
+  #[named]
+  pub fn get_absolute_visual_magnitude(&self) -> f64 {
+    trace_enter!();
+    let result = self.host_star.get_absolute_visual_magnitude();
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
   /// Retrieve or calculate the total number of stars in the system.
   #[named]
   pub fn get_stellar_count(&self) -> u8 {
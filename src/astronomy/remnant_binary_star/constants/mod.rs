@@ -0,0 +1,19 @@
+/// The minimum separation of the star and the remnant, in AU.
+///
+/// Closer than this and we're into cataclysmic variables and X-ray binaries,
+/// which are a whole other can of worms.
+pub const MINIMUM_SEPARATION: f64 = 0.04;
+
+/// The minimum average separation of the star and the remnant, in AU.
+pub const MINIMUM_AVERAGE_SEPARATION: f64 = 0.1;
+
+/// The maximum average separation of the star and the remnant, in AU.
+///
+/// Sirius A and B are about 20 AU apart.
+pub const MAXIMUM_AVERAGE_SEPARATION: f64 = 20.0;
+
+/// The minimum orbital eccentricity (unitless).
+pub const MINIMUM_ORBITAL_ECCENTRICITY: f64 = 0.0;
+
+/// The maximum orbital eccentricity (unitless).
+pub const MAXIMUM_ORBITAL_ECCENTRICITY: f64 = 0.6;
@@ -0,0 +1,141 @@
+use rand::prelude::*;
+use std::default::Default;
+
+use crate::astronomy::remnant_binary_star::constants::*;
+use crate::astronomy::remnant_binary_star::error::Error;
+use crate::astronomy::remnant_binary_star::RemnantBinaryStar;
+use crate::astronomy::star::constants::{MAXIMUM_MASS as MAXIMUM_STAR_MASS, MINIMUM_MASS as MINIMUM_STAR_MASS};
+use crate::astronomy::star::math::mass::get_random_stellar_mass_in_range;
+use crate::astronomy::star::Star;
+use crate::astronomy::stellar_remnant::constraints::Constraints as StellarRemnantConstraints;
+
+/// Constraints for creating a star with a remnant companion.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Constraints {
+  /// The minimum separation between the members, in AU.
+  pub minimum_average_separation: Option<f64>,
+  /// The maximum separation between the members, in AU.
+  pub maximum_average_separation: Option<f64>,
+  /// The minimum orbital eccentricity.
+  pub minimum_orbital_eccentricity: Option<f64>,
+  /// The maximum orbital eccentricity.
+  pub maximum_orbital_eccentricity: Option<f64>,
+  /// The maximum mass of the surviving star, in Msol.
+  pub maximum_star_mass: Option<f64>,
+  /// Stellar remnant constraints.
+  pub stellar_remnant_constraints: Option<StellarRemnantConstraints>,
+  /// The metallicity ([Fe/H]) shared by both members.
+  pub metallicity: Option<f64>,
+}
+
+impl Constraints {
+  /// Generate a star with a remnant companion.
+  ///
+  /// The remnant comes first; the surviving star must have started out
+  /// lighter than the remnant's progenitor, and is aged along with it.
+  #[named]
+  pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<RemnantBinaryStar, Error> {
+    trace_enter!();
+    let minimum_average_separation = self.minimum_average_separation.unwrap_or(MINIMUM_AVERAGE_SEPARATION);
+    trace_var!(minimum_average_separation);
+    let maximum_average_separation = self.maximum_average_separation.unwrap_or(MAXIMUM_AVERAGE_SEPARATION);
+    trace_var!(maximum_average_separation);
+    let minimum_orbital_eccentricity = self
+      .minimum_orbital_eccentricity
+      .unwrap_or(MINIMUM_ORBITAL_ECCENTRICITY);
+    trace_var!(minimum_orbital_eccentricity);
+    let maximum_orbital_eccentricity = self
+      .maximum_orbital_eccentricity
+      .unwrap_or(MAXIMUM_ORBITAL_ECCENTRICITY);
+    trace_var!(maximum_orbital_eccentricity);
+    let stellar_remnant_constraints = self.stellar_remnant_constraints.unwrap_or_default();
+    let stellar_remnant_constraints = StellarRemnantConstraints {
+      metallicity: self.metallicity.or(stellar_remnant_constraints.metallicity),
+      ..stellar_remnant_constraints
+    };
+    trace_var!(stellar_remnant_constraints);
+    let remnant = stellar_remnant_constraints.generate(rng)?;
+    trace_var!(remnant);
+    let maximum_star_mass = self
+      .maximum_star_mass
+      .unwrap_or(MAXIMUM_STAR_MASS)
+      .min(remnant.progenitor_mass)
+      .min(0.999 * MAXIMUM_STAR_MASS);
+    trace_var!(maximum_star_mass);
+    let star_mass = get_random_stellar_mass_in_range(rng, 1.001 * MINIMUM_STAR_MASS, maximum_star_mass)?;
+    trace_var!(star_mass);
+    let star = Star::from_mass_and_metallicity(rng, star_mass, remnant.metallicity)?.get_at_age(remnant.current_age)?;
+    trace_var!(star);
+    let average_separation = rng.gen_range(minimum_average_separation..maximum_average_separation);
+    trace_var!(average_separation);
+    let orbital_eccentricity = rng.gen_range(minimum_orbital_eccentricity..maximum_orbital_eccentricity);
+    trace_var!(orbital_eccentricity);
+    let result = RemnantBinaryStar::from_members(star, remnant, average_separation, orbital_eccentricity)?;
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+}
+
+impl Default for Constraints {
+  /// No constraints, just let it all hang out.
+  fn default() -> Self {
+    let minimum_average_separation = None;
+    let maximum_average_separation = None;
+    let minimum_orbital_eccentricity = None;
+    let maximum_orbital_eccentricity = None;
+    let maximum_star_mass = None;
+    let stellar_remnant_constraints = None;
+    let metallicity = None;
+    Self {
+      minimum_average_separation,
+      maximum_average_separation,
+      minimum_orbital_eccentricity,
+      maximum_orbital_eccentricity,
+      maximum_star_mass,
+      stellar_remnant_constraints,
+      metallicity,
+    }
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_generate() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    for _ in 0..20 {
+      let remnant_binary_star = Constraints::default().generate(&mut rng)?;
+      trace_var!(remnant_binary_star);
+      assert!(remnant_binary_star.star.mass <= remnant_binary_star.remnant.progenitor_mass);
+      assert_approx_eq!(
+        remnant_binary_star.star.metallicity,
+        remnant_binary_star.remnant.metallicity
+      );
+      assert_approx_eq!(
+        remnant_binary_star.star.current_age,
+        remnant_binary_star.remnant.current_age
+      );
+      assert!(!remnant_binary_star.is_habitable());
+    }
+    let remnant_binary_star = Constraints {
+      minimum_average_separation: Some(5.0),
+      ..Constraints::default()
+    }
+    .generate(&mut rng)?;
+    print_var!(remnant_binary_star);
+    assert!(remnant_binary_star.average_separation >= 5.0);
+    trace_exit!();
+    Ok(())
+  }
+}
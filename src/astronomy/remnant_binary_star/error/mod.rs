@@ -0,0 +1,39 @@
+use crate::astronomy::star::error::Error as StarError;
+use crate::astronomy::stellar_remnant::error::Error as StellarRemnantError;
+
+/// Remnant binary star-related errors.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Error {
+  /// Star Error.
+  StarError(StarError),
+  /// Stellar Remnant Error.
+  StellarRemnantError(StellarRemnantError),
+  /// Lower than MINIMUM_SEPARATION.
+  BinaryStarsTooCloseForComfort,
+}
+
+honeyholt_define_brief!(Error, |error: &Error| {
+  use Error::*;
+  match error {
+    StarError(star_error) => format!("an error occurred in the star ({})", honeyholt_brief!(star_error)),
+    StellarRemnantError(stellar_remnant_error) => format!(
+      "an error occurred in the stellar remnant ({})",
+      honeyholt_brief!(stellar_remnant_error)
+    ),
+    BinaryStarsTooCloseForComfort => "the stars are too close together to be stable".to_string(),
+  }
+});
+
+impl From<StarError> for Error {
+  #[named]
+  fn from(error: StarError) -> Self {
+    Error::StarError(error)
+  }
+}
+
+impl From<StellarRemnantError> for Error {
+  #[named]
+  fn from(error: StellarRemnantError) -> Self {
+    Error::StellarRemnantError(error)
+  }
+}
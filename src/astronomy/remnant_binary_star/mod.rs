@@ -0,0 +1,241 @@
+use crate::astronomy::close_binary_star::math::barycenter::{
+  get_average_distances_from_barycenter, get_maximum_distances_from_barycenter, get_minimum_distances_from_barycenter,
+};
+use crate::astronomy::close_binary_star::math::separation::{get_maximum_separation, get_minimum_separation};
+use crate::astronomy::star::math::habitable_zone::star_luminosity_to_habitable_zone;
+use crate::astronomy::star::math::magnitude::{
+  combine_magnitudes, star_luminosity_and_temperature_to_absolute_visual_magnitude,
+};
+use crate::astronomy::star::Star;
+use crate::astronomy::stellar_remnant::StellarRemnant;
+
+pub mod constants;
+use constants::*;
+pub mod constraints;
+pub mod error;
+use error::Error;
+
+/// A `RemnantBinaryStar` is a `Star` paired with a `StellarRemnant`.
+///
+/// Think Sirius or Procyon: the originally heavier star has already lived
+/// fast and died young, leaving its companion orbiting the remains.  Like a
+/// `CloseBinaryStar`, planets orbit the pair rather than either member.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemnantBinaryStar {
+  /// The surviving star, originally the less massive of the pair.
+  pub star: Star,
+  /// The remains of the originally more massive star.
+  pub remnant: StellarRemnant,
+  /// Average separation of the binary components, in AU.
+  pub average_separation: f64,
+  /// Orbital eccentricity of the components (unitless).
+  pub orbital_eccentricity: f64,
+  /// Average distance from barycenter of the components, in AU.
+  pub average_distances_from_barycenter: (f64, f64),
+  /// Minimum separation of the components, in AU.
+  pub minimum_separation: f64,
+  /// Maximum separation of the components, in AU.
+  pub maximum_separation: f64,
+  /// Habitable zone, in AU.
+  pub habitable_zone: (f64, f64),
+  /// Satellite zone, in AU.
+  pub satellite_zone: (f64, f64),
+  /// Frost line, in AU.
+  pub frost_line: f64,
+}
+
+impl RemnantBinaryStar {
+  /// Create from a star and a remnant, average separation, and orbital eccentricity.
+  #[named]
+  pub fn from_members(
+    star: Star,
+    remnant: StellarRemnant,
+    average_separation: f64,
+    orbital_eccentricity: f64,
+  ) -> Result<Self, Error> {
+    trace_enter!();
+    trace_var!(star);
+    trace_var!(remnant);
+    trace_var!(average_separation);
+    trace_var!(orbital_eccentricity);
+    let average_distances_from_barycenter =
+      get_average_distances_from_barycenter(average_separation, star.mass, remnant.mass);
+    trace_var!(average_distances_from_barycenter);
+    let minimum_separation = get_minimum_separation(get_minimum_distances_from_barycenter(
+      average_separation,
+      star.mass,
+      remnant.mass,
+      orbital_eccentricity,
+    ));
+    trace_var!(minimum_separation);
+    if minimum_separation < MINIMUM_SEPARATION {
+      return Err(Error::BinaryStarsTooCloseForComfort);
+    }
+    let maximum_separation = get_maximum_separation(get_maximum_distances_from_barycenter(
+      average_separation,
+      star.mass,
+      remnant.mass,
+      orbital_eccentricity,
+    ));
+    trace_var!(maximum_separation);
+    let habitable_zone = star_luminosity_to_habitable_zone(star.luminosity + remnant.luminosity);
+    trace_var!(habitable_zone);
+    let combined_mass = star.mass + remnant.mass;
+    let satellite_zone = (0.1 * combined_mass, 40.0 * combined_mass);
+    trace_var!(satellite_zone);
+    // The frost line scales with the square root of the luminosity.
+    let frost_line = (star.frost_line.powf(2.0) + remnant.frost_line.powf(2.0)).sqrt();
+    trace_var!(frost_line);
+    let result = RemnantBinaryStar {
+      star,
+      remnant,
+      average_separation,
+      orbital_eccentricity,
+      average_distances_from_barycenter,
+      minimum_separation,
+      maximum_separation,
+      habitable_zone,
+      satellite_zone,
+      frost_line,
+    };
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Retrieve or calculate the age of the stars.
+  ///
+  /// Calculated in Gyr.
+  #[named]
+  pub fn get_current_age(&self) -> f64 {
+    trace_enter!();
+    let result = self.star.current_age;
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the XUV flux at a distance in AU, in erg/cm²/s.
+  ///
+  /// The remnant contributes nothing here; only the star is active.
+  #[named]
+  pub fn get_xuv_flux(&self, distance: f64) -> f64 {
+    trace_enter!();
+    trace_var!(distance);
+    let result = self.star.get_xuv_flux(distance);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the rate of flares at or above an energy in ergs,
+  /// in flares per year.
+  #[named]
+  pub fn get_flare_rate(&self, energy: f64) -> f64 {
+    trace_enter!();
+    trace_var!(energy);
+    let result = self.star.get_flare_rate(energy);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the metallicity ([Fe/H]) of the stars.
+  ///
+  /// They formed together, so they share a metallicity.
+  #[named]
+  pub fn get_metallicity(&self) -> f64 {
+    trace_enter!();
+    let result = self.star.metallicity;
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the total mass of the stars.
+  ///
+  /// Calculated in Msol.
+  #[named]
+  pub fn get_stellar_mass(&self) -> f64 {
+    trace_enter!();
+    let result = self.star.mass + self.remnant.mass;
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Measured in Lsol.
+  #[named]
+  pub fn get_luminosity(&self) -> f64 {
+    trace_enter!();
+    let result = self.star.luminosity + self.remnant.luminosity;
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the absolute visual (V band) magnitude of the
+  /// star and remnant together.
+  #[named]
+  pub fn get_absolute_visual_magnitude(&self) -> f64 {
+    trace_enter!();
+    let remnant_magnitude =
+      star_luminosity_and_temperature_to_absolute_visual_magnitude(self.remnant.luminosity, self.remnant.temperature);
+    trace_var!(remnant_magnitude);
+    let result = combine_magnitudes(self.star.get_absolute_visual_magnitude(), remnant_magnitude);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  #[named]
+  pub fn get_name(&self) -> String {
+    trace_enter!();
+    let result = format!("{}-{}", self.star.name, self.remnant.name);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the habitable zone at a given age, in AU.
+  ///
+  /// Both members are evolved to the specified age.
+  #[named]
+  pub fn get_habitable_zone_at_age(&self, age: f64) -> Result<(f64, f64), Error> {
+    trace_enter!();
+    trace_var!(age);
+    let star = self.star.get_at_age(age)?;
+    trace_var!(star);
+    let remnant = self.remnant.get_at_age(age)?;
+    trace_var!(remnant);
+    let result = star_luminosity_to_habitable_zone(star.luminosity + remnant.luminosity);
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Indicate whether this system is capable of supporting conventional life.
+  #[named]
+  pub fn check_habitable(&self) -> Result<(), Error> {
+    trace_enter!();
+    self.remnant.check_habitable()?;
+    self.star.check_habitable()?;
+    let result = Ok(());
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Indicate whether this system is capable of supporting conventional life.
+  #[named]
+  pub fn is_habitable(&self) -> bool {
+    trace_enter!();
+    let result = match self.check_habitable() {
+      Ok(()) => true,
+      Err(_) => false,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
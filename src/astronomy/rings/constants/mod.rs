@@ -0,0 +1,40 @@
+/// The density of ring particles, in g/cm³; mostly ice, loosely packed.
+pub const RING_PARTICLE_DENSITY: f64 = 0.9;
+
+/// The probability that a giant with a moon close enough to feed them has
+/// broad, bright rings rather than just a faint haze of dust.
+pub const BRIGHT_RINGS_PROBABILITY: f64 = 0.3;
+
+/// A moon within this multiple of the Roche limit can feed rings, whether by
+/// being torn apart or by shedding debris.
+pub const RING_SOURCE_DISTANCE_FACTOR: f64 = 2.0;
+
+/// The least optical depth of bright rings.
+pub const MINIMUM_BRIGHT_OPTICAL_DEPTH: f64 = 0.05;
+
+/// The greatest optical depth of bright rings; Saturn's B ring reaches this.
+pub const MAXIMUM_BRIGHT_OPTICAL_DEPTH: f64 = 2.0;
+
+/// The least optical depth of faint dust rings.
+pub const MINIMUM_FAINT_OPTICAL_DEPTH: f64 = 1.0E-7;
+
+/// The greatest optical depth of faint dust rings.
+pub const MAXIMUM_FAINT_OPTICAL_DEPTH: f64 = 1.0E-4;
+
+/// Inside the frost line, the ice has sublimated away, leaving much thinner
+/// rings of rock; this is how much of the optical depth is left.
+pub const ROCKY_OPTICAL_DEPTH_FACTOR: f64 = 0.1;
+
+/// Rings thinner than this can't be seen with the naked eye.
+pub const MINIMUM_VISIBLE_OPTICAL_DEPTH: f64 = 0.01;
+
+/// The least inner radius of rings, in planetary radii; any closer and they
+/// drag in the upper atmosphere.
+pub const MINIMUM_INNER_RADIUS: f64 = 1.1;
+
+/// The greatest inner radius of rings, in planetary radii.
+pub const MAXIMUM_INNER_RADIUS: f64 = 1.5;
+
+/// A moon embedded in rings clears a gap this many of its Hill radii to
+/// either side of it, like Pan's Encke Gap.
+pub const GAP_HALF_WIDTH: f64 = 8.0;
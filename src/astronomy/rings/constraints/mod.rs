@@ -0,0 +1,166 @@
+use rand::prelude::*;
+
+use crate::astronomy::host_star::HostStar;
+use crate::astronomy::moon::constants::EARTH_MASS_PER_MOON_MASS;
+use crate::astronomy::moon::Moon;
+use crate::astronomy::planet::Planet;
+use crate::astronomy::rings::constants::*;
+use crate::astronomy::rings::error::Error;
+use crate::astronomy::rings::ring_composition::RingComposition;
+use crate::astronomy::rings::Rings;
+use crate::astronomy::satellite_systems::constants::HOT_JUPITER_MAXIMUM_SEMI_MAJOR_AXIS;
+
+/// Constraints for creating rings.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Constraints {
+  /// The probability that a giant with a moon to feed them has bright rings.
+  pub bright_rings_probability: Option<f64>,
+}
+
+impl Constraints {
+  /// Generate.
+  ///
+  /// Only giants get rings.  Bright ones need a moon close enough to the
+  /// Roche limit to feed them, and a hot Jupiter can't hold on to them at
+  /// all; the rest get a faint haze of dust.
+  #[named]
+  pub fn generate<R: Rng + ?Sized>(
+    &self,
+    rng: &mut R,
+    host_star: &HostStar,
+    star_distance: f64,
+    planet: &Planet,
+    moons: &[Moon],
+  ) -> Result<Option<Rings>, Error> {
+    trace_enter!();
+    trace_var!(host_star);
+    trace_var!(star_distance);
+    trace_var!(planet);
+    if !matches!(planet, Planet::GasGiantPlanet(_)) {
+      trace_exit!();
+      return Ok(None);
+    }
+    let bright_rings_probability = self.bright_rings_probability.unwrap_or(BRIGHT_RINGS_PROBABILITY);
+    trace_var!(bright_rings_probability);
+    let planet_radius = planet.get_radius_in_km();
+    trace_var!(planet_radius);
+    let roche_limit = planet.get_roche_limit(RING_PARTICLE_DENSITY);
+    trace_var!(roche_limit);
+    let has_source = moons
+      .iter()
+      .any(|moon| moon.semi_major_axis < RING_SOURCE_DISTANCE_FACTOR * roche_limit);
+    trace_var!(has_source);
+    let is_bright = has_source
+      && star_distance >= HOT_JUPITER_MAXIMUM_SEMI_MAJOR_AXIS
+      && rng.gen_range(0.0..1.0) < bright_rings_probability;
+    trace_var!(is_bright);
+    let (composition, optical_depth) = match is_bright {
+      true => {
+        let optical_depth = rng
+          .gen_range(MINIMUM_BRIGHT_OPTICAL_DEPTH.ln()..MAXIMUM_BRIGHT_OPTICAL_DEPTH.ln())
+          .exp();
+        match star_distance >= host_star.get_frost_line() {
+          true => (RingComposition::Icy, optical_depth),
+          false => (RingComposition::Rocky, ROCKY_OPTICAL_DEPTH_FACTOR * optical_depth),
+        }
+      },
+      false => {
+        let optical_depth = rng
+          .gen_range(MINIMUM_FAINT_OPTICAL_DEPTH.ln()..MAXIMUM_FAINT_OPTICAL_DEPTH.ln())
+          .exp();
+        (RingComposition::Dusty, optical_depth)
+      },
+    };
+    trace_var!(composition);
+    trace_var!(optical_depth);
+    let inner_radius = planet_radius * rng.gen_range(MINIMUM_INNER_RADIUS..MAXIMUM_INNER_RADIUS);
+    trace_var!(inner_radius);
+    let outer_radius = inner_radius.max(roche_limit * rng.gen_range(0.8..1.0));
+    trace_var!(outer_radius);
+    let planet_mass = planet.get_mass_in_earth_masses();
+    trace_var!(planet_mass);
+    let gaps = moons
+      .iter()
+      .filter(|moon| moon.semi_major_axis > inner_radius && moon.semi_major_axis < outer_radius)
+      .map(|moon| {
+        let hill_radius =
+          moon.semi_major_axis * (moon.mass * EARTH_MASS_PER_MOON_MASS / (3.0 * planet_mass)).powf(1.0 / 3.0);
+        let half_width = GAP_HALF_WIDTH * hill_radius;
+        (
+          (moon.semi_major_axis - half_width).max(inner_radius),
+          (moon.semi_major_axis + half_width).min(outer_radius),
+        )
+      })
+      .collect();
+    trace_var!(gaps);
+    let result = Some(Rings {
+      inner_radius,
+      outer_radius,
+      optical_depth,
+      composition,
+      gaps,
+    });
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+}
+
+impl Default for Constraints {
+  /// No constraints, just let it all hang out.
+  fn default() -> Self {
+    let bright_rings_probability = None;
+    Self {
+      bright_rings_probability,
+    }
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::astronomy::host_star::constraints::Constraints as HostStarConstraints;
+  use crate::astronomy::moons::constraints::Constraints as MoonsConstraints;
+  use crate::astronomy::planet::constraints::Constraints as PlanetConstraints;
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_generate() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let host_star = &HostStarConstraints::default().generate(&mut rng)?;
+    trace_var!(host_star);
+    let star_distance = host_star.get_frost_line() + 1.0;
+    trace_var!(star_distance);
+    let planet_constraints = PlanetConstraints {
+      mass: Some(317.8),
+      ..PlanetConstraints::default()
+    };
+    let planet = &planet_constraints.generate(&mut rng, host_star, star_distance)?;
+    trace_var!(planet);
+    let moons = MoonsConstraints::default()
+      .generate(&mut rng, host_star, star_distance, planet)
+      .unwrap();
+    let constraints = Constraints {
+      bright_rings_probability: Some(1.0),
+    };
+    let rings = constraints
+      .generate(&mut rng, host_star, star_distance, planet, &moons.moons)?
+      .unwrap();
+    print_var!(rings);
+    assert!(rings.inner_radius > planet.get_radius_in_km());
+    assert!(rings.outer_radius <= planet.get_roche_limit(RING_PARTICLE_DENSITY));
+    assert!(rings
+      .gaps
+      .iter()
+      .all(|&(inner, outer)| inner >= rings.inner_radius && outer <= rings.outer_radius));
+    trace_exit!();
+    Ok(())
+  }
+}
@@ -0,0 +1,109 @@
+pub mod constants;
+use constants::*;
+pub mod constraints;
+pub mod error;
+pub mod ring_composition;
+use ring_composition::RingComposition;
+
+/// The `Rings` of a planet.
+///
+/// All of the giants in our own system have them, but only Saturn's are much
+/// to look at.  They sit inside the Roche limit, where nothing bigger than a
+/// boulder can hold itself together, and any moons that orbit among them
+/// sweep out gaps.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rings {
+  /// Inner radius, in KM from the center of the planet.
+  pub inner_radius: f64,
+  /// Outer radius, in KM from the center of the planet.
+  pub outer_radius: f64,
+  /// Normal optical depth; above 1 or so, the rings are opaque.
+  pub optical_depth: f64,
+  /// What they're made of.
+  pub composition: RingComposition,
+  /// Gaps swept out by moons, as inner and outer radii in KM.
+  pub gaps: Vec<(f64, f64)>,
+}
+
+impl Rings {
+  /// Retrieve or calculate the fraction of light the rings block.
+  #[named]
+  pub fn get_opacity(&self) -> f64 {
+    trace_enter!();
+    let result = 1.0 - (-self.optical_depth).exp();
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Indicate whether the rings can be seen with the naked eye, from the
+  /// planet or its moons.
+  #[named]
+  pub fn is_visible(&self) -> bool {
+    trace_enter!();
+    let result = self.optical_depth >= MINIMUM_VISIBLE_OPTICAL_DEPTH;
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the band of latitudes, in degrees, that lie in the
+  /// shadow of the rings at noon.
+  ///
+  /// The shadow falls on the hemisphere tilted away from the star, so these
+  /// are measured from the equator into that hemisphere.  The star's
+  /// elevation above the ring plane is in degrees, and the planet's radius
+  /// is in KM.  At equinox, the shadow is just a line along the equator, and
+  /// rings too faint to see don't cast much of one.
+  #[named]
+  pub fn get_shadow_latitudes(&self, stellar_elevation: f64, planet_radius: f64) -> Option<(f64, f64)> {
+    trace_enter!();
+    trace_var!(stellar_elevation);
+    trace_var!(planet_radius);
+    let elevation = stellar_elevation.abs().to_radians();
+    trace_var!(elevation);
+    let result = match self.is_visible() && elevation > 0.0 {
+      // A ray from latitude φ toward the star crosses the ring plane at
+      // r = R sin(φ + δ) / sin δ.
+      true => {
+        let get_latitude =
+          |radius: f64| ((radius / planet_radius * elevation.sin()).min(1.0).asin() - elevation).to_degrees();
+        Some((get_latitude(self.inner_radius), get_latitude(self.outer_radius)))
+      },
+      false => None,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_shadow() {
+    init();
+    trace_enter!();
+    // Saturn's main rings.
+    let rings = Rings {
+      inner_radius: 74_658.0,
+      outer_radius: 136_775.0,
+      optical_depth: 1.0,
+      composition: RingComposition::Icy,
+      gaps: vec![(133_410.0, 133_740.0)],
+    };
+    assert!(rings.is_visible());
+    assert_approx_eq!(rings.get_opacity(), 0.632, 0.001);
+    assert_eq!(rings.get_shadow_latitudes(0.0, 58_232.0), None);
+    // Near solstice, the shadow reaches well into the winter hemisphere.
+    let (inner, outer) = rings.get_shadow_latitudes(26.7, 58_232.0).unwrap();
+    print_var!((inner, outer));
+    assert!(inner > 5.0 && inner < outer && outer < 90.0);
+    trace_exit!();
+  }
+}
@@ -0,0 +1,30 @@
+/// The `RingComposition` type.
+///
+/// What a planet's rings are mostly made of, which decides how bright they
+/// are.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RingComposition {
+  /// Water ice, bright and white, like Saturn's.
+  Icy,
+  /// Rock, dark and reddish; ice doesn't last this close to the star.
+  Rocky,
+  /// Fine dust knocked off the inner moons, like Jupiter's.
+  Dusty,
+}
+
+impl RingComposition {
+  /// Get the typical albedo of the ring particles.
+  #[named]
+  pub fn get_albedo(&self) -> f64 {
+    trace_enter!();
+    use RingComposition::*;
+    let result = match self {
+      Icy => 0.5,
+      Rocky => 0.1,
+      Dusty => 0.05,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
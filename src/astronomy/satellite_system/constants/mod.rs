@@ -1 +1,10 @@
+/// The mass of a giant we'd put a habitable moon around, in Mearth; a
+/// Jupiter, which has plenty of room for one.
+pub const HABITABLE_MOON_PLANET_MASS: f64 = 317.8;
 
+/// The shortest a planet's day can be when it forms, in days; any faster,
+/// and it would fly apart.
+pub const MINIMUM_PRIMORDIAL_ROTATION_PERIOD: f64 = 0.2;
+
+/// The longest a planet's day is likely to be when it forms, in days.
+pub const MAXIMUM_PRIMORDIAL_ROTATION_PERIOD: f64 = 0.5;
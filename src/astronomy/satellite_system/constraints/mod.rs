@@ -1,10 +1,16 @@
 use rand::prelude::*;
 
 use crate::astronomy::host_star::HostStar;
+use crate::astronomy::impact_history::constraints::Constraints as ImpactHistoryConstraints;
+use crate::astronomy::moon::moon_origin::MoonOrigin;
+use crate::astronomy::moons::constants::STABLE_HILL_SPHERE_FRACTION;
 use crate::astronomy::moons::constraints::Constraints as MoonsConstraints;
 use crate::astronomy::planet::constraints::Constraints as PlanetConstraints;
+use crate::astronomy::rings::constraints::Constraints as RingsConstraints;
+use crate::astronomy::satellite_system::constants::*;
 use crate::astronomy::satellite_system::error::Error;
 use crate::astronomy::satellite_system::SatelliteSystem;
+use crate::astronomy::trojans::constraints::Constraints as TrojansConstraints;
 
 /// Constraints for creating a planet and its moons.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -13,6 +19,12 @@ pub struct Constraints {
   pub planet_constraints: Option<PlanetConstraints>,
   /// Moons constraints.
   pub moons_constraints: Option<MoonsConstraints>,
+  /// Rings constraints.
+  pub rings_constraints: Option<RingsConstraints>,
+  /// Trojans constraints.
+  pub trojans_constraints: Option<TrojansConstraints>,
+  /// Impact History constraints.
+  pub impact_history_constraints: Option<ImpactHistoryConstraints>,
 }
 
 impl Constraints {
@@ -25,7 +37,32 @@ impl Constraints {
     }
   }
 
+  /// A giant with a moon big enough to be habitable; whether it is depends
+  /// on where we put it.
+  pub fn habitable_moon() -> Self {
+    let planet_constraints = Some(PlanetConstraints {
+      mass: Some(HABITABLE_MOON_PLANET_MASS),
+      ..PlanetConstraints::default()
+    });
+    let moons_constraints = Some(MoonsConstraints {
+      generate_habitable: true,
+      ..MoonsConstraints::default()
+    });
+    Self {
+      planet_constraints,
+      moons_constraints,
+      ..Constraints::default()
+    }
+  }
+
   /// Generate.
+  ///
+  /// The planet starts out spinning fast, and its moons' tides spend the age
+  /// of the star slowing it down, the heaviest moon first.  Irregular moons
+  /// are too far out to notice.  Moons that spiral in past their Roche limit
+  /// are torn apart, and any that wander out of the planet's Hill sphere are
+  /// lost.  Trojan moons are too small to migrate on their own, so they
+  /// just follow the moon whose orbit they share.
   #[named]
   pub fn generate<R: Rng + ?Sized>(
     &self,
@@ -40,11 +77,68 @@ impl Constraints {
     trace_var!(planet_constraints);
     let moons_constraints = self.moons_constraints.unwrap_or(MoonsConstraints::default());
     trace_var!(moons_constraints);
+    let rings_constraints = self.rings_constraints.unwrap_or_default();
+    trace_var!(rings_constraints);
+    let trojans_constraints = self.trojans_constraints.unwrap_or_default();
+    trace_var!(trojans_constraints);
+    let impact_history_constraints = self.impact_history_constraints.unwrap_or_default();
+    trace_var!(impact_history_constraints);
     let planet = planet_constraints.generate(rng, host_star, star_distance)?;
     trace_var!(planet);
-    let moons = moons_constraints.generate(rng, host_star, star_distance, &planet)?;
+    let mut moons = moons_constraints.generate(rng, host_star, star_distance, &planet)?;
+    let mut planet_rotation_period =
+      rng.gen_range(MINIMUM_PRIMORDIAL_ROTATION_PERIOD..MAXIMUM_PRIMORDIAL_ROTATION_PERIOD);
+    trace_var!(planet_rotation_period);
+    let mut indices: Vec<usize> = (0..moons.moons.len())
+      .filter(|&index| matches!(moons.moons[index].origin, MoonOrigin::Regular | MoonOrigin::GiantImpact))
+      .collect();
+    indices.sort_by(|&a, &b| moons.moons[b].mass.partial_cmp(&moons.moons[a].mass).unwrap());
+    for index in indices {
+      let semi_major_axis = moons.moons[index].semi_major_axis;
+      planet_rotation_period =
+        moons.moons[index].evolve_tides(host_star, star_distance, &planet, planet_rotation_period)?;
+      let new_semi_major_axis = moons.moons[index].semi_major_axis;
+      for moon in moons
+        .moons
+        .iter_mut()
+        .filter(|moon| matches!(moon.origin, MoonOrigin::Trojan(_)) && moon.semi_major_axis == semi_major_axis)
+      {
+        moon.update_orbit(new_semi_major_axis, host_star, star_distance, &planet)?;
+      }
+    }
+    trace_var!(planet_rotation_period);
+    // Anything inside its own Roche limit gets torn apart, and anything past
+    // the stable part of the Hill sphere wanders off.
+    let outermost_orbit = STABLE_HILL_SPHERE_FRACTION * planet.get_hill_sphere(host_star.get_stellar_mass());
+    trace_var!(outermost_orbit);
+    moons.moons.retain(|moon| {
+      moon.semi_major_axis > planet.get_roche_limit(moon.density) && moon.semi_major_axis < outermost_orbit
+    });
+    moons
+      .moons
+      .sort_by(|a, b| a.semi_major_axis.partial_cmp(&b.semi_major_axis).unwrap());
     trace_var!(moons);
-    let result = SatelliteSystem { planet, moons };
+    let rings = rings_constraints.generate(rng, host_star, star_distance, &planet, &moons.moons)?;
+    trace_var!(rings);
+    let trojans = trojans_constraints.generate(rng, host_star, star_distance, &planet)?;
+    trace_var!(trojans);
+    let impact_history = impact_history_constraints.generate_for_planet(rng, host_star, &planet, &moons)?;
+    trace_var!(impact_history);
+    let moon_impact_histories = moons
+      .moons
+      .iter()
+      .map(|moon| impact_history_constraints.generate_for_moon(rng, host_star, moon))
+      .collect::<Result<Vec<_>, _>>()?;
+    trace_var!(moon_impact_histories);
+    let result = SatelliteSystem {
+      planet,
+      moons,
+      rings,
+      trojans,
+      planet_rotation_period,
+      impact_history,
+      moon_impact_histories,
+    };
     trace_var!(result);
     trace_exit!();
     Ok(result)
@@ -56,9 +150,15 @@ impl Default for Constraints {
   fn default() -> Self {
     let planet_constraints = None;
     let moons_constraints = None;
+    let rings_constraints = None;
+    let trojans_constraints = None;
+    let impact_history_constraints = None;
     Self {
       planet_constraints,
       moons_constraints,
+      rings_constraints,
+      trojans_constraints,
+      impact_history_constraints,
     }
   }
 }
@@ -67,6 +167,7 @@ impl Default for Constraints {
 pub mod test {
 
   use crate::astronomy::host_star::constraints::Constraints as HostStarConstraints;
+  use crate::astronomy::moon::constants::MINIMUM_HABITABLE_MASS;
   use rand::prelude::*;
 
   use super::*;
@@ -88,4 +189,35 @@ pub mod test {
     trace_exit!();
     Ok(())
   }
+
+  #[named]
+  #[test]
+  pub fn test_habitable_moon() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let host_star = HostStarConstraints::habitable().generate(&mut rng)?;
+    // Where Earth would be, by the light it gets.
+    let distance = host_star.get_luminosity().sqrt();
+    let satellite_system = &Constraints::habitable_moon().generate(&mut rng, &host_star, distance)?;
+    trace_var!(satellite_system);
+    print_var!(satellite_system);
+    assert!(!satellite_system.planet.is_habitable());
+    let moon = satellite_system
+      .moons
+      .moons
+      .iter()
+      .find(|moon| moon.mass >= MINIMUM_HABITABLE_MASS)
+      .unwrap();
+    print_var!(moon);
+    assert_eq!(moon.check_habitable(), Ok(()));
+    assert_eq!(satellite_system.check_habitable(), Ok(()));
+    assert_eq!(
+      satellite_system.moon_impact_histories.len(),
+      satellite_system.moons.moons.len()
+    );
+    trace_exit!();
+    Ok(())
+  }
 }
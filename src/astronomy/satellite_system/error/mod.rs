@@ -1,19 +1,28 @@
 use crate::astronomy::host_star::error::Error as HostStarError;
+use crate::astronomy::impact_history::error::Error as ImpactHistoryError;
 use crate::astronomy::moon::error::Error as MoonError;
 use crate::astronomy::moons::error::Error as MoonsError;
 use crate::astronomy::planet::error::Error as PlanetError;
+use crate::astronomy::rings::error::Error as RingsError;
+use crate::astronomy::trojans::error::Error as TrojansError;
 
 /// Moon-related errors.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Error {
   /// Host Star Error.
   HostStarError(HostStarError),
+  /// Impact History Error.
+  ImpactHistoryError(ImpactHistoryError),
   /// Moon Error.
   MoonError(MoonError),
   /// Moons Error.
   MoonsError(MoonsError),
   /// Planet Error.
   PlanetError(PlanetError),
+  /// Rings Error.
+  RingsError(RingsError),
+  /// Trojans Error.
+  TrojansError(TrojansError),
 }
 
 honeyholt_define_brief!(Error, |error: &Error| {
@@ -23,9 +32,15 @@ honeyholt_define_brief!(Error, |error: &Error| {
       "an error occurred in the host star ({})",
       honeyholt_brief!(host_star_error)
     ),
+    ImpactHistoryError(impact_history_error) => format!(
+      "an error occurred in the impact history ({})",
+      honeyholt_brief!(impact_history_error)
+    ),
     MoonError(moon_error) => format!("an error occurred in the moon ({})", honeyholt_brief!(moon_error)),
     MoonsError(moons_error) => format!("an error occurred in the moons ({})", honeyholt_brief!(moons_error)),
     PlanetError(planet_error) => format!("an error occurred in the planet ({})", honeyholt_brief!(planet_error)),
+    RingsError(rings_error) => format!("an error occurred in the rings ({})", honeyholt_brief!(rings_error)),
+    TrojansError(trojans_error) => format!("an error occurred in the trojans ({})", honeyholt_brief!(trojans_error)),
   }
 });
 
@@ -43,6 +58,13 @@ impl From<HostStarError> for Error {
   }
 }
 
+impl From<ImpactHistoryError> for Error {
+  #[named]
+  fn from(error: ImpactHistoryError) -> Self {
+    Error::ImpactHistoryError(error)
+  }
+}
+
 impl From<MoonsError> for Error {
   #[named]
   fn from(error: MoonsError) -> Self {
@@ -56,3 +78,17 @@ impl From<PlanetError> for Error {
     Error::PlanetError(error)
   }
 }
+
+impl From<RingsError> for Error {
+  #[named]
+  fn from(error: RingsError) -> Self {
+    Error::RingsError(error)
+  }
+}
+
+impl From<TrojansError> for Error {
+  #[named]
+  fn from(error: TrojansError) -> Self {
+    Error::TrojansError(error)
+  }
+}
@@ -1,18 +1,33 @@
+use crate::astronomy::impact_history::ImpactHistory;
 use crate::astronomy::moons::Moons;
 use crate::astronomy::planet::Planet;
+use crate::astronomy::rings::Rings;
+use crate::astronomy::trojans::Trojans;
 
 pub mod constants;
 pub mod constraints;
 pub mod error;
 use error::Error;
 
-/// A `SatelliteSystem` is a collection of a `Planet` and `Moons`.
+/// A `SatelliteSystem` is a collection of a `Planet`, `Moons`, and maybe
+/// `Rings` and `Trojans`, along with what's hit them.
 #[derive(Clone, Debug, PartialEq)]
 pub struct SatelliteSystem {
   /// The planet.
   pub planet: Planet,
   /// The moons.
   pub moons: Moons,
+  /// The rings, if any.
+  pub rings: Option<Rings>,
+  /// Anything sharing the planet's orbit at its Lagrange points.
+  pub trojans: Vec<Trojans>,
+  /// The planet's rotation period, in days, after its moons have had their
+  /// way with it.
+  pub planet_rotation_period: f64,
+  /// What's hit the planet.
+  pub impact_history: ImpactHistory,
+  /// What's hit each of the moons, in the same order.
+  pub moon_impact_histories: Vec<ImpactHistory>,
 }
 
 impl SatelliteSystem {
@@ -20,11 +35,20 @@ impl SatelliteSystem {
   #[named]
   pub fn check_habitable(&self) -> Result<(), Error> {
     trace_enter!();
-    let result = {
-      self.planet.check_habitable()?;
-      // Perhaps someday.
-      // self.moons.check_habitable()?;
-      Ok(())
+    // The planet, one of its moons, or a trojan planet will do.
+    let result = match self.planet.check_habitable() {
+      Ok(()) => Ok(()),
+      Err(_) if self.moons.is_habitable() => Ok(()),
+      Err(_)
+        if self
+          .trojans
+          .iter()
+          .filter_map(|trojans| trojans.planet.as_ref())
+          .any(|planet| planet.is_habitable()) =>
+      {
+        Ok(())
+      },
+      Err(error) => Err(error.into()),
     };
     trace_var!(result);
     trace_exit!();
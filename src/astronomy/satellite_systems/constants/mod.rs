@@ -3,3 +3,72 @@ pub const MINIMUM_SATELLITE_SYSTEMS: usize = 0;
 
 /// Maximum number of satellite systems to generate.
 pub const MAXIMUM_SATELLITE_SYSTEMS: usize = 12;
+
+/// The mass of the minimum-mass solar nebula, in Msol.
+///
+/// This is the least mass of gas and dust that could have built the planets
+/// we have (Hayashi, 1981), and it's the yardstick for other disks.
+pub const MINIMUM_MASS_SOLAR_NEBULA_MASS: f64 = 0.013;
+
+/// The surface density of solids in the minimum-mass solar nebula at 1 AU,
+/// inside the frost line, in g/cm².
+pub const MINIMUM_MASS_SOLAR_NEBULA_SOLID_SURFACE_DENSITY: f64 = 7.1;
+
+/// Beyond the frost line, ices freeze out and add to the solids.
+pub const FROST_LINE_SOLID_ENHANCEMENT: f64 = 4.2;
+
+/// The typical mass of a protoplanetary disk, as a fraction of the mass of
+/// its star.
+pub const DISK_MASS_FRACTION: f64 = 0.01;
+
+/// Disk masses vary a lot from star to star; this is the standard deviation
+/// of the natural log of the disk mass.
+pub const DISK_MASS_STANDARD_DEVIATION: f64 = 0.8;
+
+/// The spacing of oligarchs when they've swept up their feeding zones,
+/// measured in mutual Hill radii (Kokubo & Ida, 2002).
+pub const ISOLATION_SPACING: f64 = 10.0;
+
+/// Inside the frost line, oligarchs go on to collide and merge, leaving
+/// fewer, bigger planets, further apart; measured in mutual Hill radii.
+pub const MINIMUM_FINAL_SPACING: f64 = 20.0;
+
+/// The widest final spacing inside the frost line, in mutual Hill radii.
+pub const MAXIMUM_FINAL_SPACING: f64 = 40.0;
+
+/// A core at least this massive, in Mearth, pulls in gas from the disk and
+/// runs away into a gas giant.
+pub const CRITICAL_CORE_MASS: f64 = 10.0;
+
+/// The share of the remaining gas in the disk that each gas giant captures.
+pub const GAS_GIANT_DISK_FRACTION: f64 = 0.1;
+
+/// Beyond this multiple of the frost line, cores grow too slowly to form
+/// before the disk disperses.
+pub const MAXIMUM_FORMATION_DISTANCE_FACTOR: f64 = 10.0;
+
+/// Neighbors closer together than this, in mutual Hill radii, tend to go
+/// unstable over billions of years (Chambers et al., 1996).
+pub const MINIMUM_STABLE_SEPARATION: f64 = 8.0;
+
+/// The mean-motion resonances (as period ratios) that migrating planets get
+/// caught in, innermost first.
+pub const RESONANCE_RATIOS: [f64; 2] = [1.5, 2.0];
+
+/// The probability that the inner planets of an M dwarf form a resonant
+/// chain, like TRAPPIST-1's.
+pub const RESONANCE_CHAIN_PROBABILITY: f64 = 0.2;
+
+/// The probability that a system's innermost gas giant migrates inward.
+pub const MIGRATION_PROBABILITY: f64 = 0.15;
+
+/// Migrating giants stall at the inner edge of the disk, where the star's
+/// magnetic field has cleared out the gas; a multiple of the innermost orbit.
+pub const MIGRATION_INNER_EDGE_FACTOR: f64 = 4.0;
+
+/// The probability that a planet in a migrating giant's way is flung outward
+/// rather than lost.
+pub const SCATTERING_PROBABILITY: f64 = 0.3;
+
+/// A giant inside this distance, in AU, is a hot Jupiter.
+pub const HOT_JUPITER_MAXIMUM_SEMI_MAJOR_AXIS: f64 = 0.1;
@@ -2,10 +2,24 @@ use rand::prelude::*;
 use std::default::Default;
 
 use crate::astronomy::host_star::HostStar;
+use crate::astronomy::planet::constraints::Constraints as PlanetConstraints;
+use crate::astronomy::planet::math::mass::{earth_mass_to_jupiter_mass, EARTH_MASS_PER_SOLAR_MASS};
+use crate::astronomy::planet::math::occurrence::get_rocky_planet_probability;
+use crate::astronomy::planet::Planet;
 use crate::astronomy::satellite_system::constraints::Constraints as SatelliteSystemConstraints;
+use crate::astronomy::satellite_system::SatelliteSystem;
 use crate::astronomy::satellite_systems::constants::*;
 use crate::astronomy::satellite_systems::error::Error;
+use crate::astronomy::satellite_systems::math::dynamics::get_resonance_chain;
+use crate::astronomy::satellite_systems::math::formation::*;
+use crate::astronomy::satellite_systems::math::migration::*;
+use crate::astronomy::satellite_systems::migration::Migration;
 use crate::astronomy::satellite_systems::SatelliteSystems;
+use crate::astronomy::star::math::spectral_class::spectral_class_to_mass_range;
+use crate::astronomy::star::spectral_type::SpectralType;
+use crate::astronomy::terrestrial_planet::constants::{
+  MAXIMUM_MASS as TERRESTRIAL_PLANET_MAXIMUM_MASS, MINIMUM_MASS as TERRESTRIAL_PLANET_MINIMUM_MASS,
+};
 
 /// Constraints for creating satellite systems.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -20,6 +34,18 @@ pub struct Constraints {
   pub generate_primary_gas_giant: bool,
   /// Generate a habitable planet.
   pub generate_habitable: bool,
+  /// Place planets, and decide their masses, by modeling the protoplanetary
+  /// disk they formed from.
+  pub use_formation_model: bool,
+  /// The probability that the inner planets form a chain of mean-motion
+  /// resonances.  This is zero by default; if set to None, M dwarfs get them
+  /// with the usual probability and other stars don't.
+  pub resonance_chain_probability: Option<f64>,
+  /// Break up pairs of planets that are too close together to be stable.  Off
+  /// by default.
+  pub repair_unstable_orbits: bool,
+  /// The probability that the innermost gas giant migrates inward.
+  pub migration_probability: Option<f64>,
 }
 
 impl Constraints {
@@ -49,38 +75,181 @@ impl Constraints {
     trace_var!(minimum_count);
     let maximum_count = self.maximum_count.unwrap_or(MAXIMUM_SATELLITE_SYSTEMS);
     trace_var!(maximum_count);
-    let satellite_system_constraints = self
-      .satellite_system_constraints
-      .unwrap_or(SatelliteSystemConstraints::default());
-    trace_var!(satellite_system_constraints);
     let mut satellite_systems = Vec::new();
-    let orbits = self.generate_orbits(rng, host_star)?;
-    for orbit in orbits.into_iter() {
-      let satellite_system = satellite_system_constraints.generate(rng, host_star, orbit)?;
+    let mut orbits = match self.use_formation_model {
+      true => self.generate_orbits_from_disk(rng, host_star)?,
+      false => self.generate_orbits(rng, host_star)?,
+    };
+    let resonance_chain_probability = self.resonance_chain_probability.unwrap_or(
+      match host_star.get_stellar_mass() < spectral_class_to_mass_range(SpectralType::M).end {
+        true => RESONANCE_CHAIN_PROBABILITY,
+        false => 0.0,
+      },
+    );
+    trace_var!(resonance_chain_probability);
+    if rng.gen_range(0.0..1.0) < resonance_chain_probability {
+      orbits = self.get_resonance_chain_orbits(host_star, &orbits);
+    }
+    for (orbit, mass) in orbits.into_iter() {
+      let satellite_system = self.generate_satellite_system(rng, host_star, orbit, mass)?;
       trace_var!(satellite_system);
       satellite_systems.push(satellite_system);
     }
     trace_var!(satellite_systems);
-    let result = SatelliteSystems { satellite_systems };
+    let mut result = SatelliteSystems { satellite_systems };
+    if self.repair_unstable_orbits {
+      result.stabilize(host_star);
+    }
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Generate a satellite system in a given orbit, in AU, with the planet's
+  /// mass (in Mearth) if we know it.
+  #[named]
+  pub fn generate_satellite_system<R: Rng + ?Sized>(
+    &self,
+    rng: &mut R,
+    host_star: &HostStar,
+    orbit: f64,
+    mass: Option<f64>,
+  ) -> Result<SatelliteSystem, Error> {
+    trace_enter!();
+    trace_var!(orbit);
+    trace_var!(mass);
+    let satellite_system_constraints = self.satellite_system_constraints.unwrap_or_default();
+    trace_var!(satellite_system_constraints);
+    let planet_constraints = satellite_system_constraints.planet_constraints.unwrap_or_default();
+    trace_var!(planet_constraints);
+    let constraints = SatelliteSystemConstraints {
+      planet_constraints: Some(PlanetConstraints {
+        mass: mass.or(planet_constraints.mass),
+        ..planet_constraints
+      }),
+      ..satellite_system_constraints
+    };
+    let result = constraints.generate(rng, host_star, orbit)?;
     trace_var!(result);
     trace_exit!();
     Ok(result)
   }
 
-  /// Generate orbits.
+  /// Maybe move the innermost gas giant inward, as happens to giants that
+  /// form while there's still plenty of gas in the disk.
+  ///
+  /// Terrestrial planets in its way are lost, or flung outward beyond where
+  /// the giant started.  If we want a habitable planet, the giant stops
+  /// short of the habitable zone.
+  ///
+  /// Brown dwarfs stay put; they collapse like stars rather than growing in
+  /// the disk, so there's no gas to carry them inward.
   #[named]
-  pub fn generate_orbits<R: Rng + ?Sized>(&self, rng: &mut R, host_star: &HostStar) -> Result<Vec<f64>, Error> {
+  pub fn migrate<R: Rng + ?Sized>(
+    &self,
+    rng: &mut R,
+    host_star: &HostStar,
+    satellite_systems: &mut SatelliteSystems,
+  ) -> Result<Vec<Migration>, Error> {
+    trace_enter!();
+    let migration_probability = self.migration_probability.unwrap_or(MIGRATION_PROBABILITY);
+    trace_var!(migration_probability);
+    let mut result = Vec::new();
+    let index = satellite_systems
+      .satellite_systems
+      .iter()
+      .position(|satellite_system| matches!(satellite_system.planet, Planet::GasGiantPlanet(_)));
+    trace_var!(index);
+    if let Some(index) = index.filter(|_| rng.gen_range(0.0..1.0) < migration_probability) {
+      let giant = satellite_systems.satellite_systems.remove(index);
+      let mass = giant.planet.get_mass_in_earth_masses();
+      trace_var!(mass);
+      let initial_semi_major_axis = giant.planet.get_semi_major_axis();
+      trace_var!(initial_semi_major_axis);
+      let satellite_zone = host_star.get_satellite_zone();
+      trace_var!(satellite_zone);
+      let inner_edge = match self.generate_habitable {
+        true => host_star.get_habitable_zone().1,
+        false => MIGRATION_INNER_EDGE_FACTOR * satellite_zone.0,
+      };
+      trace_var!(inner_edge);
+      let final_semi_major_axis = get_random_migration_distance(rng, initial_semi_major_axis, inner_edge);
+      trace_var!(final_semi_major_axis);
+      let (crossed, mut remaining): (Vec<_>, Vec<_>) =
+        satellite_systems
+          .satellite_systems
+          .drain(..)
+          .partition(|satellite_system| {
+            let orbit = satellite_system.planet.get_semi_major_axis();
+            matches!(satellite_system.planet, Planet::TerrestrialPlanet(_))
+              && orbit > final_semi_major_axis
+              && orbit < initial_semi_major_axis
+          });
+      trace_var!(crossed);
+      let mut cleared_count = 0;
+      let mut scattered_count = 0;
+      for satellite_system in crossed.into_iter() {
+        let orbit = get_random_scattered_distance(rng, initial_semi_major_axis);
+        trace_var!(orbit);
+        if rng.gen_range(0.0..1.0) < SCATTERING_PROBABILITY && orbit < satellite_zone.1 {
+          let mass = satellite_system.planet.get_mass_in_earth_masses();
+          remaining.push(self.generate_satellite_system(rng, host_star, orbit, Some(mass))?);
+          scattered_count += 1;
+        } else {
+          cleared_count += 1;
+        }
+      }
+      remaining.push(self.generate_satellite_system(rng, host_star, final_semi_major_axis, Some(mass))?);
+      remaining.sort_by(|a, b| {
+        a.planet
+          .get_semi_major_axis()
+          .partial_cmp(&b.planet.get_semi_major_axis())
+          .unwrap()
+      });
+      satellite_systems.satellite_systems = remaining;
+      if self.repair_unstable_orbits {
+        satellite_systems.stabilize(host_star);
+      }
+      result.push(Migration {
+        mass: earth_mass_to_jupiter_mass(mass),
+        initial_semi_major_axis,
+        final_semi_major_axis,
+        cleared_count,
+        scattered_count,
+      });
+    }
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Generate orbits, and the masses of the planets in them (in Mearth) where
+  /// we need to pin them down.
+  ///
+  /// Only the primary gas giant gets a mass, so that it really is a gas
+  /// giant; the rest are left up to the planet constraints.
+  #[named]
+  pub fn generate_orbits<R: Rng + ?Sized>(
+    &self,
+    rng: &mut R,
+    host_star: &HostStar,
+  ) -> Result<Vec<(f64, Option<f64>)>, Error> {
     trace_enter!();
     trace_var!(host_star);
     let mut result = Vec::new();
     if self.generate_primary_gas_giant {
       let orbit = rng.gen_range(1.0..1.25) + host_star.get_frost_line();
-      result.push(orbit);
+      trace_var!(orbit);
+      let gas_mass = get_random_disk_mass(rng, host_star.get_stellar_mass()) * EARTH_MASS_PER_SOLAR_MASS;
+      trace_var!(gas_mass);
+      let mass = CRITICAL_CORE_MASS + get_random_gas_giant_envelope_mass(rng, gas_mass);
+      trace_var!(mass);
+      result.push((orbit, Some(mass)));
     }
     if self.generate_habitable {
       let habitable_zone = host_star.get_habitable_zone();
       let orbit = rng.gen_range(habitable_zone.0..habitable_zone.1);
-      result.push(orbit);
+      result.push((orbit, None));
     }
     let satellite_zone = host_star.get_satellite_zone();
     trace_var!(satellite_zone);
@@ -94,6 +263,10 @@ impl Constraints {
     trace_var!(distance_limit);
     let growth_factor = 0.3;
     trace_var!(growth_factor);
+    let frost_line = host_star.get_frost_line();
+    trace_var!(frost_line);
+    let rocky_planet_probability = get_rocky_planet_probability(host_star.get_metallicity());
+    trace_var!(rocky_planet_probability);
     let mut orbital_distance = minimum;
     let mut index = 0;
     loop {
@@ -101,10 +274,13 @@ impl Constraints {
       let max_unwrapped = 1.25 * orbital_distance;
       if !result
         .iter()
-        .any(|&orbit| orbit > min_unwrapped && orbit < max_unwrapped)
+        .any(|&(orbit, _)| orbit > min_unwrapped && orbit < max_unwrapped)
       {
         let orbit = rng.gen_range(min_unwrapped..max_unwrapped);
-        result.push(orbit);
+        // Metal-poor systems leave some of the inner orbits empty.
+        if orbit >= frost_line || rng.gen_range(0.0..1.0) < rocky_planet_probability {
+          result.push((orbit, None));
+        }
       }
       orbital_distance = minimum + growth_factor * (2.0_f64).powf(index as f64);
       index += 1;
@@ -112,11 +288,146 @@ impl Constraints {
         break;
       }
     }
-    result.sort_by(|a, b| a.partial_cmp(&b).unwrap());
+    result.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
     trace_var!(result);
     trace_exit!();
     Ok(result)
   }
+
+  /// Generate orbits, and the masses of the planets in them (in Mearth), from
+  /// a model of the protoplanetary disk.
+  ///
+  /// Working outward, each protoplanet sweeps up the solids in its feeding
+  /// zone.  Beyond the frost line, cores that grow big enough pull in gas and
+  /// become gas giants, and the disk has that much less gas for the next one.
+  /// The rest merge with their neighbors, leaving planets some number of
+  /// mutual Hill radii apart.
+  ///
+  /// A mass of None leaves it up to the planet constraints, as for the
+  /// habitable planet.
+  #[named]
+  pub fn generate_orbits_from_disk<R: Rng + ?Sized>(
+    &self,
+    rng: &mut R,
+    host_star: &HostStar,
+  ) -> Result<Vec<(f64, Option<f64>)>, Error> {
+    trace_enter!();
+    trace_var!(host_star);
+    let stellar_mass = host_star.get_stellar_mass();
+    trace_var!(stellar_mass);
+    let metallicity = host_star.get_metallicity();
+    trace_var!(metallicity);
+    let frost_line = host_star.get_frost_line();
+    trace_var!(frost_line);
+    let satellite_zone = host_star.get_satellite_zone();
+    trace_var!(satellite_zone);
+    let disk_mass = get_random_disk_mass(rng, stellar_mass);
+    trace_var!(disk_mass);
+    let mut gas_mass = disk_mass * EARTH_MASS_PER_SOLAR_MASS;
+    let innermost_orbit = 40.0 * satellite_zone.0;
+    trace_var!(innermost_orbit);
+    let outermost_orbit = satellite_zone.1.min(MAXIMUM_FORMATION_DISTANCE_FACTOR * frost_line);
+    trace_var!(outermost_orbit);
+    let mut result = Vec::new();
+    let mut distance = innermost_orbit;
+    while distance < outermost_orbit {
+      let surface_density = get_solid_surface_density(disk_mass, metallicity, distance, frost_line);
+      trace_var!(surface_density);
+      let core_mass = get_isolation_mass(surface_density, distance, stellar_mass, ISOLATION_SPACING);
+      trace_var!(core_mass);
+      let spacing = rng.gen_range(MINIMUM_FINAL_SPACING..MAXIMUM_FINAL_SPACING);
+      trace_var!(spacing);
+      let mass = match distance >= frost_line && core_mass >= CRITICAL_CORE_MASS {
+        true => {
+          let envelope_mass = get_random_gas_giant_envelope_mass(rng, gas_mass);
+          gas_mass -= envelope_mass;
+          core_mass + envelope_mass
+        },
+        false => get_isolation_mass(surface_density, distance, stellar_mass, spacing),
+      };
+      trace_var!(mass);
+      // Anything smaller is just part of an asteroid belt.
+      if mass >= TERRESTRIAL_PLANET_MINIMUM_MASS {
+        result.push((distance, Some(mass)));
+      }
+      distance += spacing * get_mutual_hill_radius(mass, mass, distance, distance, stellar_mass);
+    }
+    if self.generate_habitable {
+      let habitable_zone = host_star.get_habitable_zone();
+      let orbit = rng.gen_range(habitable_zone.0..habitable_zone.1);
+      trace_var!(orbit);
+      // Clear out some room for it.
+      result.retain(|&(other, _)| other < 0.8 * orbit || other > 1.25 * orbit);
+      result.push((orbit, None));
+    }
+    let has_gas_giant = result
+      .iter()
+      .any(|&(_, mass)| mass.is_some_and(|mass| mass > TERRESTRIAL_PLANET_MAXIMUM_MASS));
+    trace_var!(has_gas_giant);
+    if self.generate_primary_gas_giant && !has_gas_giant {
+      // The disk had the gas, but the core didn't quite get there; give it a
+      // hand, or make one if there's nothing out there at all.
+      let mass = CRITICAL_CORE_MASS + get_random_gas_giant_envelope_mass(rng, gas_mass);
+      trace_var!(mass);
+      let orbit = match result.iter().position(|&(orbit, _)| orbit >= frost_line) {
+        Some(index) => result.remove(index).0,
+        None => rng.gen_range(1.0..1.25) + frost_line,
+      };
+      trace_var!(orbit);
+      // It clears out its neighborhood as it grows.
+      result.retain(|&(other, other_mass)| {
+        let mutual_hill_radius = get_mutual_hill_radius(mass, other_mass.unwrap_or(1.0), orbit, other, stellar_mass);
+        (other - orbit).abs() > ISOLATION_SPACING * mutual_hill_radius
+      });
+      result.push((orbit, Some(mass)));
+    }
+    result.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Snap the orbits inside the frost line into a chain of mean-motion
+  /// resonances, as migration through the disk does in compact systems.
+  ///
+  /// The habitable planet, if we want one, stays where it is; the others
+  /// line up around it.  Anything pushed inside the satellite zone is lost.
+  #[named]
+  pub fn get_resonance_chain_orbits(
+    &self,
+    host_star: &HostStar,
+    orbits: &[(f64, Option<f64>)],
+  ) -> Vec<(f64, Option<f64>)> {
+    trace_enter!();
+    trace_var!(orbits);
+    let frost_line = host_star.get_frost_line();
+    trace_var!(frost_line);
+    let habitable_zone = host_star.get_habitable_zone();
+    trace_var!(habitable_zone);
+    let (inner, outer): (Vec<_>, Vec<_>) = orbits.iter().partition(|&&(orbit, _)| orbit < frost_line);
+    let anchor = match self.generate_habitable {
+      true => inner
+        .iter()
+        .position(|&(orbit, _)| orbit > habitable_zone.0 && orbit < habitable_zone.1)
+        .unwrap_or(0),
+      false => 0,
+    };
+    trace_var!(anchor);
+    let chain = get_resonance_chain(&inner.iter().map(|&(orbit, _)| orbit).collect::<Vec<_>>(), anchor);
+    trace_var!(chain);
+    let innermost_orbit = host_star.get_satellite_zone().0;
+    trace_var!(innermost_orbit);
+    let mut result: Vec<_> = chain
+      .into_iter()
+      .zip(inner.into_iter().map(|(_, mass)| mass))
+      .filter(|&(orbit, _)| orbit > innermost_orbit)
+      .chain(outer)
+      .collect();
+    result.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
 }
 
 impl Default for Constraints {
@@ -127,12 +438,20 @@ impl Default for Constraints {
     let satellite_system_constraints = None;
     let generate_primary_gas_giant = false;
     let generate_habitable = false;
+    let use_formation_model = false;
+    let resonance_chain_probability = Some(0.0);
+    let repair_unstable_orbits = false;
+    let migration_probability = None;
     Self {
       minimum_count,
       maximum_count,
       satellite_system_constraints,
       generate_primary_gas_giant,
       generate_habitable,
+      use_formation_model,
+      resonance_chain_probability,
+      repair_unstable_orbits,
+      migration_probability,
     }
   }
 }
@@ -175,6 +494,161 @@ pub mod test {
     let satellite_systems = &Constraints::habitable().generate(&mut rng, host_star)?;
     trace_var!(satellite_systems);
     print_var!(satellite_systems);
+    // The primary gas giant is there every time, not just when the planet
+    // constraints happen to roll one.
+    for _ in 0..50 {
+      let satellite_systems = Constraints::habitable().generate(&mut rng, host_star)?;
+      assert!(satellite_systems
+        .satellite_systems
+        .iter()
+        .any(|satellite_system| matches!(satellite_system.planet, Planet::GasGiantPlanet(_))));
+    }
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_generate_orbits_from_disk() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let host_star = &HostStarConstraints::habitable().generate(&mut rng)?;
+    trace_var!(host_star);
+    let constraints = Constraints {
+      use_formation_model: true,
+      ..Constraints::habitable()
+    };
+    let orbits = constraints.generate_orbits_from_disk(&mut rng, host_star)?;
+    print_var!(orbits);
+    let habitable_zone = host_star.get_habitable_zone();
+    assert!(orbits
+      .iter()
+      .any(|&(orbit, mass)| mass.is_none() && orbit > habitable_zone.0 && orbit < habitable_zone.1));
+    assert!(orbits
+      .iter()
+      .any(|&(_, mass)| mass.is_some_and(|mass| mass > TERRESTRIAL_PLANET_MAXIMUM_MASS)));
+    assert!(orbits.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    let satellite_systems = &constraints.generate(&mut rng, host_star)?;
+    print_var!(satellite_systems);
+    assert_eq!(orbits.is_empty(), satellite_systems.satellite_systems.is_empty());
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_resonance_chain() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let host_star = &HostStarConstraints::default().generate(&mut rng)?;
+    trace_var!(host_star);
+    let constraints = Constraints {
+      resonance_chain_probability: Some(1.0),
+      repair_unstable_orbits: true,
+      ..Constraints::default()
+    };
+    let orbits = constraints.generate_orbits(&mut rng, host_star)?;
+    let chain = constraints.get_resonance_chain_orbits(host_star, &orbits);
+    print_var!(chain);
+    let frost_line = host_star.get_frost_line();
+    for pair in chain.windows(2).filter(|pair| pair[1].0 < frost_line) {
+      let period_ratio = (pair[1].0 / pair[0].0).powf(1.5);
+      assert!(RESONANCE_RATIOS
+        .iter()
+        .any(|&ratio| (ratio - period_ratio).abs() < 1.0E-6));
+    }
+    let satellite_systems = &constraints.generate(&mut rng, host_star)?;
+    print_var!(satellite_systems);
+    assert!(satellite_systems.is_stable(host_star));
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_migrate() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let host_star = &HostStarConstraints::default().generate(&mut rng)?;
+    trace_var!(host_star);
+    // No giants other than the primary, so we know which one moves.
+    let planet_constraints = PlanetConstraints {
+      brown_dwarf_probability: Some(0.0),
+      gas_giant_probability: Some(0.0),
+      ..PlanetConstraints::default()
+    };
+    let satellite_system_constraints = SatelliteSystemConstraints {
+      planet_constraints: Some(planet_constraints),
+      ..SatelliteSystemConstraints::default()
+    };
+    let constraints = Constraints {
+      satellite_system_constraints: Some(satellite_system_constraints),
+      generate_primary_gas_giant: true,
+      migration_probability: Some(1.0),
+      ..Constraints::default()
+    };
+    let mut satellite_systems = constraints.generate(&mut rng, host_star)?;
+    let migrations = constraints.migrate(&mut rng, host_star, &mut satellite_systems)?;
+    print_var!(migrations);
+    print_var!(satellite_systems);
+    assert_eq!(migrations.len(), 1);
+    let migration = migrations[0];
+    assert!(migration.final_semi_major_axis <= migration.initial_semi_major_axis);
+    assert!(satellite_systems
+      .satellite_systems
+      .iter()
+      .any(|satellite_system| satellite_system.planet.get_semi_major_axis() == migration.final_semi_major_axis));
+    assert!(!satellite_systems.satellite_systems.iter().any(|satellite_system| {
+      let orbit = satellite_system.planet.get_semi_major_axis();
+      matches!(satellite_system.planet, Planet::TerrestrialPlanet(_))
+        && orbit > migration.final_semi_major_axis
+        && orbit < migration.initial_semi_major_axis
+    }));
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_migrate_brown_dwarf() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let host_star = &HostStarConstraints::default().generate(&mut rng)?;
+    trace_var!(host_star);
+    let planet_constraints = PlanetConstraints {
+      brown_dwarf_probability: Some(1.0),
+      ..PlanetConstraints::default()
+    };
+    let satellite_system_constraints = SatelliteSystemConstraints {
+      planet_constraints: Some(planet_constraints),
+      ..SatelliteSystemConstraints::default()
+    };
+    let constraints = Constraints {
+      satellite_system_constraints: Some(satellite_system_constraints),
+      migration_probability: Some(1.0),
+      ..Constraints::default()
+    };
+    let orbit = 2.0 * host_star.get_frost_line();
+    let satellite_system = constraints.generate_satellite_system(&mut rng, host_star, orbit, None)?;
+    assert!(matches!(satellite_system.planet, Planet::BrownDwarf(_)));
+    let mut satellite_systems = SatelliteSystems {
+      satellite_systems: vec![satellite_system],
+    };
+    let migrations = constraints.migrate(&mut rng, host_star, &mut satellite_systems)?;
+    print_var!(migrations);
+    assert!(migrations.is_empty());
+    assert_eq!(
+      satellite_systems.satellite_systems[0].planet.get_semi_major_axis(),
+      orbit
+    );
     trace_exit!();
     Ok(())
   }
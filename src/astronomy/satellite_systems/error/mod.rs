@@ -10,6 +10,8 @@ pub enum Error {
   SatelliteSystemError(SatelliteSystemError),
   /// No habitable systems found.
   NoHabitableSatelliteSystemsFound,
+  /// Some neighbors are too close together to stay that way.
+  UnstableSatelliteSystems,
 }
 
 honeyholt_define_brief!(Error, |error: &Error| {
@@ -24,6 +26,7 @@ honeyholt_define_brief!(Error, |error: &Error| {
       honeyholt_brief!(satellite_system_error)
     ),
     NoHabitableSatelliteSystemsFound => "no habitable systems could be found".to_string(),
+    UnstableSatelliteSystems => "some planets are too close together to be stable".to_string(),
   }
 });
 
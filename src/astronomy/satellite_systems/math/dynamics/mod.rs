@@ -0,0 +1,106 @@
+use crate::astronomy::satellite_systems::constants::*;
+use crate::astronomy::satellite_systems::math::formation::get_mutual_hill_radius;
+
+/// Get the separation of two bodies (masses in Mearth, distances in AU) from
+/// a star of a given mass in Msol, measured in mutual Hill radii.
+#[named]
+pub fn get_mutual_hill_separation(mass1: f64, mass2: f64, distance1: f64, distance2: f64, stellar_mass: f64) -> f64 {
+  trace_enter!();
+  trace_var!(mass1);
+  trace_var!(mass2);
+  trace_var!(distance1);
+  trace_var!(distance2);
+  trace_var!(stellar_mass);
+  let mutual_hill_radius = get_mutual_hill_radius(mass1, mass2, distance1, distance2, stellar_mass);
+  trace_var!(mutual_hill_radius);
+  let result = (distance2 - distance1).abs() / mutual_hill_radius;
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the orbit, in AU, whose period is a given multiple of the period of an
+/// orbit at a given distance in AU.
+#[named]
+pub fn get_resonant_orbit(distance: f64, period_ratio: f64) -> f64 {
+  trace_enter!();
+  trace_var!(distance);
+  trace_var!(period_ratio);
+  let result = distance * period_ratio.powf(2.0 / 3.0);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the mean-motion resonance (as a period ratio) nearest to the actual
+/// period ratio of two orbits, in AU.
+#[named]
+pub fn get_nearest_resonance(inner_distance: f64, outer_distance: f64) -> f64 {
+  trace_enter!();
+  trace_var!(inner_distance);
+  trace_var!(outer_distance);
+  let period_ratio = (outer_distance / inner_distance).powf(1.5);
+  trace_var!(period_ratio);
+  let result = RESONANCE_RATIOS
+    .iter()
+    .copied()
+    .min_by(|a, b| (a - period_ratio).abs().partial_cmp(&(b - period_ratio).abs()).unwrap())
+    .unwrap();
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Snap a sorted list of orbits, in AU, into a chain of mean-motion
+/// resonances.
+///
+/// The orbit at the anchor index stays put, and each neighbor moves into the
+/// resonance nearest to where it was, working outward and inward from there.
+#[named]
+pub fn get_resonance_chain(orbits: &[f64], anchor: usize) -> Vec<f64> {
+  trace_enter!();
+  trace_var!(orbits);
+  trace_var!(anchor);
+  let mut result = orbits.to_vec();
+  for index in (anchor + 1)..orbits.len() {
+    let period_ratio = get_nearest_resonance(orbits[index - 1], orbits[index]);
+    result[index] = get_resonant_orbit(result[index - 1], period_ratio);
+  }
+  for index in (0..anchor.min(orbits.len())).rev() {
+    let period_ratio = get_nearest_resonance(orbits[index], orbits[index + 1]);
+    result[index] = get_resonant_orbit(result[index + 1], 1.0 / period_ratio);
+  }
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_dynamics() {
+    init();
+    trace_enter!();
+    // Earth and Venus are about 26 mutual Hill radii apart.
+    let separation = get_mutual_hill_separation(0.815, 1.0, 0.723, 1.0, 1.0);
+    print_var!(separation);
+    assert_approx_eq!(separation, 26.0, 1.0);
+    assert!(separation > MINIMUM_STABLE_SEPARATION);
+    // Neptune and Pluto are in a 3:2 resonance.
+    assert_approx_eq!(get_resonant_orbit(30.07, 1.5), 39.4, 0.1);
+    assert_approx_eq!(get_nearest_resonance(30.07, 39.48), 1.5);
+    assert_approx_eq!(get_nearest_resonance(1.0, 1.55), 2.0);
+    let chain = get_resonance_chain(&[0.02, 0.026, 0.04, 0.05], 1);
+    print_var!(chain);
+    assert_approx_eq!(chain[1], 0.026);
+    assert_approx_eq!((chain[1] / chain[0]).powf(1.5), 1.5);
+    assert_approx_eq!((chain[2] / chain[1]).powf(1.5), 2.0);
+    assert_approx_eq!((chain[3] / chain[2]).powf(1.5), 1.5);
+    trace_exit!();
+  }
+}
@@ -0,0 +1,152 @@
+use rand::prelude::*;
+use rand_distr::{Distribution, LogNormal};
+
+use crate::astronomy::planet::math::mass::EARTH_MASS_PER_SOLAR_MASS;
+use crate::astronomy::satellite_systems::constants::*;
+
+/// Grams per Mearth.
+const GRAMS_PER_EARTH_MASS: f64 = 5.972E27;
+
+/// Centimeters per AU.
+const CM_PER_AU: f64 = 1.496E13;
+
+/// Get a random protoplanetary disk mass, in Msol, for a star of a given
+/// mass in Msol.
+#[named]
+pub fn get_random_disk_mass<R: Rng + ?Sized>(rng: &mut R, stellar_mass: f64) -> f64 {
+  trace_enter!();
+  trace_var!(stellar_mass);
+  let log_normal = LogNormal::new((DISK_MASS_FRACTION * stellar_mass).ln(), DISK_MASS_STANDARD_DEVIATION).unwrap();
+  let result = log_normal.sample(rng);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the surface density of solids, in g/cm², at a distance in AU.
+///
+/// This is the minimum-mass solar nebula, scaled by the disk mass (in Msol)
+/// and by metallicity, which sets how much of the disk is dust.  Beyond the
+/// frost line (in AU), ices add to the solids.
+#[named]
+pub fn get_solid_surface_density(disk_mass: f64, metallicity: f64, distance: f64, frost_line: f64) -> f64 {
+  trace_enter!();
+  trace_var!(disk_mass);
+  trace_var!(metallicity);
+  trace_var!(distance);
+  trace_var!(frost_line);
+  let enhancement = match distance >= frost_line {
+    true => FROST_LINE_SOLID_ENHANCEMENT,
+    false => 1.0,
+  };
+  trace_var!(enhancement);
+  let result = MINIMUM_MASS_SOLAR_NEBULA_SOLID_SURFACE_DENSITY
+    * (disk_mass / MINIMUM_MASS_SOLAR_NEBULA_MASS)
+    * 10.0_f64.powf(metallicity)
+    * enhancement
+    * distance.powf(-1.5);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the isolation mass, in Mearth, of a protoplanet at a distance in AU
+/// around a star of a given mass in Msol.
+///
+/// This is the mass a protoplanet reaches when it has swept up all of the
+/// solids (surface density in g/cm²) in its feeding zone, the width of which
+/// is some number of mutual Hill radii (Kokubo & Ida, 2002).
+#[named]
+pub fn get_isolation_mass(surface_density: f64, distance: f64, stellar_mass: f64, spacing: f64) -> f64 {
+  trace_enter!();
+  trace_var!(surface_density);
+  trace_var!(distance);
+  trace_var!(stellar_mass);
+  trace_var!(spacing);
+  let distance = distance * CM_PER_AU;
+  let stellar_mass = stellar_mass * EARTH_MASS_PER_SOLAR_MASS * GRAMS_PER_EARTH_MASS;
+  // M = 2π a Δa Σ, where Δa = b (2M / 3M*)^⅓ a, solved for M.
+  let result = (2.0 * std::f64::consts::PI * spacing * distance.powf(2.0) * surface_density).powf(1.5)
+    * (2.0 / (3.0 * stellar_mass)).sqrt()
+    / GRAMS_PER_EARTH_MASS;
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the mutual Hill radius, in AU, of two bodies (masses in Mearth) at
+/// two distances (in AU) from a star of a given mass in Msol.
+///
+/// Neighbors much closer together than ten or so of these won't stay that
+/// way for long.
+#[named]
+pub fn get_mutual_hill_radius(mass1: f64, mass2: f64, distance1: f64, distance2: f64, stellar_mass: f64) -> f64 {
+  trace_enter!();
+  trace_var!(mass1);
+  trace_var!(mass2);
+  trace_var!(distance1);
+  trace_var!(distance2);
+  trace_var!(stellar_mass);
+  let result = ((mass1 + mass2) / (3.0 * stellar_mass * EARTH_MASS_PER_SOLAR_MASS)).powf(1.0 / 3.0)
+    * (distance1 + distance2)
+    / 2.0;
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get a random mass of gas, in Mearth, captured by a gas giant from a disk
+/// with a given mass of gas remaining, in Mearth.
+#[named]
+pub fn get_random_gas_giant_envelope_mass<R: Rng + ?Sized>(rng: &mut R, gas_mass: f64) -> f64 {
+  trace_enter!();
+  trace_var!(gas_mass);
+  let log_normal = LogNormal::new(0.0, 0.5).unwrap();
+  let result = (GAS_GIANT_DISK_FRACTION * gas_mass * log_normal.sample(rng)).min(gas_mass);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_formation() {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let disk_mass = get_random_disk_mass(&mut rng, 1.0);
+    print_var!(disk_mass);
+    assert!(disk_mass > 0.0);
+    // The minimum-mass solar nebula.
+    let inner_density = get_solid_surface_density(MINIMUM_MASS_SOLAR_NEBULA_MASS, 0.0, 1.0, 2.7);
+    assert_approx_eq!(inner_density, MINIMUM_MASS_SOLAR_NEBULA_SOLID_SURFACE_DENSITY);
+    let outer_density = get_solid_surface_density(MINIMUM_MASS_SOLAR_NEBULA_MASS, 0.0, 5.2, 2.7);
+    print_var!(outer_density);
+    assert!(outer_density > 2.0 && outer_density < 3.0);
+    assert!(get_solid_surface_density(MINIMUM_MASS_SOLAR_NEBULA_MASS, 0.3, 1.0, 2.7) > 1.9 * inner_density);
+    // Oligarchs at 1 AU are about a tenth of an Earth mass (Kokubo & Ida, 2002).
+    let isolation_mass = get_isolation_mass(inner_density, 1.0, 1.0, ISOLATION_SPACING);
+    print_var!(isolation_mass);
+    assert_approx_eq!(isolation_mass, 0.1, 0.02);
+    // Jupiter's core would have had to form in a heavier disk than this.
+    let core_mass = get_isolation_mass(outer_density, 5.2, 1.0, ISOLATION_SPACING);
+    print_var!(core_mass);
+    assert!(core_mass > 1.0 && core_mass < CRITICAL_CORE_MASS);
+    // Earth and Venus are about 26 mutual Hill radii apart.
+    let mutual_hill_radius = get_mutual_hill_radius(1.0, 0.815, 1.0, 0.723, 1.0);
+    print_var!(mutual_hill_radius);
+    assert_approx_eq!((1.0 - 0.723) / mutual_hill_radius, 26.0, 1.0);
+    let envelope_mass = get_random_gas_giant_envelope_mass(&mut rng, 1_000.0);
+    assert!(envelope_mass > 0.0 && envelope_mass <= 1_000.0);
+    trace_exit!();
+  }
+}
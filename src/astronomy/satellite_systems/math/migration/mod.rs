@@ -0,0 +1,58 @@
+use rand::prelude::*;
+
+/// Get a random distance, in AU, at which a giant migrating inward from a
+/// given distance stops, somewhere beyond a given inner edge.
+///
+/// Giants can stall anywhere along the way, so this is uniform in the log of
+/// the distance; that puts about as many warm Jupiters at 0.1-1 AU as hot
+/// ones at 0.01-0.1 AU.
+#[named]
+pub fn get_random_migration_distance<R: Rng + ?Sized>(rng: &mut R, distance: f64, inner_edge: f64) -> f64 {
+  trace_enter!();
+  trace_var!(distance);
+  trace_var!(inner_edge);
+  let result = match inner_edge < distance {
+    true => rng.gen_range(inner_edge.ln()..distance.ln()).exp(),
+    false => distance,
+  };
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get a random distance, in AU, to which a giant migrating inward from a
+/// given distance flings a planet in its way.
+#[named]
+pub fn get_random_scattered_distance<R: Rng + ?Sized>(rng: &mut R, distance: f64) -> f64 {
+  trace_enter!();
+  trace_var!(distance);
+  let result = distance * rng.gen_range(1.5..3.0);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_migration() {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    for _ in 0..100 {
+      let distance = get_random_migration_distance(&mut rng, 5.2, 0.04);
+      assert!((0.04..5.2).contains(&distance));
+      assert!(get_random_scattered_distance(&mut rng, 5.2) > 5.2);
+    }
+    assert_approx_eq!(get_random_migration_distance(&mut rng, 0.03, 0.04), 0.03);
+    trace_exit!();
+  }
+}
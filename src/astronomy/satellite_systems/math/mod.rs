@@ -0,0 +1,3 @@
+pub mod dynamics;
+pub mod formation;
+pub mod migration;
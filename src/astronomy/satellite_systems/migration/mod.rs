@@ -0,0 +1,31 @@
+use crate::astronomy::satellite_systems::constants::HOT_JUPITER_MAXIMUM_SEMI_MAJOR_AXIS;
+
+/// The `Migration` type.
+///
+/// A record of a giant planet moving inward through the disk it formed in,
+/// and what happened to the planets in its way.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Migration {
+  /// The mass of the giant, in Mjupiter.
+  pub mass: f64,
+  /// Where it formed, in AU.
+  pub initial_semi_major_axis: f64,
+  /// Where it ended up, in AU.
+  pub final_semi_major_axis: f64,
+  /// The number of planets in its way that were lost.
+  pub cleared_count: usize,
+  /// The number of planets in its way that were flung outward.
+  pub scattered_count: usize,
+}
+
+impl Migration {
+  /// Indicate whether the giant ended up as a hot Jupiter.
+  #[named]
+  pub fn is_hot_jupiter(&self) -> bool {
+    trace_enter!();
+    let result = self.final_semi_major_axis < HOT_JUPITER_MAXIMUM_SEMI_MAJOR_AXIS;
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
@@ -1,9 +1,14 @@
+use crate::astronomy::host_star::HostStar;
 use crate::astronomy::satellite_system::SatelliteSystem;
+use crate::astronomy::satellite_systems::constants::MINIMUM_STABLE_SEPARATION;
+use crate::astronomy::satellite_systems::math::dynamics::get_mutual_hill_separation;
 
 pub mod constants;
 pub mod constraints;
 pub mod error;
 use error::Error;
+pub mod math;
+pub mod migration;
 
 /// The `SatelliteSystems` object wraps a vector of `SatelliteSystem` objects.
 #[derive(Clone, Debug, PartialEq)]
@@ -13,6 +18,103 @@ pub struct SatelliteSystems {
 }
 
 impl SatelliteSystems {
+  /// Retrieve or calculate the separation of each pair of neighbors, in
+  /// mutual Hill radii.
+  ///
+  /// Assumes the satellite systems are sorted by orbit.
+  #[named]
+  pub fn get_mutual_hill_separations(&self, host_star: &HostStar) -> Vec<f64> {
+    trace_enter!();
+    let stellar_mass = host_star.get_stellar_mass();
+    trace_var!(stellar_mass);
+    let result = self
+      .satellite_systems
+      .windows(2)
+      .map(|pair| {
+        get_mutual_hill_separation(
+          pair[0].planet.get_mass_in_earth_masses(),
+          pair[1].planet.get_mass_in_earth_masses(),
+          pair[0].planet.get_semi_major_axis(),
+          pair[1].planet.get_semi_major_axis(),
+          stellar_mass,
+        )
+      })
+      .collect();
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve the index of the inner planet of each pair of neighbors that
+  /// are too close together to be stable.
+  #[named]
+  pub fn get_unstable_pairs(&self, host_star: &HostStar) -> Vec<usize> {
+    trace_enter!();
+    let result = self
+      .get_mutual_hill_separations(host_star)
+      .into_iter()
+      .enumerate()
+      .filter(|&(_, separation)| separation < MINIMUM_STABLE_SEPARATION)
+      .map(|(index, _)| index)
+      .collect();
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Indicate whether these planets can coexist for the long haul.
+  #[named]
+  pub fn check_stable(&self, host_star: &HostStar) -> Result<(), Error> {
+    trace_enter!();
+    let result = {
+      if !self.get_unstable_pairs(host_star).is_empty() {
+        return Err(Error::UnstableSatelliteSystems);
+      }
+      Ok(())
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Indicate whether these planets can coexist for the long haul.
+  #[named]
+  pub fn is_stable(&self, host_star: &HostStar) -> bool {
+    trace_enter!();
+    let result = match self.check_stable(host_star) {
+      Ok(()) => true,
+      Err(_) => false,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Break up pairs of neighbors that are too close together.
+  ///
+  /// In reality, one would be flung out of the system or into the star, or
+  /// the two would collide; either way, we lose the smaller one, unless it's
+  /// the habitable one.
+  #[named]
+  pub fn stabilize(&mut self, host_star: &HostStar) {
+    trace_enter!();
+    while let Some(&index) = self.get_unstable_pairs(host_star).first() {
+      trace_var!(index);
+      let inner = &self.satellite_systems[index];
+      let outer = &self.satellite_systems[index + 1];
+      let inner_is_smaller = inner.planet.get_mass_in_earth_masses() < outer.planet.get_mass_in_earth_masses();
+      let lost = match (inner.is_habitable(), outer.is_habitable()) {
+        (true, false) => index + 1,
+        (false, true) => index,
+        _ if inner_is_smaller => index,
+        _ => index + 1,
+      };
+      trace_var!(lost);
+      self.satellite_systems.remove(lost);
+    }
+    trace_exit!();
+  }
+
   /// Indicate whether this star is capable of supporting conventional life.
   #[named]
   pub fn check_habitable(&self) -> Result<(), Error> {
@@ -43,3 +43,154 @@ pub const CLASS_K_WEIGHT: f64 = 12.1;
 
 /// The probability of generating an M-class star.
 pub const CLASS_M_WEIGHT: f64 = 76.45;
+
+/// The length of the subgiant phase, as a fraction of the main-sequence
+/// lifetime.
+pub const SUBGIANT_DURATION: f64 = 0.08;
+
+/// The length of the red giant branch, as a fraction of the main-sequence
+/// lifetime.
+pub const RED_GIANT_BRANCH_DURATION: f64 = 0.1;
+
+/// The length of the horizontal branch, as a fraction of the main-sequence
+/// lifetime.
+pub const HORIZONTAL_BRANCH_DURATION: f64 = 0.012;
+
+/// The length of the asymptotic giant branch, as a fraction of the
+/// main-sequence lifetime.
+pub const ASYMPTOTIC_GIANT_BRANCH_DURATION: f64 = 0.002;
+
+/// A main-sequence star brightens as it ages; this is its luminosity at the
+/// start of the main sequence, relative to its "typical" luminosity.
+///
+/// The Sun started out at about 70% of its present luminosity.
+pub const ZERO_AGE_MAIN_SEQUENCE_LUMINOSITY_FACTOR: f64 = 0.7;
+
+/// As above, but for radius.
+pub const ZERO_AGE_MAIN_SEQUENCE_RADIUS_FACTOR: f64 = 0.85;
+
+/// The temperature at the base of the red giant branch.
+///
+/// Measured in Kelvin.
+pub const RED_GIANT_BRANCH_BASE_TEMPERATURE: f64 = 4_800.0;
+
+/// The temperature at the tip of the red giant branch.
+///
+/// Measured in Kelvin.
+pub const RED_GIANT_BRANCH_TIP_TEMPERATURE: f64 = 3_600.0;
+
+/// The luminosity at the tip of the red giant branch.
+///
+/// For stars below a couple of solar masses, the helium flash happens at
+/// about the same core mass regardless of the star's mass, and so at about
+/// the same luminosity.  More massive stars don't get a flash at all; they
+/// just get a bit brighter.
+///
+/// Measured in Lsol.
+pub const RED_GIANT_BRANCH_TIP_LUMINOSITY: f64 = 2_500.0;
+
+/// The luminosity of a horizontal branch star, i.e. one fusing helium in its
+/// core.
+///
+/// Measured in Lsol.
+pub const HORIZONTAL_BRANCH_LUMINOSITY: f64 = 50.0;
+
+/// The temperature of a horizontal branch star.
+///
+/// Metal-rich stars like the ones we're mostly generating end up in the red
+/// clump rather than out along the blue horizontal branch.
+///
+/// Measured in Kelvin.
+pub const HORIZONTAL_BRANCH_TEMPERATURE: f64 = 5_000.0;
+
+/// The luminosity at the tip of the asymptotic giant branch.
+///
+/// Measured in Lsol.
+pub const ASYMPTOTIC_GIANT_BRANCH_TIP_LUMINOSITY: f64 = 10_000.0;
+
+/// The temperature at the tip of the asymptotic giant branch.
+///
+/// Measured in Kelvin.
+pub const ASYMPTOTIC_GIANT_BRANCH_TIP_TEMPERATURE: f64 = 3_000.0;
+
+/// The mean metallicity ([Fe/H]) of stars in the solar neighborhood.
+///
+/// The Sun is, by definition, 0.0; it's a little richer than most of its
+/// neighbors.
+pub const MEAN_METALLICITY: f64 = -0.05;
+
+/// The spread in metallicity ([Fe/H]) from one star system to the next.
+pub const METALLICITY_STANDARD_DEVIATION: f64 = 0.2;
+
+/// The lowest metallicity ([Fe/H]) we'll generate.
+///
+/// Anything below this would be a halo star, and we're not going there.
+pub const MINIMUM_METALLICITY: f64 = -1.0;
+
+/// The highest metallicity ([Fe/H]) we'll generate.
+pub const MAXIMUM_METALLICITY: f64 = 0.5;
+
+/// How strongly metallicity affects luminosity.
+///
+/// Metal-poor stars have more transparent envelopes, so at a given mass they
+/// are brighter; each dex of [Fe/H] changes luminosity by about this many
+/// dex, in the opposite direction.
+pub const METALLICITY_LUMINOSITY_EXPONENT: f64 = 0.3;
+
+/// How strongly metallicity affects effective temperature.
+///
+/// As above; metal-poor stars are also hotter and bluer.
+pub const METALLICITY_TEMPERATURE_EXPONENT: f64 = 0.06;
+
+/// The rotation period of the Sun, measured in days.
+pub const SOLAR_ROTATION_PERIOD: f64 = 25.4;
+
+/// The age of the Sun, measured in Gyr.
+pub const SOLAR_AGE: f64 = 4.6;
+
+/// Stars spin down as they shed angular momentum through their winds; the
+/// rotation period grows as the square root of age (Skumanich, 1972).
+pub const ROTATION_PERIOD_AGE_EXPONENT: f64 = 0.5;
+
+/// Smaller stars spin down further at a given age.
+pub const ROTATION_PERIOD_MASS_EXPONENT: f64 = -0.6;
+
+/// The fraction of a star's luminosity emitted as X-rays and extreme UV when
+/// it's as active as it can get.
+pub const SATURATED_ACTIVITY: f64 = 1.0E-3;
+
+/// Below this Rossby number, activity is saturated.
+pub const SATURATION_ROSSBY_NUMBER: f64 = 0.13;
+
+/// Above saturation, activity falls off as the Rossby number to this power.
+pub const ACTIVITY_ROSSBY_EXPONENT: f64 = -2.7;
+
+/// Flare energies follow a power law, dN/dE ∝ E^-α, with α around 1.8.
+pub const FLARE_POWER_LAW_INDEX: f64 = 1.8;
+
+/// The reference flare energy for the flare rate, measured in ergs.
+pub const REFERENCE_FLARE_ENERGY: f64 = 1.0E32;
+
+/// The rate of flares above the reference energy for a saturated star,
+/// measured in flares per year.
+///
+/// Young or active M dwarfs manage about one a day.
+pub const SATURATED_FLARE_RATE: f64 = 365.0;
+
+/// The Sun's absolute bolometric magnitude, by IAU fiat.
+pub const SOLAR_ABSOLUTE_BOLOMETRIC_MAGNITUDE: f64 = 4.74;
+
+/// The coolest temperature, in Kelvin, at which we trust the bolometric
+/// correction fit; below this it goes haywire, so we clamp.
+pub const MINIMUM_BOLOMETRIC_CORRECTION_TEMPERATURE: f64 = 3_000.0;
+
+/// The hottest temperature, in Kelvin, at which we trust the bolometric
+/// correction fit.
+pub const MAXIMUM_BOLOMETRIC_CORRECTION_TEMPERATURE: f64 = 50_000.0;
+
+/// Typical interstellar extinction in the visual band near the Sun, measured
+/// in magnitudes per kiloparsec.
+///
+/// The Sun sits in a fairly empty bubble, so this is on the low side of the
+/// usual galactic-plane figures.
+pub const INTERSTELLAR_EXTINCTION_RATE: f64 = 1.0;
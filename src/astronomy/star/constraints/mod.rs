@@ -3,6 +3,10 @@ use std::default::Default;
 
 use crate::astronomy::star::constants::*;
 use crate::astronomy::star::error::Error;
+use crate::astronomy::star::math::mass::{
+  get_random_habitable_stellar_mass_in_range, get_random_stellar_mass_in_range,
+};
+use crate::astronomy::star::math::metallicity::get_random_metallicity;
 use crate::astronomy::star::math::spectral_class::*;
 use crate::astronomy::star::Star;
 
@@ -15,6 +19,8 @@ pub struct Constraints {
   pub maximum_mass: Option<f64>,
   /// Ensure this star is habitable.
   pub make_habitable: bool,
+  /// The metallicity ([Fe/H]) of the star.
+  pub metallicity: Option<f64>,
 }
 
 impl Constraints {
@@ -35,35 +41,64 @@ impl Constraints {
   #[named]
   pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<Star, Error> {
     trace_enter!();
-    let mass = {
-      let random_spectral_class = match self.make_habitable {
-        false => get_random_spectral_class(rng),
-        true => get_random_habitable_spectral_class(rng),
-      };
-      trace_var!(random_spectral_class);
-      let random_range = match self.make_habitable {
-        false => spectral_class_to_mass_range(random_spectral_class),
-        true => spectral_class_to_habitable_mass_range(random_spectral_class),
-      };
-      trace_var!(random_range);
-      let lower_bound_mass = random_range.start;
-      trace_var!(lower_bound_mass);
-      let upper_bound_mass = random_range.end;
-      trace_var!(upper_bound_mass);
-      let mass = rng.gen_range(lower_bound_mass..upper_bound_mass);
-      mass
+    let mass = match (self.minimum_mass, self.maximum_mass) {
+      (None, None) => {
+        let random_spectral_class = match self.make_habitable {
+          false => get_random_spectral_class(rng),
+          true => get_random_habitable_spectral_class(rng),
+        };
+        trace_var!(random_spectral_class);
+        let random_range = match self.make_habitable {
+          false => spectral_class_to_mass_range(random_spectral_class),
+          true => spectral_class_to_habitable_mass_range(random_spectral_class)?,
+        };
+        trace_var!(random_range);
+        let lower_bound_mass = random_range.start;
+        trace_var!(lower_bound_mass);
+        let upper_bound_mass = random_range.end;
+        trace_var!(upper_bound_mass);
+        rng.gen_range(lower_bound_mass..upper_bound_mass)
+      },
+      (minimum_mass, maximum_mass) => {
+        // Stay strictly within the main sequence, or `Star::from_mass` balks.
+        let minimum_mass = minimum_mass.unwrap_or(MINIMUM_MASS).max(1.001 * MINIMUM_MASS);
+        trace_var!(minimum_mass);
+        let maximum_mass = maximum_mass.unwrap_or(MAXIMUM_MASS).min(0.999 * MAXIMUM_MASS);
+        trace_var!(maximum_mass);
+        match self.make_habitable {
+          false => get_random_stellar_mass_in_range(rng, minimum_mass, maximum_mass)?,
+          true => get_random_habitable_stellar_mass_in_range(rng, minimum_mass, maximum_mass)?,
+        }
+      },
     };
     trace_var!(mass);
-    let mut result = Star::from_mass(rng, mass)?;
+    let mut metallicity = match self.metallicity {
+      Some(metallicity) => metallicity,
+      None => get_random_metallicity(rng, MEAN_METALLICITY),
+    };
+    trace_var!(metallicity);
+    let mut result = Star::from_mass_and_metallicity(rng, mass, metallicity)?;
+    // Metal-poor stars burn brighter and die younger; some just can't make it
+    // to a habitable age.  If the metallicity is up to us, pick another.
+    while self.make_habitable && self.metallicity.is_none() && 0.9 * result.life_expectancy <= MINIMUM_HABITABLE_AGE {
+      metallicity = get_random_metallicity(rng, MEAN_METALLICITY);
+      trace_var!(metallicity);
+      result = Star::from_mass_and_metallicity(rng, mass, metallicity)?;
+    }
     trace_var!(result);
+    let maximum_age = 0.9 * result.life_expectancy;
+    trace_var!(maximum_age);
+    if self.make_habitable && maximum_age <= MINIMUM_HABITABLE_AGE {
+      return Err(Error::TooYoungToSupportLife);
+    }
     let minimum_age = match self.make_habitable {
       true => MINIMUM_HABITABLE_AGE,
       false => 0.1 * result.life_expectancy,
     };
     trace_var!(minimum_age);
-    let maximum_age = 0.9 * result.life_expectancy;
-    trace_var!(maximum_age);
-    result.current_age = rng.gen_range(minimum_age..maximum_age);
+    let current_age = rng.gen_range(minimum_age..maximum_age);
+    trace_var!(current_age);
+    let result = result.get_at_age(current_age)?;
     trace_var!(result);
     trace_exit!();
     Ok(result)
@@ -76,10 +111,12 @@ impl Default for Constraints {
     let minimum_mass = None;
     let maximum_mass = None;
     let make_habitable = false;
+    let metallicity = None;
     Self {
       minimum_mass,
       maximum_mass,
       make_habitable,
+      metallicity,
     }
   }
 }
@@ -105,4 +142,28 @@ pub mod test {
     trace_exit!();
     Ok(())
   }
+
+  #[named]
+  #[test]
+  pub fn get_random_habitable() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    for _ in 0..200 {
+      let star = Constraints::habitable().generate(&mut rng)?;
+      trace_var!(star);
+      assert!(star.current_age >= MINIMUM_HABITABLE_AGE);
+      assert_eq!(Ok(()), star.check_habitable());
+    }
+    // A metal-poor F star burns out before it's old enough.
+    let constraints = Constraints {
+      minimum_mass: Some(1.2),
+      metallicity: Some(MINIMUM_METALLICITY),
+      ..Constraints::habitable()
+    };
+    assert_eq!(Err(Error::TooYoungToSupportLife), constraints.generate(&mut rng));
+    trace_exit!();
+    Ok(())
+  }
 }
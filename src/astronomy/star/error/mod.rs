@@ -11,6 +11,14 @@ pub enum Error {
   MassTooLowToSupportLife,
   /// Higher than MAXIMUM_STAR_MASS_TO_SUPPORT_LIFE.
   MassTooHighToSupportLife,
+  /// Stars that have left the main sequence are too unstable.
+  NotOnMainSequence,
+  /// Ages before the star formed are meaningless.
+  AgeTooLow,
+  /// Not a spectral class we recognize.
+  UnparseableSpectralClass,
+  /// No suitable mass lies within the requested bounds.
+  NoMassWithinBounds,
 }
 
 honeyholt_define_brief!(Error, |error: &Error| {
@@ -21,5 +29,9 @@ honeyholt_define_brief!(Error, |error: &Error| {
     TooYoungToSupportLife => "it is too young to support life".to_string(),
     MassTooLowToSupportLife => "its mass is too low to support life".to_string(),
     MassTooHighToSupportLife => "its mass is too high to support life".to_string(),
+    NotOnMainSequence => "it is no longer on the main sequence".to_string(),
+    AgeTooLow => "it had not formed yet".to_string(),
+    UnparseableSpectralClass => "its spectral class could not be understood".to_string(),
+    NoMassWithinBounds => "no suitable mass lies within the requested bounds".to_string(),
   }
 });
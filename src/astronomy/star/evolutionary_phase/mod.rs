@@ -0,0 +1,25 @@
+/// The `EvolutionaryPhase` type.
+///
+/// Where a star is in its life.  Most of the stars we generate are on the main
+/// sequence and will stay there for longer than anyone cares about, but the
+/// more massive ones burn out quickly, and it's nice to know what our sun
+/// looked like when the dinosaurs were around.
+///
+/// The names follow low- and intermediate-mass stars; a massive star swells
+/// into a supergiant rather than a giant, but goes through much the same
+/// motions (only faster).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EvolutionaryPhase {
+  /// Fusing hydrogen in the core.
+  MainSequence,
+  /// Core hydrogen exhausted; fusing hydrogen in a shell and expanding.
+  Subgiant,
+  /// Swelling and brightening on its way to helium ignition.
+  RedGiantBranch,
+  /// Fusing helium in the core.
+  HorizontalBranch,
+  /// Core helium exhausted; fusing helium and hydrogen in shells.
+  AsymptoticGiantBranch,
+  /// It's all over: a white dwarf, neutron star, or black hole.
+  Remnant,
+}
@@ -0,0 +1,150 @@
+use std::fmt;
+
+use crate::astronomy::star::evolutionary_phase::EvolutionaryPhase;
+
+/// The `LuminosityClass` type.
+///
+/// The Roman numeral at the end of a spectral class, i.e. the "III" in
+/// "K0III".  Two stars of the same temperature can differ enormously in size
+/// and brightness, and this is how the MK system tells them apart.
+///
+/// These sort from most to least luminous.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum LuminosityClass {
+  /// Ia+ (or 0): the very brightest, e.g. Rho Cassiopeiae.
+  Hypergiant,
+  /// Ia: luminous supergiants, e.g. Deneb.
+  LuminousSupergiant,
+  /// Iab (or plain I): supergiants, e.g. Betelgeuse.
+  Supergiant,
+  /// Ib: less luminous supergiants.
+  LessLuminousSupergiant,
+  /// II: bright giants.
+  BrightGiant,
+  /// III: giants, e.g. Arcturus.
+  Giant,
+  /// IV: subgiants.
+  Subgiant,
+  /// V: dwarfs, i.e. main-sequence stars like the Sun.
+  MainSequence,
+  /// VI (or sd): subdwarfs, metal-poor and a little dimmer than dwarfs.
+  Subdwarf,
+  /// VII (or D): white dwarfs.
+  WhiteDwarf,
+}
+
+impl LuminosityClass {
+  /// Parse a luminosity class from the start of a string.
+  ///
+  /// Returns the luminosity class and the number of bytes consumed, or None
+  /// if the string doesn't start with one.
+  #[named]
+  pub fn parse_prefix(string: &str) -> Option<(Self, usize)> {
+    trace_enter!();
+    trace_var!(string);
+    use LuminosityClass::*;
+    // Longest first, so "III" doesn't get read as "I" and so forth.
+    let numerals = [
+      ("VII", WhiteDwarf),
+      ("VI", Subdwarf),
+      ("V", MainSequence),
+      ("III", Giant),
+      ("IV", Subgiant),
+      ("II", BrightGiant),
+      ("Iab", Supergiant),
+      ("Ia0", Hypergiant),
+      ("Ia+", Hypergiant),
+      ("Ia", LuminousSupergiant),
+      ("Ib", LessLuminousSupergiant),
+      ("I", Supergiant),
+      ("0", Hypergiant),
+    ];
+    let result = numerals
+      .iter()
+      .find(|(numeral, _)| string.starts_with(numeral))
+      .map(|&(numeral, luminosity_class)| (luminosity_class, numeral.len()));
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Get the luminosity class of a star of a given mass in Msol from its
+  /// evolutionary phase.
+  ///
+  /// Massive stars swell into supergiants rather than giants.  Remnants are
+  /// filed under white dwarfs, which is what most of them are.
+  #[named]
+  pub fn from_evolutionary_phase(phase: EvolutionaryPhase, mass: f64) -> Self {
+    trace_enter!();
+    trace_var!(phase);
+    trace_var!(mass);
+    use EvolutionaryPhase::*;
+    let result = match phase {
+      MainSequence => LuminosityClass::MainSequence,
+      Subgiant => LuminosityClass::Subgiant,
+      RedGiantBranch | HorizontalBranch | AsymptoticGiantBranch if mass >= 10.0 => LuminosityClass::Supergiant,
+      RedGiantBranch | HorizontalBranch => LuminosityClass::Giant,
+      AsymptoticGiantBranch => LuminosityClass::BrightGiant,
+      Remnant => LuminosityClass::WhiteDwarf,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve the Roman numeral for this luminosity class.
+  #[named]
+  pub fn to_numeral(&self) -> &'static str {
+    trace_enter!();
+    use LuminosityClass::*;
+    let result = match self {
+      Hypergiant => "Ia+",
+      LuminousSupergiant => "Ia",
+      Supergiant => "Iab",
+      LessLuminousSupergiant => "Ib",
+      BrightGiant => "II",
+      Giant => "III",
+      Subgiant => "IV",
+      MainSequence => "V",
+      Subdwarf => "VI",
+      WhiteDwarf => "VII",
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Get the typical absolute bolometric magnitude of a star of this class,
+  /// given the magnitude of a main-sequence star of the same temperature.
+  ///
+  /// These are rough; luminosity classes are defined by the look of the
+  /// spectral lines, not by brightness, and the ranges overlap a fair bit.
+  /// Supergiants are all about equally bright whatever their temperature.
+  #[named]
+  pub fn get_absolute_magnitude(&self, main_sequence_magnitude: f64) -> f64 {
+    trace_enter!();
+    trace_var!(main_sequence_magnitude);
+    use LuminosityClass::*;
+    let result = match self {
+      Hypergiant => -9.5,
+      LuminousSupergiant => -8.0,
+      Supergiant => -6.5,
+      LessLuminousSupergiant => -5.0,
+      BrightGiant => (main_sequence_magnitude - 3.0).min(-2.5),
+      Giant => (main_sequence_magnitude - 2.0).min(0.5),
+      Subgiant => main_sequence_magnitude - 1.5,
+      MainSequence => main_sequence_magnitude,
+      Subdwarf => main_sequence_magnitude + 1.5,
+      WhiteDwarf => 12.0,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
+
+impl fmt::Display for LuminosityClass {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.to_numeral())
+  }
+}
@@ -0,0 +1,203 @@
+use rand::prelude::*;
+use std::f64::consts::PI;
+
+use crate::astronomy::star::constants::*;
+use crate::astronomy::star::math::distance::au_to_meters;
+use crate::astronomy::star::math::luminosity::lsol_to_ergs;
+
+/// Get the rotation period of a star in days from its mass in Msol and its
+/// age in Gyr.
+///
+/// This is a rough gyrochronology relation, calibrated to the Sun.
+#[named]
+pub fn star_mass_and_age_to_rotation_period(mass: f64, age: f64) -> f64 {
+  trace_enter!();
+  trace_var!(mass);
+  trace_var!(age);
+  let result = SOLAR_ROTATION_PERIOD
+    * (age / SOLAR_AGE).powf(ROTATION_PERIOD_AGE_EXPONENT)
+    * mass.powf(ROTATION_PERIOD_MASS_EXPONENT);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the convective turnover time of a star in days from its mass in Msol.
+///
+/// After Wright et al. (2018).
+#[named]
+pub fn star_mass_to_convective_turnover_time(mass: f64) -> f64 {
+  trace_enter!();
+  trace_var!(mass);
+  let result = 10.0_f64.powf(2.33 - 1.5 * mass + 0.31 * mass.powf(2.0));
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the activity level of a star, i.e. the fraction of its luminosity
+/// emitted as X-rays and extreme UV, from its rotation period in days and
+/// its mass in Msol.
+///
+/// Activity depends on the Rossby number, the ratio of the rotation period
+/// to the convective turnover time; fast rotators are saturated, and slower
+/// rotators fall off steeply.
+#[named]
+pub fn get_activity(rotation_period: f64, mass: f64) -> f64 {
+  trace_enter!();
+  trace_var!(rotation_period);
+  trace_var!(mass);
+  let rossby_number = rotation_period / star_mass_to_convective_turnover_time(mass);
+  trace_var!(rossby_number);
+  let result = match rossby_number {
+    rossby_number if rossby_number < SATURATION_ROSSBY_NUMBER => SATURATED_ACTIVITY,
+    rossby_number => SATURATED_ACTIVITY * (rossby_number / SATURATION_ROSSBY_NUMBER).powf(ACTIVITY_ROSSBY_EXPONENT),
+  };
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the XUV flux at a given distance, in erg/cm²/s, from the XUV
+/// luminosity in Lsol and the distance in AU.
+#[named]
+pub fn get_xuv_flux(xuv_luminosity: f64, distance: f64) -> f64 {
+  trace_enter!();
+  trace_var!(xuv_luminosity);
+  trace_var!(distance);
+  let distance_cm = au_to_meters(distance) * 100.0;
+  trace_var!(distance_cm);
+  let result = lsol_to_ergs(xuv_luminosity) / (4.0 * PI * distance_cm.powf(2.0));
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the rate of flares at or above a given energy in ergs, in flares per
+/// year, from the activity level.
+#[named]
+pub fn get_flare_rate(activity: f64, energy: f64) -> f64 {
+  trace_enter!();
+  trace_var!(activity);
+  trace_var!(energy);
+  let result = SATURATED_FLARE_RATE
+    * (activity / SATURATED_ACTIVITY)
+    * (energy / REFERENCE_FLARE_ENERGY).powf(1.0 - FLARE_POWER_LAW_INDEX);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the energy, in ergs, a flare needs to deliver a given fluence, in
+/// erg/cm², at a given distance in AU.
+#[named]
+pub fn get_flare_energy_for_fluence(fluence: f64, distance: f64) -> f64 {
+  trace_enter!();
+  trace_var!(fluence);
+  trace_var!(distance);
+  let distance_cm = au_to_meters(distance) * 100.0;
+  trace_var!(distance_cm);
+  let result = fluence * 4.0 * PI * distance_cm.powf(2.0);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the energy of a random flare at or above a given energy, in ergs.
+#[named]
+pub fn get_random_flare_energy<R: Rng + ?Sized>(rng: &mut R, minimum_energy: f64) -> f64 {
+  trace_enter!();
+  trace_var!(minimum_energy);
+  let uniform: f64 = rng.gen_range(f64::EPSILON..1.0);
+  trace_var!(uniform);
+  let result = minimum_energy * uniform.powf(1.0 / (1.0 - FLARE_POWER_LAW_INDEX));
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::astronomy::star::error::Error;
+  use crate::astronomy::star::Star;
+  use crate::astronomy::terrestrial_planet::constants::HAZARDOUS_FLARE_FLUENCE;
+  use crate::astronomy::terrestrial_planet::error::Error as TerrestrialPlanetError;
+  use crate::astronomy::terrestrial_planet::TerrestrialPlanet;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_activity() {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    // Jolly ol' Sol
+    let rotation_period = star_mass_and_age_to_rotation_period(1.0, SOLAR_AGE);
+    assert_approx_eq!(rotation_period, SOLAR_ROTATION_PERIOD);
+    let activity = get_activity(rotation_period, 1.0);
+    print_var!(activity);
+    assert!(activity < 1.0E-5);
+    // Earth gets about 1 erg/cm²/s of XUV.
+    let xuv_flux = get_xuv_flux(activity, 1.0);
+    print_var!(xuv_flux);
+    assert_approx_eq!(xuv_flux, 1.0, 0.5);
+    // Activity decays with age, and a young M dwarf is saturated.
+    assert!(get_activity(star_mass_and_age_to_rotation_period(1.0, 1.0), 1.0) > activity);
+    let young_m_dwarf = get_activity(star_mass_and_age_to_rotation_period(0.2, 0.1), 0.2);
+    assert_approx_eq!(young_m_dwarf, SATURATED_ACTIVITY);
+    assert_approx_eq!(
+      get_flare_rate(SATURATED_ACTIVITY, REFERENCE_FLARE_ENERGY),
+      SATURATED_FLARE_RATE
+    );
+    assert!(get_flare_rate(SATURATED_ACTIVITY, 10.0 * REFERENCE_FLARE_ENERGY) < SATURATED_FLARE_RATE);
+    for _ in 0..100 {
+      assert!(get_random_flare_energy(&mut rng, REFERENCE_FLARE_ENERGY) >= REFERENCE_FLARE_ENERGY);
+    }
+    trace_exit!();
+  }
+
+  #[named]
+  #[test]
+  pub fn test_habitability() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    // An Earth in the middle of the habitable zone, exposed to whatever the
+    // star throws at it.
+    let get_planet = |star: &Star| {
+      let distance = (star.habitable_zone.0 + star.habitable_zone.1) / 2.0;
+      let mut planet = TerrestrialPlanet::from_mass(1.0).unwrap();
+      planet.xuv_flux = star.get_xuv_flux(distance);
+      planet.hazardous_flare_rate =
+        star.get_flare_rate(get_flare_energy_for_fluence(HAZARDOUS_FLARE_FLUENCE, distance));
+      planet
+    };
+    let sun = Star::from_mass(&mut rng, 1.0)?.get_at_age(SOLAR_AGE)?;
+    let planet = get_planet(&sun);
+    print_var!(planet.xuv_flux);
+    print_var!(planet.hazardous_flare_rate);
+    assert_eq!(Ok(()), planet.check_habitable());
+    let young_m_dwarf = Star::from_mass(&mut rng, 0.2)?.get_at_age(0.1)?;
+    let mut planet = get_planet(&young_m_dwarf);
+    print_var!(planet.xuv_flux);
+    print_var!(planet.hazardous_flare_rate);
+    assert_eq!(
+      Err(TerrestrialPlanetError::XuvFluxTooHighToSupportConventionalLife),
+      planet.check_habitable()
+    );
+    // Even if the atmosphere survived the XUV, the flares would get it.
+    planet.xuv_flux = 1.0;
+    assert_eq!(
+      Err(TerrestrialPlanetError::FlaresTooFrequentToSupportConventionalLife),
+      planet.check_habitable()
+    );
+    trace_exit!();
+    Ok(())
+  }
+}
@@ -23,6 +23,19 @@ pub fn star_mass_to_rgb(mass: f64) -> Result<(u8, u8, u8), Error> {
   }
   let temperature = star_mass_to_temperature(mass)?;
   trace_var!(temperature);
+  let result = star_temperature_to_rgb(temperature);
+  trace_3u8!(result);
+  trace_exit!();
+  Ok(result)
+}
+
+/// Get the RGB color of a star based on its temperature in Kelvin.
+///
+/// As above, this is the absolute color.
+#[named]
+pub fn star_temperature_to_rgb(temperature: f64) -> (u8, u8, u8) {
+  trace_enter!();
+  trace_var!(temperature);
   let x = match temperature {
     temperature if temperature >= 1_667.0 && temperature <= 4_000.0 => {
       ((-0.2661239 * (10.0_f64).powf(9.0)) / temperature.powf(3.0))
@@ -86,7 +99,7 @@ pub fn star_mass_to_rgb(mass: f64) -> Result<(u8, u8, u8), Error> {
   trace_var!(result);
   trace_3u8!(result);
   trace_exit!();
-  Ok(result)
+  result
 }
 
 #[cfg(test)]
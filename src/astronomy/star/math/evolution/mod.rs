@@ -0,0 +1,266 @@
+use crate::astronomy::star::constants::*;
+use crate::astronomy::star::error::Error;
+use crate::astronomy::star::evolutionary_phase::EvolutionaryPhase;
+use crate::astronomy::star::math::luminosity::star_mass_to_luminosity;
+use crate::astronomy::star::math::metallicity::{metallicity_to_luminosity_factor, metallicity_to_radius_factor};
+use crate::astronomy::star::math::radius::star_mass_to_radius;
+use crate::astronomy::stellar_remnant::math::luminosity::get_remnant_luminosity_and_temperature;
+use crate::astronomy::stellar_remnant::math::mass::*;
+use crate::astronomy::stellar_remnant::math::radius::remnant_mass_to_radius;
+
+/// Get the evolutionary phase of a star from its age and main-sequence life
+/// expectancy, both in Gyr.
+#[named]
+pub fn get_evolutionary_phase(age: f64, life_expectancy: f64) -> EvolutionaryPhase {
+  trace_enter!();
+  trace_var!(age);
+  trace_var!(life_expectancy);
+  use EvolutionaryPhase::*;
+  let age = age / life_expectancy;
+  trace_var!(age);
+  let subgiant_end = 1.0 + SUBGIANT_DURATION;
+  let red_giant_branch_end = subgiant_end + RED_GIANT_BRANCH_DURATION;
+  let horizontal_branch_end = red_giant_branch_end + HORIZONTAL_BRANCH_DURATION;
+  let asymptotic_giant_branch_end = horizontal_branch_end + ASYMPTOTIC_GIANT_BRANCH_DURATION;
+  let result = match age {
+    age if age < 1.0 => MainSequence,
+    age if age < subgiant_end => Subgiant,
+    age if age < red_giant_branch_end => RedGiantBranch,
+    age if age < horizontal_branch_end => HorizontalBranch,
+    age if age < asymptotic_giant_branch_end => AsymptoticGiantBranch,
+    _ => Remnant,
+  };
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the age (in Gyr) at which a star of the given mass (in Msol) and
+/// metallicity ([Fe/H]) dies and leaves behind a remnant.
+#[named]
+pub fn get_remnant_formation_age(mass: f64, metallicity: f64) -> Result<f64, Error> {
+  trace_enter!();
+  trace_var!(mass);
+  trace_var!(metallicity);
+  let luminosity = star_mass_to_luminosity(mass)? * metallicity_to_luminosity_factor(metallicity);
+  trace_var!(luminosity);
+  let life_expectancy = mass / luminosity * 10.0;
+  trace_var!(life_expectancy);
+  let result = life_expectancy
+    * (1.0
+      + SUBGIANT_DURATION
+      + RED_GIANT_BRANCH_DURATION
+      + HORIZONTAL_BRANCH_DURATION
+      + ASYMPTOTIC_GIANT_BRANCH_DURATION);
+  trace_var!(result);
+  trace_exit!();
+  Ok(result)
+}
+
+/// Get the radius of a star in Rsol from its luminosity in Lsol and its
+/// temperature in Kelvin.
+#[named]
+pub fn star_luminosity_and_temperature_to_radius(luminosity: f64, temperature: f64) -> f64 {
+  trace_enter!();
+  trace_var!(luminosity);
+  trace_var!(temperature);
+  let result = luminosity.sqrt() * (5776.0 / temperature).powf(2.0);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the temperature of a star in Kelvin from its luminosity in Lsol and
+/// its radius in Rsol.
+#[named]
+pub fn star_luminosity_and_radius_to_temperature(luminosity: f64, radius: f64) -> f64 {
+  trace_enter!();
+  trace_var!(luminosity);
+  trace_var!(radius);
+  let result = (luminosity / radius.powf(2.0)).powf(0.25) * 5776.0;
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Interpolate geometrically, which suits quantities that change by orders
+/// of magnitude.
+fn interpolate(start: f64, end: f64, fraction: f64) -> f64 {
+  start * (end / start).powf(fraction)
+}
+
+/// Get the phase, luminosity, radius, and temperature of a star of the given
+/// mass (in Msol) and metallicity ([Fe/H]) at the given age (in Gyr).
+///
+/// This is a very rough sketch of the stellar tracks; each phase lasts a
+/// fixed fraction of the main-sequence lifetime, and within each phase we
+/// just interpolate between a few waypoints.  It's good enough to make a star
+/// brighten over time, swell into a giant, and fade away.
+///
+/// Returns (phase, luminosity in Lsol, radius in Rsol, temperature in K).
+#[named]
+pub fn get_evolved_properties(
+  mass: f64,
+  metallicity: f64,
+  age: f64,
+) -> Result<(EvolutionaryPhase, f64, f64, f64), Error> {
+  trace_enter!();
+  trace_var!(mass);
+  trace_var!(metallicity);
+  trace_var!(age);
+  use EvolutionaryPhase::*;
+  if age < 0.0 {
+    return Err(Error::AgeTooLow);
+  }
+  let main_sequence_luminosity = star_mass_to_luminosity(mass)? * metallicity_to_luminosity_factor(metallicity);
+  trace_var!(main_sequence_luminosity);
+  let main_sequence_radius = star_mass_to_radius(mass)? * metallicity_to_radius_factor(metallicity);
+  trace_var!(main_sequence_radius);
+  let life_expectancy = mass / main_sequence_luminosity * 10.0;
+  trace_var!(life_expectancy);
+  let phase = get_evolutionary_phase(age, life_expectancy);
+  trace_var!(phase);
+  // The main-sequence values are "typical", i.e. at the halfway point.
+  let terminal_luminosity = main_sequence_luminosity * (2.0 - ZERO_AGE_MAIN_SEQUENCE_LUMINOSITY_FACTOR);
+  trace_var!(terminal_luminosity);
+  let terminal_radius = main_sequence_radius * (2.0 - ZERO_AGE_MAIN_SEQUENCE_RADIUS_FACTOR);
+  trace_var!(terminal_radius);
+  let terminal_temperature = star_luminosity_and_radius_to_temperature(terminal_luminosity, terminal_radius);
+  trace_var!(terminal_temperature);
+  let base_luminosity = 1.5 * terminal_luminosity;
+  let base_temperature = terminal_temperature.min(RED_GIANT_BRANCH_BASE_TEMPERATURE);
+  let tip_luminosity = RED_GIANT_BRANCH_TIP_LUMINOSITY.max(2.0 * base_luminosity);
+  let horizontal_branch_luminosity = HORIZONTAL_BRANCH_LUMINOSITY.max(base_luminosity);
+  let asymptotic_tip_luminosity = ASYMPTOTIC_GIANT_BRANCH_TIP_LUMINOSITY.max(2.0 * tip_luminosity);
+  // How far along we are in the current phase, from 0.0 to 1.0.
+  let elapsed = age / life_expectancy;
+  let (start, duration) = match phase {
+    MainSequence => (0.0, 1.0),
+    Subgiant => (1.0, SUBGIANT_DURATION),
+    RedGiantBranch => (1.0 + SUBGIANT_DURATION, RED_GIANT_BRANCH_DURATION),
+    HorizontalBranch => (
+      1.0 + SUBGIANT_DURATION + RED_GIANT_BRANCH_DURATION,
+      HORIZONTAL_BRANCH_DURATION,
+    ),
+    AsymptoticGiantBranch => (
+      1.0 + SUBGIANT_DURATION + RED_GIANT_BRANCH_DURATION + HORIZONTAL_BRANCH_DURATION,
+      ASYMPTOTIC_GIANT_BRANCH_DURATION,
+    ),
+    Remnant => (
+      1.0
+        + SUBGIANT_DURATION
+        + RED_GIANT_BRANCH_DURATION
+        + HORIZONTAL_BRANCH_DURATION
+        + ASYMPTOTIC_GIANT_BRANCH_DURATION,
+      0.0,
+    ),
+  };
+  let fraction = match phase {
+    Remnant => 0.0,
+    _ => (elapsed - start) / duration,
+  };
+  trace_var!(fraction);
+  let (luminosity, temperature) = match phase {
+    MainSequence => {
+      let luminosity_factor =
+        ZERO_AGE_MAIN_SEQUENCE_LUMINOSITY_FACTOR + 2.0 * (1.0 - ZERO_AGE_MAIN_SEQUENCE_LUMINOSITY_FACTOR) * fraction;
+      let radius_factor =
+        ZERO_AGE_MAIN_SEQUENCE_RADIUS_FACTOR + 2.0 * (1.0 - ZERO_AGE_MAIN_SEQUENCE_RADIUS_FACTOR) * fraction;
+      let luminosity = main_sequence_luminosity * luminosity_factor;
+      let radius = main_sequence_radius * radius_factor;
+      (
+        luminosity,
+        star_luminosity_and_radius_to_temperature(luminosity, radius),
+      )
+    },
+    Subgiant => (
+      interpolate(terminal_luminosity, base_luminosity, fraction),
+      interpolate(terminal_temperature, base_temperature, fraction),
+    ),
+    RedGiantBranch => (
+      interpolate(base_luminosity, tip_luminosity, fraction.powf(2.0)),
+      interpolate(base_temperature, RED_GIANT_BRANCH_TIP_TEMPERATURE, fraction),
+    ),
+    HorizontalBranch => (horizontal_branch_luminosity, HORIZONTAL_BRANCH_TEMPERATURE),
+    AsymptoticGiantBranch => (
+      interpolate(horizontal_branch_luminosity, asymptotic_tip_luminosity, fraction),
+      interpolate(
+        RED_GIANT_BRANCH_TIP_TEMPERATURE,
+        ASYMPTOTIC_GIANT_BRANCH_TIP_TEMPERATURE,
+        fraction,
+      ),
+    ),
+    Remnant => {
+      let cooling_age = age - start * life_expectancy;
+      get_remnant_luminosity_and_temperature(
+        progenitor_mass_to_remnant_type(mass),
+        progenitor_mass_to_remnant_mass(mass),
+        cooling_age,
+      )
+    },
+  };
+  trace_var!(luminosity);
+  trace_var!(temperature);
+  let radius = match phase {
+    Remnant => remnant_mass_to_radius(
+      progenitor_mass_to_remnant_type(mass),
+      progenitor_mass_to_remnant_mass(mass),
+    ),
+    _ => star_luminosity_and_temperature_to_radius(luminosity, temperature),
+  };
+  trace_var!(radius);
+  let result = (phase, luminosity, radius, temperature);
+  trace_var!(result);
+  trace_exit!();
+  Ok(result)
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_evolutionary_phase() {
+    init();
+    trace_enter!();
+    use EvolutionaryPhase::*;
+    assert_eq!(MainSequence, get_evolutionary_phase(4.6, 10.0));
+    assert_eq!(Subgiant, get_evolutionary_phase(10.5, 10.0));
+    assert_eq!(RedGiantBranch, get_evolutionary_phase(11.5, 10.0));
+    assert_eq!(Remnant, get_evolutionary_phase(13.0, 10.0));
+    trace_exit!();
+  }
+
+  #[named]
+  #[test]
+  pub fn test_get_evolved_properties() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    use EvolutionaryPhase::*;
+    // Jolly ol' Sol, then, now, and later.
+    let (phase, young_luminosity, _, _) = get_evolved_properties(1.0, 0.0, 0.0)?;
+    assert_eq!(MainSequence, phase);
+    assert_approx_eq!(young_luminosity, 0.7);
+    let (phase, luminosity, radius, temperature) = get_evolved_properties(1.0, 0.0, 4.6)?;
+    assert_eq!(MainSequence, phase);
+    assert_approx_eq!(luminosity, 1.0, 0.05);
+    assert_approx_eq!(radius, 1.0, 0.05);
+    assert_approx_eq!(temperature, 5776.0, 50.0);
+    let (phase, luminosity, radius, temperature) = get_evolved_properties(1.0, 0.0, 11.7)?;
+    print_var!(luminosity);
+    print_var!(radius);
+    print_var!(temperature);
+    assert_eq!(RedGiantBranch, phase);
+    assert!(luminosity > 100.0 && radius > 10.0 && temperature < 4_800.0);
+    let (phase, luminosity, radius, _) = get_evolved_properties(1.0, 0.0, 14.0)?;
+    assert_eq!(Remnant, phase);
+    assert!(luminosity < 0.01);
+    assert!(radius < 0.02);
+    assert_eq!(Err(Error::AgeTooLow), get_evolved_properties(1.0, 0.0, -1.0));
+    trace_exit!();
+    Ok(())
+  }
+}
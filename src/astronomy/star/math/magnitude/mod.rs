@@ -0,0 +1,180 @@
+use crate::astronomy::star::constants::*;
+use crate::astronomy::stellar_neighbor::constants::LIGHT_YEARS_PER_PARSEC;
+
+/// Get the absolute bolometric magnitude of a star based on its Lsol.
+#[named]
+pub fn star_luminosity_to_absolute_bolometric_magnitude(luminosity: f64) -> f64 {
+  trace_enter!();
+  trace_var!(luminosity);
+  let result = SOLAR_ABSOLUTE_BOLOMETRIC_MAGNITUDE - 2.5 * luminosity.log10();
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the bolometric correction (in the V band) of a star based on its
+/// temperature in Kelvin.
+///
+/// This is the polynomial fit in log T by Flower (1996), with the typos
+/// corrected by Torres (2010).  It's only good for about 3,000K to 50,000K,
+/// so we clamp to that.
+#[named]
+pub fn star_temperature_to_bolometric_correction(temperature: f64) -> f64 {
+  trace_enter!();
+  trace_var!(temperature);
+  let log_temperature = temperature
+    .clamp(
+      MINIMUM_BOLOMETRIC_CORRECTION_TEMPERATURE,
+      MAXIMUM_BOLOMETRIC_CORRECTION_TEMPERATURE,
+    )
+    .log10();
+  trace_var!(log_temperature);
+  let coefficients: &[f64] = match log_temperature {
+    log_temperature if log_temperature < 3.70 => &[
+      -0.190537291496456E+05,
+      0.155144866764412E+05,
+      -0.421278819301717E+04,
+      0.381476328422343E+03,
+    ],
+    log_temperature if log_temperature < 3.90 => &[
+      -0.370510203809015E+05,
+      0.385672629965804E+05,
+      -0.150651486316025E+05,
+      0.261724637119416E+04,
+      -0.170623810323864E+03,
+    ],
+    _ => &[
+      -0.118115450538963E+06,
+      0.137145973583929E+06,
+      -0.636233812100225E+05,
+      0.147412923562646E+05,
+      -0.170587278406872E+04,
+      0.788731721804990E+02,
+    ],
+  };
+  let result = coefficients
+    .iter()
+    .rev()
+    .fold(0.0, |sum, coefficient| sum * log_temperature + coefficient);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the absolute visual (V band) magnitude of a star based on its Lsol and
+/// temperature in Kelvin.
+#[named]
+pub fn star_luminosity_and_temperature_to_absolute_visual_magnitude(luminosity: f64, temperature: f64) -> f64 {
+  trace_enter!();
+  trace_var!(luminosity);
+  trace_var!(temperature);
+  let result = star_luminosity_to_absolute_bolometric_magnitude(luminosity)
+    - star_temperature_to_bolometric_correction(temperature);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the B-V color index of a star based on its temperature in Kelvin.
+///
+/// This inverts the formula by Ballesteros (2012), which treats the star as a
+/// black body seen through the B and V filters.
+#[named]
+pub fn star_temperature_to_color_index(temperature: f64) -> f64 {
+  trace_enter!();
+  trace_var!(temperature);
+  // T = 4600 (1 / (0.92 (B-V) + 1.7) + 1 / (0.92 (B-V) + 0.62)), which is a
+  // quadratic in 0.92 (B-V).
+  let k = temperature / 4_600.0;
+  let b = 2.32 * k - 2.0;
+  let c = 1.054 * k - 2.32;
+  let x = (-b + (b.powf(2.0) - 4.0 * k * c).sqrt()) / (2.0 * k);
+  let result = x / 0.92;
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the interstellar extinction in the V band, in magnitudes, over a
+/// given distance in light years.
+#[named]
+pub fn get_interstellar_extinction(distance: f64) -> f64 {
+  trace_enter!();
+  trace_var!(distance);
+  let result = INTERSTELLAR_EXTINCTION_RATE * distance / LIGHT_YEARS_PER_PARSEC / 1_000.0;
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the apparent magnitude of an object given its absolute magnitude and
+/// distance in light years.
+///
+/// Extinction is in magnitudes; if we aren't given it, we estimate it from
+/// the distance.
+#[named]
+pub fn get_apparent_magnitude(absolute_magnitude: f64, distance: f64, extinction: Option<f64>) -> f64 {
+  trace_enter!();
+  trace_var!(absolute_magnitude);
+  trace_var!(distance);
+  trace_var!(extinction);
+  let extinction = extinction.unwrap_or_else(|| get_interstellar_extinction(distance));
+  trace_var!(extinction);
+  let distance_modulus = 5.0 * (distance / LIGHT_YEARS_PER_PARSEC).log10() - 5.0;
+  trace_var!(distance_modulus);
+  let result = absolute_magnitude + distance_modulus + extinction;
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the magnitude of two objects seen as one.
+///
+/// Magnitudes are logarithmic, so we add up the fluxes instead.
+#[named]
+pub fn combine_magnitudes(magnitude1: f64, magnitude2: f64) -> f64 {
+  trace_enter!();
+  trace_var!(magnitude1);
+  trace_var!(magnitude2);
+  let result = -2.5 * (10.0_f64.powf(-0.4 * magnitude1) + 10.0_f64.powf(-0.4 * magnitude2)).log10();
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_magnitudes() {
+    init();
+    trace_enter!();
+    // Jolly ol' Sol
+    let bolometric_magnitude = star_luminosity_to_absolute_bolometric_magnitude(1.0);
+    assert_approx_eq!(bolometric_magnitude, 4.74);
+    let bolometric_correction = star_temperature_to_bolometric_correction(5_772.0);
+    print_var!(bolometric_correction);
+    assert_approx_eq!(bolometric_correction, -0.08, 0.02);
+    let color_index = star_temperature_to_color_index(5_772.0);
+    print_var!(color_index);
+    assert_approx_eq!(color_index, 0.65, 0.03);
+    // M dwarfs are much dimmer in V than bolometrically, and very red.
+    assert!(star_temperature_to_bolometric_correction(3_200.0) < -3.0);
+    assert!(star_temperature_to_color_index(3_200.0) > 1.4);
+    // Hot stars are blue, and emit mostly in the UV.
+    assert!(star_temperature_to_bolometric_correction(30_000.0) < -2.5);
+    assert!(star_temperature_to_color_index(30_000.0) < -0.2);
+    assert!(star_temperature_to_bolometric_correction(1_000.0).is_finite());
+    // The Sun seen from 10 parsecs, without and with extinction.
+    let distance = 10.0 * LIGHT_YEARS_PER_PARSEC;
+    assert_approx_eq!(get_apparent_magnitude(4.83, distance, Some(0.0)), 4.83);
+    assert!(get_apparent_magnitude(4.83, distance, None) > 4.83);
+    assert_approx_eq!(combine_magnitudes(5.0, 5.0), 5.0 - 2.5 * 2.0_f64.log10());
+    assert_approx_eq!(combine_magnitudes(5.0, f64::INFINITY), 5.0);
+    trace_exit!();
+  }
+}
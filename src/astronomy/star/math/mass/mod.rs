@@ -1,8 +1,13 @@
+use crate::astronomy::star::constants::*;
+use crate::astronomy::star::error::Error;
 use crate::astronomy::star::math::spectral_class::{
   get_random_habitable_spectral_class, get_random_spectral_class, spectral_class_to_habitable_mass_range,
   spectral_class_to_mass_range,
 };
+use crate::astronomy::star::spectral_type::SpectralType;
+use rand::distributions::WeightedIndex;
 use rand::prelude::*;
+use std::ops::Range;
 
 const KG_PER_SOLAR_MASS: f64 = 1.989E30;
 
@@ -36,10 +41,143 @@ pub fn get_random_habitable_stellar_mass<R: Rng + ?Sized>(rng: &mut R) -> f64 {
   trace_enter!();
   let spectral_class = get_random_habitable_spectral_class(rng);
   trace_var!(spectral_class);
-  let mass_range = spectral_class_to_habitable_mass_range(spectral_class);
+  // The habitable spectral classes all have habitable mass ranges.
+  let mass_range = spectral_class_to_habitable_mass_range(spectral_class).unwrap();
   trace_var!(mass_range);
   let result = rng.gen_range(mass_range);
   trace_var!(result);
   trace_exit!();
   result
 }
+
+/// Get a (weighted) random mass for a star within the specified bounds.
+///
+/// Each spectral class is weighted by its overall frequency and by how much
+/// of its mass range falls within the bounds, so the result follows the same
+/// distribution as `get_random_stellar_mass`, just truncated.
+#[named]
+pub fn get_random_stellar_mass_in_range<R: Rng + ?Sized>(
+  rng: &mut R,
+  minimum_mass: f64,
+  maximum_mass: f64,
+) -> Result<f64, Error> {
+  trace_enter!();
+  trace_var!(minimum_mass);
+  trace_var!(maximum_mass);
+  use SpectralType::*;
+  let choices = [
+    (O, CLASS_O_WEIGHT),
+    (B, CLASS_B_WEIGHT),
+    (A, CLASS_A_WEIGHT),
+    (F, CLASS_F_WEIGHT),
+    (G, CLASS_G_WEIGHT),
+    (K, CLASS_K_WEIGHT),
+    (M, CLASS_M_WEIGHT),
+  ]
+  .iter()
+  .map(|&(class, weight)| (spectral_class_to_mass_range(class), weight))
+  .collect::<Vec<(Range<f64>, f64)>>();
+  let result = get_random_mass_from_choices(rng, &choices, minimum_mass, maximum_mass)?;
+  trace_var!(result);
+  trace_exit!();
+  Ok(result)
+}
+
+/// Get a (weighted) habitable random mass for a star within the specified
+/// bounds.
+#[named]
+pub fn get_random_habitable_stellar_mass_in_range<R: Rng + ?Sized>(
+  rng: &mut R,
+  minimum_mass: f64,
+  maximum_mass: f64,
+) -> Result<f64, Error> {
+  trace_enter!();
+  trace_var!(minimum_mass);
+  trace_var!(maximum_mass);
+  use SpectralType::*;
+  let choices = [(F, CLASS_F_WEIGHT), (G, CLASS_G_WEIGHT), (K, CLASS_K_WEIGHT)]
+    .iter()
+    .filter_map(|&(class, weight)| Some((spectral_class_to_habitable_mass_range(class).ok()?, weight)))
+    .collect::<Vec<(Range<f64>, f64)>>();
+  let result = get_random_mass_from_choices(rng, &choices, minimum_mass, maximum_mass)?;
+  trace_var!(result);
+  trace_exit!();
+  Ok(result)
+}
+
+/// Pick a mass range by weight, truncated to the bounds, and then a mass
+/// within it.
+///
+/// If the bounds collapse to a single mass, e.g. for a member of a binary,
+/// we take it as long as one of the ranges allows it.  Otherwise, bounds
+/// that miss every range are an error; quietly picking something else would
+/// hand back a mass the caller didn't ask for.
+#[named]
+fn get_random_mass_from_choices<R: Rng + ?Sized>(
+  rng: &mut R,
+  choices: &[(Range<f64>, f64)],
+  minimum_mass: f64,
+  maximum_mass: f64,
+) -> Result<f64, Error> {
+  trace_enter!();
+  if minimum_mass >= maximum_mass {
+    let result = match choices
+      .iter()
+      .any(|(range, _)| range.start <= minimum_mass && minimum_mass <= range.end)
+    {
+      true => Ok(minimum_mass),
+      false => Err(Error::NoMassWithinBounds),
+    };
+    trace_var!(result);
+    trace_exit!();
+    return result;
+  }
+  let truncated = choices
+    .iter()
+    .map(|(range, weight)| {
+      let start = range.start.max(minimum_mass);
+      let end = range.end.min(maximum_mass);
+      let overlap = (end - start).max(0.0);
+      (start..end, weight * overlap / (range.end - range.start))
+    })
+    .collect::<Vec<(Range<f64>, f64)>>();
+  trace_var!(truncated);
+  let dist = WeightedIndex::new(truncated.iter().map(|(_, weight)| *weight)).map_err(|_| Error::NoMassWithinBounds)?;
+  let index = dist.sample(rng);
+  let result = rng.gen_range(truncated[index].0.clone());
+  trace_var!(result);
+  trace_exit!();
+  Ok(result)
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_random_stellar_mass_in_range() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    for _ in 0..100 {
+      let mass = get_random_stellar_mass_in_range(&mut rng, 0.3, 2.0)?;
+      assert!((0.3..2.0).contains(&mass));
+      let mass = get_random_stellar_mass_in_range(&mut rng, 0.6, 0.6)?;
+      assert_approx_eq!(mass, 0.6);
+      let mass = get_random_habitable_stellar_mass_in_range(&mut rng, 0.1, 0.9)?;
+      assert!((MINIMUM_HABITABLE_MASS..0.9).contains(&mass));
+      let mass = get_random_habitable_stellar_mass_in_range(&mut rng, 0.299, 0.301);
+      assert_eq!(mass, Err(Error::NoMassWithinBounds));
+      let mass = get_random_habitable_stellar_mass_in_range(&mut rng, 0.3, 0.3);
+      assert_eq!(mass, Err(Error::NoMassWithinBounds));
+    }
+    trace_exit!();
+    Ok(())
+  }
+}
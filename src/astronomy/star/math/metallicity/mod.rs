@@ -0,0 +1,96 @@
+use rand::prelude::*;
+use rand_distr::{Distribution, Normal};
+
+use crate::astronomy::star::constants::*;
+
+/// Get a random metallicity ([Fe/H]) scattered around a mean.
+///
+/// The result is clamped to the range of metallicities we can reasonably
+/// model.
+#[named]
+pub fn get_random_metallicity<R: Rng + ?Sized>(rng: &mut R, mean: f64) -> f64 {
+  trace_enter!();
+  trace_var!(mean);
+  let metallicity = Normal::new(mean, METALLICITY_STANDARD_DEVIATION).unwrap().sample(rng);
+  trace_var!(metallicity);
+  let result = metallicity.clamp(MINIMUM_METALLICITY, MAXIMUM_METALLICITY);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the factor by which metallicity ([Fe/H]) scales the luminosity of a
+/// main-sequence star of a given mass.
+///
+/// This is 1.0 at solar metallicity.
+#[named]
+pub fn metallicity_to_luminosity_factor(metallicity: f64) -> f64 {
+  trace_enter!();
+  trace_var!(metallicity);
+  let result = 10.0_f64.powf(-METALLICITY_LUMINOSITY_EXPONENT * metallicity);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the factor by which metallicity ([Fe/H]) scales the temperature of a
+/// main-sequence star of a given mass.
+///
+/// This is 1.0 at solar metallicity.
+#[named]
+pub fn metallicity_to_temperature_factor(metallicity: f64) -> f64 {
+  trace_enter!();
+  trace_var!(metallicity);
+  let result = 10.0_f64.powf(-METALLICITY_TEMPERATURE_EXPONENT * metallicity);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the factor by which metallicity ([Fe/H]) scales the radius of a
+/// main-sequence star of a given mass.
+///
+/// This just follows from the luminosity and temperature factors, courtesy
+/// of Stefan-Boltzmann.
+#[named]
+pub fn metallicity_to_radius_factor(metallicity: f64) -> f64 {
+  trace_enter!();
+  trace_var!(metallicity);
+  let luminosity_factor = metallicity_to_luminosity_factor(metallicity);
+  trace_var!(luminosity_factor);
+  let temperature_factor = metallicity_to_temperature_factor(metallicity);
+  trace_var!(temperature_factor);
+  let result = luminosity_factor.sqrt() / temperature_factor.powf(2.0);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_metallicity_factors() {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    assert_approx_eq!(metallicity_to_luminosity_factor(0.0), 1.0);
+    assert_approx_eq!(metallicity_to_temperature_factor(0.0), 1.0);
+    assert_approx_eq!(metallicity_to_radius_factor(0.0), 1.0);
+    // Metal-poor stars are brighter and hotter.
+    assert!(metallicity_to_luminosity_factor(-0.5) > 1.0);
+    assert!(metallicity_to_temperature_factor(-0.5) > 1.0);
+    for _ in 0..100 {
+      let metallicity = get_random_metallicity(&mut rng, MEAN_METALLICITY);
+      assert!((MINIMUM_METALLICITY..=MAXIMUM_METALLICITY).contains(&metallicity));
+    }
+    trace_exit!();
+  }
+}
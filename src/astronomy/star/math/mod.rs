@@ -1,9 +1,14 @@
+pub mod activity;
 pub mod color;
 pub mod distance;
+pub mod evolution;
 pub mod frost_line;
 pub mod habitable_zone;
 pub mod luminosity;
+pub mod magnitude;
 pub mod mass;
+pub mod metallicity;
+pub mod multiplicity;
 pub mod radius;
 pub mod satellite_zone;
 pub mod spectral_class;
@@ -0,0 +1,51 @@
+/// Get the multiplicity fraction for a primary of the specified mass.
+///
+/// This is the fraction of systems with a primary of this mass that have at
+/// least one stellar companion.  It climbs steeply with mass; most M dwarfs
+/// are single, whereas nearly every O star has a companion or two.
+///
+/// These figures are lifted from Duchêne & Kraus (2013).
+///
+/// Mass is measured in Msol.
+#[named]
+pub fn get_multiplicity_fraction(mass: f64) -> f64 {
+  trace_enter!();
+  trace_var!(mass);
+  let result = match mass {
+    mass if mass < 0.1 => 0.22,
+    mass if mass < 0.5 => 0.26,
+    mass if mass < 0.7 => 0.37,
+    mass if mass < 1.3 => 0.44,
+    mass if mass < 5.0 => 0.50,
+    mass if mass < 16.0 => 0.60,
+    _ => 0.80,
+  };
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_multiplicity_fraction() {
+    init();
+    trace_enter!();
+    assert_approx_eq!(get_multiplicity_fraction(0.08), 0.22);
+    assert_approx_eq!(get_multiplicity_fraction(0.3), 0.26);
+    assert_approx_eq!(get_multiplicity_fraction(1.0), 0.44);
+    assert_approx_eq!(get_multiplicity_fraction(20.0), 0.80);
+    let mut previous = 0.0;
+    for mass in [0.08, 0.2, 0.6, 1.0, 2.0, 10.0, 50.0].iter() {
+      let fraction = get_multiplicity_fraction(*mass);
+      assert!(fraction >= previous);
+      previous = fraction;
+    }
+    trace_exit!();
+  }
+}
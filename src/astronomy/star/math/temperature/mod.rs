@@ -22,6 +22,29 @@ pub fn star_mass_to_temperature(mass: f64) -> Result<f64, Error> {
   Ok(result)
 }
 
+/// Get the Msol of a main-sequence star based on its temperature in Kelvin.
+///
+/// There's no neat inverse of the above, so we just bisect.  Temperatures
+/// beyond what a main-sequence star can reach are clamped to the extremes.
+#[named]
+pub fn star_temperature_to_mass(temperature: f64) -> Result<f64, Error> {
+  trace_enter!();
+  trace_var!(temperature);
+  let mut minimum_mass = 1.001 * MINIMUM_MASS;
+  let mut maximum_mass = 0.999 * MAXIMUM_MASS;
+  for _ in 0..64 {
+    let mass = (minimum_mass + maximum_mass) / 2.0;
+    match star_mass_to_temperature(mass)? < temperature {
+      true => minimum_mass = mass,
+      false => maximum_mass = mass,
+    }
+  }
+  let result = (minimum_mass + maximum_mass) / 2.0;
+  trace_var!(result);
+  trace_exit!();
+  Ok(result)
+}
+
 #[cfg(test)]
 pub mod test {
 
@@ -76,4 +99,21 @@ pub mod test {
     trace_exit!();
     Ok(())
   }
+
+  #[named]
+  #[test]
+  pub fn test_star_temperature_to_mass() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    for mass in [0.1, 0.4, 1.0, 1.7, 8.0, 25.0] {
+      let temperature = star_mass_to_temperature(mass)?;
+      let actual = star_temperature_to_mass(temperature)?;
+      print_var!(actual);
+      assert_approx_eq!(mass, actual, 0.01 * mass);
+    }
+    assert_approx_eq!(star_temperature_to_mass(1_000.0)?, MINIMUM_MASS, 0.01);
+    assert_approx_eq!(star_temperature_to_mass(1_000_000.0)?, MAXIMUM_MASS, 1.0);
+    trace_exit!();
+    Ok(())
+  }
 }
@@ -5,15 +5,27 @@ use constants::*;
 pub mod constraints;
 pub mod error;
 use error::*;
+pub mod evolutionary_phase;
+use evolutionary_phase::EvolutionaryPhase;
+pub mod luminosity_class;
+use luminosity_class::LuminosityClass;
 pub mod math;
-use math::color::star_mass_to_rgb;
+use math::activity::{get_activity, get_flare_rate, get_xuv_flux, star_mass_and_age_to_rotation_period};
+use math::color::star_temperature_to_rgb;
+use math::evolution::get_evolved_properties;
+use math::habitable_zone::star_luminosity_to_habitable_zone;
 use math::luminosity::star_mass_to_luminosity;
+use math::magnitude::*;
+use math::metallicity::*;
 use math::radius::star_mass_to_radius;
 use math::satellite_zone::{get_approximate_innermost_orbit, get_approximate_outermost_orbit};
-use math::spectral_class::star_mass_to_spectral_class;
 use math::temperature::star_mass_to_temperature;
 pub mod name;
 use name::generate_star_name;
+pub mod spectral_class;
+use spectral_class::SpectralClass;
+pub mod spectral_peculiarities;
+pub mod spectral_type;
 
 /// The `Star` type.
 ///
@@ -23,10 +35,12 @@ use name::generate_star_name;
 /// centrality to our purpose.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Star {
-  /// Type, Decile, Luminosity class.
-  pub class: String,
+  /// Type, subclass, luminosity class, e.g. "G2V".
+  pub class: SpectralClass,
   /// Measured in Msol.
   pub mass: f64,
+  /// Iron abundance relative to the Sun, i.e. [Fe/H], measured in dex.
+  pub metallicity: f64,
   /// Measured in Kelvin.
   pub temperature: f64,
   /// Measured in Rsol.
@@ -51,22 +65,41 @@ pub struct Star {
   pub absolute_rgb: (u8, u8, u8),
   /// A generated name for this star.
   pub name: String,
+  /// Where this star is in its life.
+  pub phase: EvolutionaryPhase,
 }
 
 /// Implementation of Star.
 impl Star {
   /// Generate a random main-sequence star from a given mass.
+  ///
+  /// The star will have solar metallicity.
   #[named]
   pub fn from_mass<R: Rng + ?Sized>(rng: &mut R, mass: f64) -> Result<Star, Error> {
     trace_enter!();
     trace_var!(mass);
-    let temperature = star_mass_to_temperature(mass)?;
+    let result = Star::from_mass_and_metallicity(rng, mass, 0.0)?;
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Generate a random main-sequence star from a given mass and metallicity.
+  ///
+  /// Metallicity is [Fe/H], so 0.0 is solar.  The luminosity, radius, etc are
+  /// those of the star at its (random) current age.
+  #[named]
+  pub fn from_mass_and_metallicity<R: Rng + ?Sized>(rng: &mut R, mass: f64, metallicity: f64) -> Result<Star, Error> {
+    trace_enter!();
+    trace_var!(mass);
+    trace_var!(metallicity);
+    let temperature = star_mass_to_temperature(mass)? * metallicity_to_temperature_factor(metallicity);
     trace_var!(temperature);
-    let luminosity = star_mass_to_luminosity(mass)?;
+    let luminosity = star_mass_to_luminosity(mass)? * metallicity_to_luminosity_factor(metallicity);
     trace_var!(luminosity);
-    let radius = star_mass_to_radius(mass)?;
+    let radius = star_mass_to_radius(mass)? * metallicity_to_radius_factor(metallicity);
     trace_var!(radius);
-    let class = star_mass_to_spectral_class(mass)?;
+    let class = SpectralClass::from_temperature(temperature, Some(LuminosityClass::MainSequence));
     trace_var!(class);
     let life_expectancy = mass / luminosity * 10.0;
     trace_var!(life_expectancy);
@@ -87,13 +120,14 @@ impl Star {
     let satellite_zone = (satellite_inner_bound, satellite_outer_bound);
     let frost_line = 4.85 * luminosity.sqrt();
     trace_var!(frost_line);
-    let absolute_rgb = star_mass_to_rgb(mass)?;
+    let absolute_rgb = star_temperature_to_rgb(temperature);
     trace_3u8!(absolute_rgb);
     let name = generate_star_name(rng);
     trace_var!(name);
     let result = Star {
       class,
       mass,
+      metallicity,
       luminosity,
       radius,
       temperature,
@@ -105,16 +139,199 @@ impl Star {
       frost_line,
       absolute_rgb,
       name,
+      phase: EvolutionaryPhase::MainSequence,
+    };
+    // The values above are typical for the main sequence; this brings them
+    // in line with the star's actual age.
+    let result = result.get_at_age(current_age)?;
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Retrieve or calculate this star as it was (or will be) at a given age.
+  ///
+  /// The mass, name, frost line, etc are left alone; the frost line is set
+  /// when the protoplanetary disk forms, and we don't model mass loss.
+  #[named]
+  pub fn get_at_age(&self, age: f64) -> Result<Star, Error> {
+    trace_enter!();
+    trace_var!(age);
+    let (phase, luminosity, radius, temperature) = get_evolved_properties(self.mass, self.metallicity, age)?;
+    trace_var!(phase);
+    let luminosity_class = LuminosityClass::from_evolutionary_phase(phase, self.mass);
+    let class = SpectralClass::from_temperature(temperature, Some(luminosity_class));
+    trace_var!(class);
+    let density = match radius > 0.0 {
+      true => self.mass / radius.powf(3.0),
+      false => f64::INFINITY,
+    };
+    trace_var!(density);
+    let habitable_zone = star_luminosity_to_habitable_zone(luminosity);
+    trace_var!(habitable_zone);
+    let absolute_rgb = star_temperature_to_rgb(temperature);
+    trace_3u8!(absolute_rgb);
+    let result = Star {
+      class,
+      luminosity,
+      radius,
+      temperature,
+      current_age: age,
+      density,
+      habitable_zone,
+      absolute_rgb,
+      phase,
+      ..self.clone()
     };
     trace_var!(result);
     trace_exit!();
     Ok(result)
   }
 
+  /// Age this star by some number of Gyr (or make it younger, if negative).
+  #[named]
+  pub fn evolve(&mut self, time: f64) -> Result<(), Error> {
+    trace_enter!();
+    trace_var!(time);
+    *self = self.get_at_age(self.current_age + time)?;
+    trace_var!(self);
+    trace_exit!();
+    Ok(())
+  }
+
+  /// Retrieve or calculate the habitable zone at a given age, in AU.
+  #[named]
+  pub fn get_habitable_zone_at_age(&self, age: f64) -> Result<(f64, f64), Error> {
+    trace_enter!();
+    trace_var!(age);
+    let result = self.get_at_age(age)?.habitable_zone;
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Retrieve or calculate the rotation period, in days.
+  ///
+  /// Stars spin down as they age, so this isn't fixed.
+  #[named]
+  pub fn get_rotation_period(&self) -> f64 {
+    trace_enter!();
+    let result = star_mass_and_age_to_rotation_period(self.mass, self.current_age);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the activity level, i.e. the fraction of the
+  /// luminosity emitted as X-rays and extreme UV.
+  #[named]
+  pub fn get_activity(&self) -> f64 {
+    trace_enter!();
+    let result = get_activity(self.get_rotation_period(), self.mass);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the XUV luminosity, in Lsol.
+  #[named]
+  pub fn get_xuv_luminosity(&self) -> f64 {
+    trace_enter!();
+    let result = self.get_activity() * self.luminosity;
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the XUV flux at a distance in AU, in erg/cm²/s.
+  #[named]
+  pub fn get_xuv_flux(&self, distance: f64) -> f64 {
+    trace_enter!();
+    trace_var!(distance);
+    let result = get_xuv_flux(self.get_xuv_luminosity(), distance);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the rate of flares at or above an energy in ergs,
+  /// in flares per year.
+  #[named]
+  pub fn get_flare_rate(&self, energy: f64) -> f64 {
+    trace_enter!();
+    trace_var!(energy);
+    let result = get_flare_rate(self.get_activity(), energy);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the absolute bolometric magnitude.
+  #[named]
+  pub fn get_absolute_bolometric_magnitude(&self) -> f64 {
+    trace_enter!();
+    let result = star_luminosity_to_absolute_bolometric_magnitude(self.luminosity);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the bolometric correction, i.e. how much brighter
+  /// the star is over all wavelengths than in visible light.
+  #[named]
+  pub fn get_bolometric_correction(&self) -> f64 {
+    trace_enter!();
+    let result = star_temperature_to_bolometric_correction(self.temperature);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the absolute visual (V band) magnitude.
+  #[named]
+  pub fn get_absolute_visual_magnitude(&self) -> f64 {
+    trace_enter!();
+    let result = star_luminosity_and_temperature_to_absolute_visual_magnitude(self.luminosity, self.temperature);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the B-V color index.
+  ///
+  /// Bigger is redder.
+  #[named]
+  pub fn get_color_index(&self) -> f64 {
+    trace_enter!();
+    let result = star_temperature_to_color_index(self.temperature);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the apparent visual magnitude at a distance in
+  /// light years.
+  ///
+  /// Extinction is in magnitudes; if it isn't given, we estimate it from the
+  /// distance.
+  #[named]
+  pub fn get_apparent_magnitude(&self, distance: f64, extinction: Option<f64>) -> f64 {
+    trace_enter!();
+    trace_var!(distance);
+    trace_var!(extinction);
+    let result = get_apparent_magnitude(self.get_absolute_visual_magnitude(), distance, extinction);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
   /// Indicate whether this star is capable of supporting conventional life.
   #[named]
   pub fn check_habitable(&self) -> Result<(), Error> {
     trace_enter!();
+    if self.phase != EvolutionaryPhase::MainSequence {
+      return Err(Error::NotOnMainSequence);
+    }
     if self.mass < MINIMUM_HABITABLE_MASS {
       return Err(Error::MassTooLowToSupportLife);
     }
@@ -164,4 +381,56 @@ pub mod test {
     trace_exit!();
     Ok(())
   }
+
+  #[named]
+  #[test]
+  pub fn test_get_at_age() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let mut sun = Star::from_mass(&mut rng, 1.0)?;
+    let luminosity = sun.luminosity;
+    assert_approx_eq!(luminosity, sun.get_at_age(sun.current_age)?.luminosity);
+    sun = sun.get_at_age(4.6)?;
+    let main_sequence_rgb = sun.absolute_rgb;
+    let past = sun.get_habitable_zone_at_age(sun.current_age - 2.0)?;
+    print_var!(past);
+    let future = sun.get_habitable_zone_at_age(sun.current_age + 3.0)?;
+    print_var!(future);
+    assert!(past.0 < future.0 && past.1 < future.1);
+    sun.evolve(7.0)?;
+    print_var!(sun);
+    assert_eq!(EvolutionaryPhase::RedGiantBranch, sun.phase);
+    assert_eq!(Some(LuminosityClass::Giant), sun.class.luminosity_class);
+    assert!(sun.radius > 10.0);
+    print_var!(sun.absolute_rgb);
+    assert!(sun.absolute_rgb.2 < main_sequence_rgb.2);
+    assert_eq!(Err(Error::NotOnMainSequence), sun.check_habitable());
+    assert_eq!(Err(Error::AgeTooLow), sun.get_at_age(-1.0));
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_magnitudes() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let sun = Star::from_mass(&mut rng, 1.0)?.get_at_age(SOLAR_AGE)?;
+    let absolute_visual_magnitude = sun.get_absolute_visual_magnitude();
+    print_var!(absolute_visual_magnitude);
+    assert_approx_eq!(absolute_visual_magnitude, 4.83, 0.2);
+    assert_approx_eq!(sun.get_color_index(), 0.65, 0.05);
+    let apparent_magnitude = sun.get_apparent_magnitude(1.58E-5, Some(0.0));
+    print_var!(apparent_magnitude);
+    assert_approx_eq!(apparent_magnitude, -26.74, 0.2);
+    let red_dwarf = Star::from_mass(&mut rng, 0.2)?;
+    assert!(red_dwarf.get_absolute_visual_magnitude() > 10.0);
+    assert!(red_dwarf.get_color_index() > sun.get_color_index());
+    trace_exit!();
+    Ok(())
+  }
 }
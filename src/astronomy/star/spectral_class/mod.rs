@@ -0,0 +1,335 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::astronomy::star::error::Error;
+use crate::astronomy::star::luminosity_class::LuminosityClass;
+use crate::astronomy::star::math::color::star_temperature_to_rgb;
+use crate::astronomy::star::math::luminosity::star_mass_to_luminosity;
+use crate::astronomy::star::math::magnitude::star_luminosity_to_absolute_bolometric_magnitude;
+use crate::astronomy::star::math::temperature::star_temperature_to_mass;
+use crate::astronomy::star::spectral_peculiarities::SpectralPeculiarities;
+use crate::astronomy::star::spectral_type::SpectralType;
+
+/// The `SpectralClass` type.
+///
+/// A full Morgan-Keenan classification, e.g. "K0III" or "B3Vne": spectral
+/// type, subclass, luminosity class, and peculiarities.  Catalogs often leave
+/// out the subclass or the luminosity class, so those are optional.
+///
+/// These sort by type, then subclass, then luminosity class, so hotter and
+/// then brighter stars come first.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct SpectralClass {
+  /// The letter, e.g. O, B, A, F, G, K, M (or L, T, Y for brown dwarfs).
+  pub spectral_type: SpectralType,
+  /// The number after the letter, from 0 (hottest) up to (but not including)
+  /// 10; this can have a decimal, as in "O9.5".
+  pub subclass: Option<f64>,
+  /// The Roman numeral after the number.
+  pub luminosity_class: Option<LuminosityClass>,
+  /// Anything else of note.
+  pub peculiarities: SpectralPeculiarities,
+}
+
+impl SpectralClass {
+  /// Get the spectral class of a star from its temperature in Kelvin and
+  /// (optionally) its luminosity class.
+  ///
+  /// The subclass is rounded to the nearest half, which is about as precise
+  /// as anyone gets by eye.
+  #[named]
+  pub fn from_temperature(temperature: f64, luminosity_class: Option<LuminosityClass>) -> Self {
+    trace_enter!();
+    trace_var!(temperature);
+    trace_var!(luminosity_class);
+    let spectral_type = SpectralType::from_temperature(temperature);
+    trace_var!(spectral_type);
+    let range = spectral_type.get_temperature_range();
+    let subclass = 10.0 * (1.0 - (temperature - range.start) / (range.end - range.start));
+    let subclass = ((2.0 * subclass).round() / 2.0).clamp(0.0, 9.5);
+    trace_var!(subclass);
+    let result = Self {
+      spectral_type,
+      subclass: Some(subclass),
+      luminosity_class,
+      peculiarities: SpectralPeculiarities::default(),
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the effective temperature, in Kelvin.
+  ///
+  /// Without a subclass, we assume the middle of the spectral type.
+  #[named]
+  pub fn get_temperature(&self) -> f64 {
+    trace_enter!();
+    let range = self.spectral_type.get_temperature_range();
+    let subclass = self.subclass.unwrap_or(5.0);
+    trace_var!(subclass);
+    let result = range.end - subclass / 10.0 * (range.end - range.start);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the absolute color in SRGB.
+  #[named]
+  pub fn get_rgb(&self) -> (u8, u8, u8) {
+    trace_enter!();
+    let result = star_temperature_to_rgb(self.get_temperature());
+    trace_3u8!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the typical absolute bolometric magnitude.
+  ///
+  /// We work out how bright a main-sequence star of this temperature would
+  /// be, then let the luminosity class adjust that.  Without a luminosity
+  /// class, we assume a dwarf.
+  #[named]
+  pub fn get_absolute_bolometric_magnitude(&self) -> Result<f64, Error> {
+    trace_enter!();
+    let mass = star_temperature_to_mass(self.get_temperature())?;
+    trace_var!(mass);
+    let luminosity = star_mass_to_luminosity(mass)?;
+    trace_var!(luminosity);
+    let main_sequence_magnitude = star_luminosity_to_absolute_bolometric_magnitude(luminosity);
+    trace_var!(main_sequence_magnitude);
+    let luminosity_class = self.luminosity_class.unwrap_or(LuminosityClass::MainSequence);
+    trace_var!(luminosity_class);
+    let result = luminosity_class.get_absolute_magnitude(main_sequence_magnitude);
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+}
+
+/// Parse a spectral class as found in a catalog, e.g. "K0III", "gG9", or
+/// "A7/8V".
+///
+/// Where a range is given ("K2-3I", "G8III-IV"), we take the first value.
+/// The old Mount Wilson prefixes (d, sd, g, c) stand in for luminosity
+/// classes.  Types we don't generate (carbon stars, Wolf-Rayets, etc) are
+/// rejected.
+impl FromStr for SpectralClass {
+  type Err = Error;
+
+  #[named]
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    trace_enter!();
+    trace_var!(string);
+    let mut rest = string.trim();
+    let prefixes = [
+      ("sd", LuminosityClass::Subdwarf),
+      ("d", LuminosityClass::MainSequence),
+      ("g", LuminosityClass::Giant),
+      ("c", LuminosityClass::Supergiant),
+    ];
+    let mut luminosity_class = None;
+    if let Some(&(prefix, prefix_class)) = prefixes.iter().find(|(prefix, _)| rest.starts_with(prefix)) {
+      luminosity_class = Some(prefix_class);
+      rest = &rest[prefix.len()..];
+    }
+    let spectral_type = SpectralType::from_char(rest.chars().next().ok_or(Error::UnparseableSpectralClass)?)?;
+    trace_var!(spectral_type);
+    rest = &rest[1..];
+    let subclass_length = get_subclass_length(rest);
+    let subclass = match subclass_length {
+      0 => None,
+      length => Some(
+        rest[..length]
+          .parse::<f64>()
+          .map_err(|_| Error::UnparseableSpectralClass)?,
+      ),
+    };
+    trace_var!(subclass);
+    if subclass.is_some_and(|subclass| subclass >= 10.0) {
+      return Err(Error::UnparseableSpectralClass);
+    }
+    rest = &rest[subclass_length..];
+    // A range of subclasses, e.g. "B8-9" or "G8-K0".
+    if let Some(range) = rest.strip_prefix(['-', '/']) {
+      let range = range
+        .strip_prefix(|char: char| SpectralType::from_char(char).is_ok())
+        .unwrap_or(range);
+      let length = get_subclass_length(range);
+      if length > 0 {
+        rest = &range[length..];
+      }
+    }
+    if luminosity_class.is_none() {
+      if let Some((parsed_class, length)) = LuminosityClass::parse_prefix(rest) {
+        luminosity_class = Some(parsed_class);
+        rest = &rest[length..];
+        // Finer divisions, e.g. "Va" or "IIIb", which we don't track.
+        rest = rest.strip_prefix(['a', 'b']).unwrap_or(rest);
+        // A range of luminosity classes, e.g. "III-IV" or "IV/V".
+        if let Some(range) = rest.strip_prefix(['-', '/']) {
+          if let Some((_, length)) = LuminosityClass::parse_prefix(range) {
+            rest = &range[length..];
+          }
+        }
+      }
+    }
+    trace_var!(luminosity_class);
+    // A trailing qualifier, e.g. "K3Ib-", which we don't track either.
+    rest = rest.strip_prefix('-').unwrap_or(rest);
+    let peculiarities = SpectralPeculiarities::parse(rest);
+    trace_var!(peculiarities);
+    let result = Self {
+      spectral_type,
+      subclass,
+      luminosity_class,
+      peculiarities,
+    };
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+}
+
+/// Get the length of the subclass, e.g. "9.5", at the start of a string.
+fn get_subclass_length(string: &str) -> usize {
+  let digits = string.find(|char: char| !char.is_ascii_digit()).unwrap_or(string.len());
+  match digits > 0 && string[digits..].starts_with('.') {
+    true => {
+      let decimals = string[digits + 1..]
+        .find(|char: char| !char.is_ascii_digit())
+        .unwrap_or(string.len() - digits - 1);
+      match decimals {
+        0 => digits,
+        decimals => digits + 1 + decimals,
+      }
+    },
+    false => digits,
+  }
+}
+
+impl fmt::Display for SpectralClass {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.spectral_type)?;
+    if let Some(subclass) = self.subclass {
+      write!(f, "{}", subclass)?;
+    }
+    if let Some(luminosity_class) = self.luminosity_class {
+      write!(f, "{}", luminosity_class)?;
+    }
+    write!(f, "{}", self.peculiarities)
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_from_str() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let class: SpectralClass = "K0III".parse()?;
+    print_var!(class);
+    assert_eq!(SpectralType::K, class.spectral_type);
+    assert_eq!(Some(0.0), class.subclass);
+    assert_eq!(Some(LuminosityClass::Giant), class.luminosity_class);
+    let class: SpectralClass = "gG9".parse()?;
+    assert_eq!("G9III", class.to_string());
+    let class: SpectralClass = "O9.5Ia".parse()?;
+    assert_eq!(Some(9.5), class.subclass);
+    assert_eq!(Some(LuminosityClass::LuminousSupergiant), class.luminosity_class);
+    let class: SpectralClass = "K2-3I".parse()?;
+    assert_eq!("K2Iab", class.to_string());
+    let class: SpectralClass = "G8-K0".parse()?;
+    assert_eq!("G8", class.to_string());
+    let class: SpectralClass = "A7/8V".parse()?;
+    assert_eq!("A7V", class.to_string());
+    let class: SpectralClass = "G8III-IV".parse()?;
+    assert_eq!("G8III", class.to_string());
+    let class: SpectralClass = "F5Va".parse()?;
+    assert_eq!("F5V", class.to_string());
+    let class: SpectralClass = "K3Ib+".parse()?;
+    assert_eq!("K3Ib", class.to_string());
+    let class: SpectralClass = "B3Vne".parse()?;
+    assert!(class.peculiarities.broad_lines && class.peculiarities.emission);
+    let class: SpectralClass = "A3She".parse()?;
+    assert!(class.peculiarities.shell && class.peculiarities.emission);
+    let class: SpectralClass = "B9pSi".parse()?;
+    assert_eq!("B9p", class.to_string());
+    let class: SpectralClass = "A0V+A".parse()?;
+    assert!(class.peculiarities.composite);
+    let class: SpectralClass = "F0V:".parse()?;
+    assert!(class.peculiarities.uncertain);
+    let class: SpectralClass = "dK..".parse()?;
+    assert_eq!("KV", class.to_string());
+    let class: SpectralClass = "sdF5".parse()?;
+    assert_eq!(Some(LuminosityClass::Subdwarf), class.luminosity_class);
+    for string in ["A1Vn", "A6m", "M1", "B8IVnsh:", "G5IIIvar", "O9.5Ia0"] {
+      let class: SpectralClass = string.parse()?;
+      print_var!(class);
+      assert_eq!(class, class.to_string().parse()?);
+    }
+    for string in ["", "C5", "S", "N3", "WC8", "K12V", "pec"] {
+      assert_eq!(Err(Error::UnparseableSpectralClass), string.parse::<SpectralClass>());
+    }
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_ordering() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut classes = ["M5V", "G2V", "O9.5Ia", "G2III", "B0V", "O5V", "K0III"]
+      .iter()
+      .map(|string| string.parse::<SpectralClass>())
+      .collect::<Result<Vec<_>, _>>()?;
+    classes.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let strings = classes.iter().map(ToString::to_string).collect::<Vec<_>>();
+    print_var!(strings);
+    assert_eq!(vec!["O5V", "O9.5Ia", "B0V", "G2III", "G2V", "K0III", "M5V"], strings);
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_conversions() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let sun = SpectralClass::from_temperature(5_776.0, Some(LuminosityClass::MainSequence));
+    print_var!(sun);
+    assert_eq!("G3V", sun.to_string());
+    assert_approx_eq!(sun.get_temperature(), 5_776.0, 50.0);
+    assert_approx_eq!(sun.get_absolute_bolometric_magnitude()?, 4.74, 0.5);
+    let rgb = sun.get_rgb();
+    assert!(rgb.0 > 200 && rgb.1 > 200);
+    let arcturus: SpectralClass = "K1.5III".parse()?;
+    assert!(arcturus.get_absolute_bolometric_magnitude()? < sun.get_absolute_bolometric_magnitude()?);
+    let deneb: SpectralClass = "A2Ia".parse()?;
+    assert!(deneb.get_absolute_bolometric_magnitude()? < arcturus.get_absolute_bolometric_magnitude()?);
+    for temperature in [2_500.0, 3_000.0, 4_500.0, 6_500.0, 9_000.0, 20_000.0, 40_000.0] {
+      let class = SpectralClass::from_temperature(temperature, None);
+      print_var!(class);
+      assert_approx_eq!(class.get_temperature(), temperature, 0.05 * temperature);
+    }
+    assert_eq!(
+      SpectralType::M,
+      SpectralClass::from_temperature(1_000.0, None).spectral_type
+    );
+    assert_eq!(
+      SpectralType::O,
+      SpectralClass::from_temperature(200_000.0, None).spectral_type
+    );
+    let brown_dwarf: SpectralClass = "T6.5".parse()?;
+    assert_eq!(SpectralType::T, brown_dwarf.spectral_type);
+    assert_eq!("T6.5", brown_dwarf.to_string());
+    trace_exit!();
+    Ok(())
+  }
+}
@@ -0,0 +1,98 @@
+use std::fmt;
+
+/// The `SpectralPeculiarities` type.
+///
+/// The odds and ends that trail a spectral class, i.e. the "ne" in "B3Vne".
+/// None of these change what kind of star we're looking at, but they're
+/// worth keeping around when we read a catalog.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SpectralPeculiarities {
+  /// m: enhanced metallic lines, i.e. an Am star.
+  pub metallic_lines: bool,
+  /// p: peculiar, usually with some element (Si, Sr, Cr, Eu...) overabundant.
+  pub peculiar: bool,
+  /// n or nn: broad, diffuse lines, from rapid rotation.
+  pub broad_lines: bool,
+  /// sh: a shell of gas around the star.
+  pub shell: bool,
+  /// s: sharp, narrow lines.
+  pub sharp_lines: bool,
+  /// e: emission lines.
+  pub emission: bool,
+  /// v or var: variable spectral features.
+  pub variable: bool,
+  /// :, the classifier wasn't sure.
+  pub uncertain: bool,
+  /// +, another star's spectrum is mixed in, e.g. "A0V+A".
+  pub composite: bool,
+}
+
+impl SpectralPeculiarities {
+  /// Parse peculiarities from the tail end of a spectral class.
+  ///
+  /// Anything we don't recognize is skipped over; catalogs are messy, and
+  /// many of their entries are truncated.
+  #[named]
+  pub fn parse(string: &str) -> Self {
+    trace_enter!();
+    trace_var!(string);
+    let mut result = Self::default();
+    let mut rest = string;
+    while let Some(char) = rest.chars().next() {
+      let mut length = char.len_utf8();
+      let tail = &rest[length..];
+      match char {
+        'S' | 's' if tail.starts_with('h') => {
+          result.shell = true;
+          length = 2;
+        },
+        'v' if rest.starts_with("var") => {
+          result.variable = true;
+          length = 3;
+        },
+        'm' => result.metallic_lines = true,
+        'p' => result.peculiar = true,
+        'n' => result.broad_lines = true,
+        's' => result.sharp_lines = true,
+        'e' => result.emission = true,
+        'v' => result.variable = true,
+        ':' => result.uncertain = true,
+        // Whatever follows describes the companion, not this star.
+        '+' if !tail.is_empty() => {
+          result.composite = true;
+          break;
+        },
+        // An element symbol after a "p", e.g. "Si" or "Eu".
+        'A'..='Z' if tail.starts_with(|next: char| next.is_ascii_lowercase()) => length = 2,
+        _ => {},
+      }
+      rest = &rest[length..];
+    }
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
+
+/// We don't know who the companion of a composite spectrum is, so we leave
+/// that off.
+impl fmt::Display for SpectralPeculiarities {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let flags = [
+      (self.metallic_lines, "m"),
+      (self.peculiar, "p"),
+      (self.broad_lines, "n"),
+      (self.shell, "sh"),
+      (self.sharp_lines, "s"),
+      (self.emission, "e"),
+      (self.variable, "v"),
+      (self.uncertain, ":"),
+    ];
+    for (flag, suffix) in flags {
+      if flag {
+        write!(f, "{}", suffix)?;
+      }
+    }
+    Ok(())
+  }
+}
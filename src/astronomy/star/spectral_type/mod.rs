@@ -0,0 +1,134 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::astronomy::star::error::Error;
+
+/// The `SpectralType` type.
+///
+/// The letter at the front of a spectral class, i.e. the "O" in "O9.5Ia".
+/// These run from hottest to coolest, which is also the order astronomers
+/// call "early" to "late", so that's how they sort.
+///
+/// L, T, and Y are brown dwarfs rather than stars, but they share the
+/// sequence.  Carbon (C, R, N), S-type, and Wolf-Rayet stars are out there,
+/// but we don't generate them.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SpectralType {
+  /// Blue, hot, and short-lived.
+  O,
+  /// Blue-white.
+  B,
+  /// White, e.g. Sirius or Vega.
+  A,
+  /// Yellow-white.
+  F,
+  /// Yellow, e.g. the Sun.
+  G,
+  /// Orange.
+  K,
+  /// Red, small, cool, and very, very common.
+  M,
+  /// Dusty brown dwarfs, and a few of the very smallest stars.
+  L,
+  /// Methane brown dwarfs.
+  T,
+  /// Brown dwarfs cool enough for water clouds.
+  Y,
+}
+
+impl SpectralType {
+  /// Parse a spectral type from its letter.
+  #[named]
+  pub fn from_char(char: char) -> Result<Self, Error> {
+    trace_enter!();
+    trace_var!(char);
+    use SpectralType::*;
+    let result = match char {
+      'O' => Ok(O),
+      'B' => Ok(B),
+      'A' => Ok(A),
+      'F' => Ok(F),
+      'G' => Ok(G),
+      'K' => Ok(K),
+      'M' => Ok(M),
+      'L' => Ok(L),
+      'T' => Ok(T),
+      'Y' => Ok(Y),
+      _ => Err(Error::UnparseableSpectralClass),
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Get the spectral type of a star from its temperature in Kelvin.
+  ///
+  /// Temperatures outside the range we know about are clamped to O or M;
+  /// we never hand out L, T, or Y, since brown dwarfs work these out for
+  /// themselves.
+  #[named]
+  pub fn from_temperature(temperature: f64) -> Self {
+    trace_enter!();
+    trace_var!(temperature);
+    use SpectralType::*;
+    let result = [M, K, G, F, A, B]
+      .into_iter()
+      .find(|spectral_type| temperature < spectral_type.get_temperature_range().end)
+      .unwrap_or(O);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve the letter for this spectral type.
+  #[named]
+  pub fn to_char(&self) -> char {
+    trace_enter!();
+    use SpectralType::*;
+    let result = match self {
+      O => 'O',
+      B => 'B',
+      A => 'A',
+      F => 'F',
+      G => 'G',
+      K => 'K',
+      M => 'M',
+      L => 'L',
+      T => 'T',
+      Y => 'Y',
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve the range of effective temperatures, in Kelvin.
+  ///
+  /// Subclass 0 sits at the hot end and subclass 10 at the cool end.
+  #[named]
+  pub fn get_temperature_range(&self) -> Range<f64> {
+    trace_enter!();
+    use SpectralType::*;
+    let result = match self {
+      O => 33_000.0..95_000.0,
+      B => 10_000.0..33_000.0,
+      A => 7_500.0..10_000.0,
+      F => 6_000.0..7_500.0,
+      G => 5_200.0..6_000.0,
+      K => 3_700.0..5_200.0,
+      M => 2_000.0..3_700.0,
+      L => 1_300.0..2_250.0,
+      T => 500.0..1_300.0,
+      Y => 250.0..500.0,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
+
+impl fmt::Display for SpectralType {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.to_char())
+  }
+}
@@ -16,6 +16,8 @@ pub struct Constraints {
   pub distant_binary_star_constraints: Option<DistantBinaryStarConstraints>,
   /// Planetary System constraints.
   pub planetary_system_constraints: Option<PlanetarySystemConstraints>,
+  /// The metallicity ([Fe/H]) of the stars.
+  pub metallicity: Option<f64>,
 }
 
 impl Constraints {
@@ -45,12 +47,20 @@ impl Constraints {
       let planetary_system_constraints = self
         .planetary_system_constraints
         .unwrap_or(PlanetarySystemConstraints::default());
+      let planetary_system_constraints = PlanetarySystemConstraints {
+        metallicity: self.metallicity.or(planetary_system_constraints.metallicity),
+        ..planetary_system_constraints
+      };
       trace_var!(planetary_system_constraints);
       result = PlanetarySystem(planetary_system_constraints.generate(rng)?);
     } else {
       let distant_binary_star_constraints = self
         .distant_binary_star_constraints
         .unwrap_or(DistantBinaryStarConstraints::default());
+      let distant_binary_star_constraints = DistantBinaryStarConstraints {
+        metallicity: self.metallicity.or(distant_binary_star_constraints.metallicity),
+        ..distant_binary_star_constraints
+      };
       trace_var!(distant_binary_star_constraints);
       result = DistantBinaryStar(distant_binary_star_constraints.generate(rng)?);
     }
@@ -66,10 +76,12 @@ impl Default for Constraints {
     let distant_binary_probability = Some(DISTANT_BINARY_PROBABILITY);
     let distant_binary_star_constraints = None;
     let planetary_system_constraints = None;
+    let metallicity = None;
     Self {
       distant_binary_probability,
       distant_binary_star_constraints,
       planetary_system_constraints,
+      metallicity,
     }
   }
 }
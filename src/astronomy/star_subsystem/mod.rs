@@ -61,6 +61,21 @@ impl StarSubsystem {
     result
   }
 
+  /// Retrieve or calculate the absolute visual (V band) magnitude of the
+  /// stars.
+  #[named]
+  pub fn get_absolute_visual_magnitude(&self) -> f64 {
+    trace_enter!();
+    use StarSubsystem::*;
+    let result = match &self {
+      DistantBinaryStar(distant_binary_star) => distant_binary_star.get_absolute_visual_magnitude(),
+      PlanetarySystem(planetary_system) => planetary_system.get_absolute_visual_magnitude(),
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
   /// Retrieve or calculate the total number of stars in the system.
   #[named]
   pub fn get_stellar_count(&self) -> u8 {
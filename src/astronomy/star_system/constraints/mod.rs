@@ -1,8 +1,15 @@
 use rand::prelude::*;
 
+use crate::astronomy::close_binary_star::constants::{MAXIMUM_COMBINED_MASS, MINIMUM_COMBINED_MASS};
+use crate::astronomy::close_binary_star::constraints::Constraints as CloseBinaryStarConstraints;
+use crate::astronomy::distant_binary_star::constraints::Constraints as DistantBinaryStarConstraints;
+use crate::astronomy::host_star::constraints::Constraints as HostStarConstraints;
+use crate::astronomy::planetary_system::constraints::Constraints as PlanetarySystemConstraints;
+use crate::astronomy::star::constraints::Constraints as StarConstraints;
 use crate::astronomy::star_subsystem::constraints::Constraints as StarSubsystemConstraints;
 use crate::astronomy::star_system::error::Error;
 use crate::astronomy::star_system::StarSystem;
+use crate::astronomy::stellar_remnant::constraints::Constraints as StellarRemnantConstraints;
 
 /// Constraints for creating a star system.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -11,6 +18,8 @@ pub struct Constraints {
   pub star_subsystem_constraints: Option<StarSubsystemConstraints>,
   /// Number of times to regenerate if requirements aren't met.
   pub retries: Option<u8>,
+  /// The metallicity ([Fe/H]) of the system.
+  pub metallicity: Option<f64>,
 }
 
 impl Constraints {
@@ -21,6 +30,7 @@ impl Constraints {
     Self {
       star_subsystem_constraints,
       retries,
+      ..Constraints::default()
     }
   }
 
@@ -31,6 +41,7 @@ impl Constraints {
     Self {
       star_subsystem_constraints,
       retries,
+      ..Constraints::default()
     }
   }
 
@@ -41,6 +52,7 @@ impl Constraints {
     Self {
       star_subsystem_constraints,
       retries,
+      ..Constraints::default()
     }
   }
 
@@ -51,9 +63,216 @@ impl Constraints {
     Self {
       star_subsystem_constraints,
       retries,
+      ..Constraints::default()
     }
   }
 
+  /// Generate a star system with a single star of (roughly) the specified
+  /// mass, in Msol.
+  #[named]
+  pub fn solitary(mass: f64) -> Self {
+    trace_enter!();
+    trace_var!(mass);
+    let planetary_system_constraints = Some(PlanetarySystemConstraints {
+      host_star_constraints: Some(get_solitary_host_star_constraints(mass)),
+      ..PlanetarySystemConstraints::default()
+    });
+    let star_subsystem_constraints = Some(StarSubsystemConstraints {
+      distant_binary_probability: Some(0.0),
+      planetary_system_constraints,
+      ..StarSubsystemConstraints::default()
+    });
+    let result = Self {
+      star_subsystem_constraints,
+      ..Constraints::default()
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Generate a star system consisting of a close binary star, the primary
+  /// of which has (roughly) the specified mass, in Msol.
+  ///
+  /// The close binary generator picks the combined mass first and splits it
+  /// afterward, so this is only approximate; we aim for a mass ratio between
+  /// about 0.2 and 0.9.
+  #[named]
+  pub fn close_binary(primary_mass: f64) -> Self {
+    trace_enter!();
+    trace_var!(primary_mass);
+    let maximum_combined_mass = (1.9 * primary_mass).clamp(1.1 * MINIMUM_COMBINED_MASS, MAXIMUM_COMBINED_MASS);
+    trace_var!(maximum_combined_mass);
+    let minimum_combined_mass = (1.2 * primary_mass)
+      .max(MINIMUM_COMBINED_MASS)
+      .min(0.9 * maximum_combined_mass);
+    trace_var!(minimum_combined_mass);
+    let close_binary_star_constraints = Some(CloseBinaryStarConstraints {
+      minimum_combined_mass: Some(minimum_combined_mass),
+      maximum_combined_mass: Some(maximum_combined_mass),
+      ..CloseBinaryStarConstraints::default()
+    });
+    let host_star_constraints = Some(HostStarConstraints {
+      binary_probability: Some(1.0),
+      close_binary_star_constraints,
+      ..HostStarConstraints::default()
+    });
+    let planetary_system_constraints = Some(PlanetarySystemConstraints {
+      host_star_constraints,
+      ..PlanetarySystemConstraints::default()
+    });
+    let star_subsystem_constraints = Some(StarSubsystemConstraints {
+      distant_binary_probability: Some(0.0),
+      planetary_system_constraints,
+      ..StarSubsystemConstraints::default()
+    });
+    let result = Self {
+      star_subsystem_constraints,
+      ..Constraints::default()
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Generate a star system consisting of a distant binary star, each member
+  /// of which is a single star of (roughly) the specified mass, in Msol.
+  #[named]
+  pub fn distant_binary(primary_mass: f64, secondary_mass: f64) -> Self {
+    trace_enter!();
+    trace_var!(primary_mass);
+    trace_var!(secondary_mass);
+    let primary_constraints = Some(PlanetarySystemConstraints {
+      host_star_constraints: Some(get_solitary_host_star_constraints(primary_mass)),
+      ..PlanetarySystemConstraints::default()
+    });
+    let secondary_constraints = Some(PlanetarySystemConstraints {
+      host_star_constraints: Some(get_solitary_host_star_constraints(secondary_mass)),
+      ..PlanetarySystemConstraints::default()
+    });
+    let distant_binary_star_constraints = Some(DistantBinaryStarConstraints {
+      primary_constraints,
+      secondary_constraints,
+      ..DistantBinaryStarConstraints::default()
+    });
+    let star_subsystem_constraints = Some(StarSubsystemConstraints {
+      distant_binary_probability: Some(1.0),
+      distant_binary_star_constraints,
+      ..StarSubsystemConstraints::default()
+    });
+    let result = Self {
+      star_subsystem_constraints,
+      ..Constraints::default()
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Generate a star system around a lone stellar remnant, or a star with a
+  /// remnant companion.
+  #[named]
+  pub fn remnant() -> Self {
+    trace_enter!();
+    let host_star_constraints = Some(HostStarConstraints {
+      remnant_probability: Some(1.0),
+      ..HostStarConstraints::default()
+    });
+    let planetary_system_constraints = Some(PlanetarySystemConstraints {
+      host_star_constraints,
+      ..PlanetarySystemConstraints::default()
+    });
+    let star_subsystem_constraints = Some(StarSubsystemConstraints {
+      distant_binary_probability: Some(0.0),
+      planetary_system_constraints,
+      ..StarSubsystemConstraints::default()
+    });
+    let result = Self {
+      star_subsystem_constraints,
+      ..Constraints::default()
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Generate a star system around a lone white dwarf.
+  #[named]
+  pub fn white_dwarf() -> Self {
+    trace_enter!();
+    let planetary_system_constraints = Some(PlanetarySystemConstraints {
+      host_star_constraints: Some(get_white_dwarf_host_star_constraints()),
+      ..PlanetarySystemConstraints::default()
+    });
+    let star_subsystem_constraints = Some(StarSubsystemConstraints {
+      distant_binary_probability: Some(0.0),
+      planetary_system_constraints,
+      ..StarSubsystemConstraints::default()
+    });
+    let result = Self {
+      star_subsystem_constraints,
+      ..Constraints::default()
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Generate a lone brown dwarf, possibly with a few planets of its own.
+  #[named]
+  pub fn brown_dwarf() -> Self {
+    trace_enter!();
+    let planetary_system_constraints = Some(PlanetarySystemConstraints {
+      host_star_constraints: Some(get_brown_dwarf_host_star_constraints()),
+      ..PlanetarySystemConstraints::default()
+    });
+    let star_subsystem_constraints = Some(StarSubsystemConstraints {
+      distant_binary_probability: Some(0.0),
+      planetary_system_constraints,
+      ..StarSubsystemConstraints::default()
+    });
+    let result = Self {
+      star_subsystem_constraints,
+      ..Constraints::default()
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Generate a star of (roughly) the specified mass, in Msol, with a brown
+  /// dwarf as a distant companion.
+  #[named]
+  pub fn brown_dwarf_companion(primary_mass: f64) -> Self {
+    trace_enter!();
+    trace_var!(primary_mass);
+    let primary_constraints = Some(PlanetarySystemConstraints {
+      host_star_constraints: Some(get_solitary_host_star_constraints(primary_mass)),
+      ..PlanetarySystemConstraints::default()
+    });
+    let secondary_constraints = Some(PlanetarySystemConstraints {
+      host_star_constraints: Some(get_brown_dwarf_host_star_constraints()),
+      ..PlanetarySystemConstraints::default()
+    });
+    let distant_binary_star_constraints = Some(DistantBinaryStarConstraints {
+      primary_constraints,
+      secondary_constraints,
+      ..DistantBinaryStarConstraints::default()
+    });
+    let star_subsystem_constraints = Some(StarSubsystemConstraints {
+      distant_binary_probability: Some(1.0),
+      distant_binary_star_constraints,
+      ..StarSubsystemConstraints::default()
+    });
+    let result = Self {
+      star_subsystem_constraints,
+      ..Constraints::default()
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
   /// Generate a random star system with the specified constraints.
   ///
   /// This may or may not be habitable.
@@ -63,6 +282,10 @@ impl Constraints {
     let star_subsystem_constraints = self
       .star_subsystem_constraints
       .unwrap_or(StarSubsystemConstraints::default());
+    let star_subsystem_constraints = StarSubsystemConstraints {
+      metallicity: self.metallicity.or(star_subsystem_constraints.metallicity),
+      ..star_subsystem_constraints
+    };
     let star_subsystem = {
       let mut retries = self.retries.unwrap_or(10);
       let star_subsystem;
@@ -89,14 +312,65 @@ impl Constraints {
   }
 }
 
+/// Host star constraints for a single star of (roughly) the specified mass.
+#[named]
+fn get_solitary_host_star_constraints(mass: f64) -> HostStarConstraints {
+  trace_enter!();
+  trace_var!(mass);
+  let star_constraints = Some(StarConstraints {
+    minimum_mass: Some(0.999 * mass),
+    maximum_mass: Some(1.001 * mass),
+    ..StarConstraints::default()
+  });
+  let result = HostStarConstraints {
+    binary_probability: Some(0.0),
+    star_constraints,
+    ..HostStarConstraints::default()
+  };
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Host star constraints for a lone white dwarf.
+#[named]
+fn get_white_dwarf_host_star_constraints() -> HostStarConstraints {
+  trace_enter!();
+  let result = HostStarConstraints {
+    binary_probability: Some(0.0),
+    remnant_probability: Some(1.0),
+    stellar_remnant_constraints: Some(StellarRemnantConstraints::white_dwarf()),
+    brown_dwarf_probability: Some(0.0),
+    ..HostStarConstraints::default()
+  };
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Host star constraints for a lone brown dwarf.
+#[named]
+fn get_brown_dwarf_host_star_constraints() -> HostStarConstraints {
+  trace_enter!();
+  let result = HostStarConstraints {
+    brown_dwarf_probability: Some(1.0),
+    ..HostStarConstraints::default()
+  };
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
 impl Default for Constraints {
   /// No constraints, just let it all hang out.
   fn default() -> Self {
     let star_subsystem_constraints = Some(StarSubsystemConstraints::default());
     let retries = None;
+    let metallicity = None;
     Self {
       star_subsystem_constraints,
       retries,
+      metallicity,
     }
   }
 }
@@ -122,4 +396,31 @@ pub mod test {
     trace_exit!();
     Ok(())
   }
+
+  #[named]
+  #[test]
+  pub fn test_multiplicity() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let star_system = Constraints::solitary(0.5).generate(&mut rng)?;
+    trace_var!(star_system);
+    assert_eq!(1, star_system.get_stellar_count());
+    assert!((star_system.get_stellar_mass() - 0.5).abs() < 0.001);
+    let star_system = Constraints::close_binary(0.5).generate(&mut rng)?;
+    trace_var!(star_system);
+    assert_eq!(2, star_system.get_stellar_count());
+    let star_system = Constraints::distant_binary(0.9, 0.3).generate(&mut rng)?;
+    trace_var!(star_system);
+    assert_eq!(2, star_system.get_stellar_count());
+    assert!((star_system.get_stellar_mass() - 1.2).abs() < 0.01);
+    print_var!(star_system);
+    let star_system = Constraints::brown_dwarf_companion(0.9).generate(&mut rng)?;
+    trace_var!(star_system);
+    assert_eq!(2, star_system.get_stellar_count());
+    assert!(star_system.get_stellar_mass() < 0.99);
+    trace_exit!();
+    Ok(())
+  }
 }
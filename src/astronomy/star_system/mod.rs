@@ -37,6 +37,17 @@ impl StarSystem {
     result
   }
 
+  /// Retrieve or calculate the absolute visual (V band) magnitude of the
+  /// stars.
+  #[named]
+  pub fn get_absolute_visual_magnitude(&self) -> f64 {
+    trace_enter!();
+    let result = self.star_subsystem.get_absolute_visual_magnitude();
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
   /// Retrieve or calculate the total number of stars in the system.
   #[named]
   pub fn get_stellar_count(&self) -> u8 {
@@ -0,0 +1,33 @@
+/// The fraction of multiple systems that are close binaries.
+///
+/// Companion separations follow a broad log-normal distribution peaking at
+/// a few tens of AU, so only around a third of them fall within the few AU
+/// that we treat as a close binary; the rest become distant binaries.
+pub const CLOSE_BINARY_FRACTION: f64 = 0.3;
+
+/// The minimum mass ratio of a binary star.
+///
+/// Mass ratios are roughly uniformly distributed above this.
+pub const MINIMUM_MASS_RATIO: f64 = 0.1;
+
+/// The dispersion of space velocities relative to our primary system.
+///
+/// Disk stars near the Sun have a velocity dispersion of roughly 33, 22, and
+/// 18 km/s in the U, V, and W directions; the spread of velocities relative
+/// to another disk star is about √2 times that.  We use x, y, and z for U, V,
+/// and W respectively.
+///
+/// Measured in km/s.
+pub const VELOCITY_DISPERSION: (f64, f64, f64) = (46.0, 31.0, 25.0);
+
+/// The distance traveled in a year at a speed of 1 km/s, in light years.
+///
+/// This is just the speed as a fraction of the speed of light.
+pub const LIGHT_YEARS_PER_YEAR_PER_KM_PER_SECOND: f64 = 1.0 / 299_792.458;
+
+/// The number of light years in a parsec.
+pub const LIGHT_YEARS_PER_PARSEC: f64 = 3.261_56;
+
+/// The tangential velocity, in km/s, of an object one parsec away that has a
+/// proper motion of one arcsecond per year.
+pub const KM_PER_SECOND_PER_ARCSECOND_PER_YEAR_PER_PARSEC: f64 = 4.740_47;
@@ -1,9 +1,15 @@
 use rand::prelude::*;
 use std::default::Default;
 
+use crate::astronomy::star::constants::{MEAN_METALLICITY, MINIMUM_MASS};
+use crate::astronomy::star::math::mass::get_random_stellar_mass;
+use crate::astronomy::star::math::metallicity::get_random_metallicity;
+use crate::astronomy::star::math::multiplicity::get_multiplicity_fraction;
 use crate::astronomy::star_system::constraints::Constraints as StarSystemConstraints;
+use crate::astronomy::stellar_neighbor::constants::*;
 use crate::astronomy::stellar_neighbor::error::Error;
 use crate::astronomy::stellar_neighbor::math::point::get_random_point_in_sphere;
+use crate::astronomy::stellar_neighbor::math::velocity::get_random_space_velocity;
 use crate::astronomy::stellar_neighbor::StellarNeighbor;
 use crate::astronomy::stellar_neighborhood::constants::STELLAR_NEIGHBORHOOD_RADIUS;
 
@@ -14,6 +20,12 @@ pub struct Constraints {
   pub radius: Option<f64>,
   /// Star system constraints.
   pub system_constraints: Option<StarSystemConstraints>,
+  /// Pick the primary mass first and decide whether the system is single or
+  /// multiple based on it, rather than using the system constraints.
+  pub realistic_multiplicity: bool,
+  /// The typical metallicity ([Fe/H]) of the neighborhood; each system is
+  /// scattered around this.
+  pub metallicity: Option<f64>,
 }
 
 impl Constraints {
@@ -29,6 +41,62 @@ impl Constraints {
     }
   }
 
+  /// Generate a stellar remnant, possibly with a surviving companion.
+  #[named]
+  pub fn remnant() -> Self {
+    trace_enter!();
+    let system_constraints = Some(StarSystemConstraints::remnant());
+    let result = Self {
+      system_constraints,
+      ..Constraints::default()
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Generate a lone white dwarf.
+  #[named]
+  pub fn white_dwarf() -> Self {
+    trace_enter!();
+    let system_constraints = Some(StarSystemConstraints::white_dwarf());
+    let result = Self {
+      system_constraints,
+      ..Constraints::default()
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Generate a free-floating brown dwarf.
+  #[named]
+  pub fn brown_dwarf() -> Self {
+    trace_enter!();
+    let system_constraints = Some(StarSystemConstraints::brown_dwarf());
+    let result = Self {
+      system_constraints,
+      ..Constraints::default()
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Generate star systems whose multiplicity depends on the primary mass.
+  #[named]
+  pub fn realistic() -> Self {
+    trace_enter!();
+    let realistic_multiplicity = true;
+    let result = Self {
+      realistic_multiplicity,
+      ..Constraints::default()
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
   /// Generate a random stellar neighborhood with the specified constraints.
   ///
   /// This may or may not be habitable.
@@ -48,14 +116,51 @@ impl Constraints {
     trace_var!(z);
     let coordinates = (x, y, z);
     trace_var!(coordinates);
+    let result = self.generate_at_coordinates(rng, coordinates)?;
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Generate a random stellar neighbor at the specified coordinates.
+  ///
+  /// Each coordinate is measured in light years from the origin.
+  #[named]
+  pub fn generate_at_coordinates<R: Rng + ?Sized>(
+    &self,
+    rng: &mut R,
+    coordinates: (f64, f64, f64),
+  ) -> Result<StellarNeighbor, Error> {
+    trace_enter!();
+    trace_var!(coordinates);
+    let (x, y, z) = coordinates;
     let distance = (x.powf(2.0) + y.powf(2.0) + z.powf(2.0)).sqrt();
-    let system_constraints = self.system_constraints.unwrap_or(StarSystemConstraints::default());
+    trace_var!(distance);
+    let velocity = get_random_space_velocity(rng);
+    trace_var!(velocity);
+    let system_constraints = match self.realistic_multiplicity {
+      true => get_realistic_system_constraints(rng),
+      false => self.system_constraints.unwrap_or_default(),
+    };
+    let mean_metallicity = self.metallicity.unwrap_or(MEAN_METALLICITY);
+    trace_var!(mean_metallicity);
+    let metallicity = match system_constraints.metallicity {
+      Some(metallicity) => metallicity,
+      None => get_random_metallicity(rng, mean_metallicity),
+    };
+    trace_var!(metallicity);
+    let system_constraints = StarSystemConstraints {
+      metallicity: Some(metallicity),
+      ..system_constraints
+    };
+    trace_var!(system_constraints);
     let star_system = system_constraints.generate(rng)?;
     trace_var!(star_system);
     let name = star_system.name.clone();
     let result = StellarNeighbor {
       coordinates,
       star_system,
+      velocity,
       distance,
       name,
     };
@@ -65,14 +170,47 @@ impl Constraints {
   }
 }
 
+/// Pick a primary mass and build star system constraints around it.
+///
+/// The primary is drawn from the usual spectral class weights, then we roll
+/// for a companion against the multiplicity fraction for that mass.
+#[named]
+fn get_realistic_system_constraints<R: Rng + ?Sized>(rng: &mut R) -> StarSystemConstraints {
+  trace_enter!();
+  let primary_mass = get_random_stellar_mass(rng);
+  trace_var!(primary_mass);
+  let multiplicity_fraction = get_multiplicity_fraction(primary_mass);
+  trace_var!(multiplicity_fraction);
+  let is_multiple = rng.gen_range(0.0..1.0) < multiplicity_fraction;
+  trace_var!(is_multiple);
+  let result = match (is_multiple, rng.gen_range(0.0..1.0) < CLOSE_BINARY_FRACTION) {
+    (false, _) => StarSystemConstraints::solitary(primary_mass),
+    (true, true) => StarSystemConstraints::close_binary(primary_mass),
+    (true, false) => {
+      let mass_ratio = rng.gen_range(MINIMUM_MASS_RATIO..1.0);
+      trace_var!(mass_ratio);
+      let secondary_mass = (mass_ratio * primary_mass).max(1.01 * MINIMUM_MASS).min(primary_mass);
+      trace_var!(secondary_mass);
+      StarSystemConstraints::distant_binary(primary_mass, secondary_mass)
+    },
+  };
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
 impl Default for Constraints {
   /// No constraints, just let it all hang out.
   fn default() -> Self {
     let radius = Some(STELLAR_NEIGHBORHOOD_RADIUS);
     let system_constraints = Some(StarSystemConstraints::default());
+    let realistic_multiplicity = false;
+    let metallicity = None;
     Self {
       radius,
       system_constraints,
+      realistic_multiplicity,
+      metallicity,
     }
   }
 }
@@ -83,6 +221,7 @@ pub mod test {
   use rand::prelude::*;
 
   use super::*;
+  use crate::astronomy::stellar_remnant::constants::CHANDRASEKHAR_MASS;
   use crate::test::*;
 
   #[named]
@@ -98,4 +237,80 @@ pub mod test {
     trace_exit!();
     Ok(())
   }
+
+  #[named]
+  #[test]
+  pub fn test_generate_realistic() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    // The fraction we expect, averaged over the primary mass distribution.
+    let expected_fraction = (0..10_000)
+      .map(|_| get_multiplicity_fraction(get_random_stellar_mass(&mut rng)))
+      .sum::<f64>()
+      / 10_000.0;
+    trace_var!(expected_fraction);
+    let mut multiple_count = 0;
+    for _ in 0..200 {
+      let stellar_neighbor = Constraints::realistic().generate_at_coordinates(&mut rng, (3.0, 4.0, 0.0))?;
+      trace_var!(stellar_neighbor);
+      assert_approx_eq!(stellar_neighbor.distance, 5.0);
+      if stellar_neighbor.get_stellar_count() > 1 {
+        multiple_count += 1;
+      }
+    }
+    let actual_fraction = multiple_count as f64 / 200.0;
+    print_var!(expected_fraction);
+    print_var!(actual_fraction);
+    assert_approx_eq!(actual_fraction, expected_fraction, 0.1);
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_generate_remnant() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let stellar_neighbor = Constraints::remnant().generate(&mut rng)?;
+    trace_var!(stellar_neighbor);
+    print_var!(stellar_neighbor);
+    assert!(stellar_neighbor.get_stellar_mass() > 0.0);
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_generate_white_dwarf() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let stellar_neighbor = Constraints::white_dwarf().generate(&mut rng)?;
+    trace_var!(stellar_neighbor);
+    print_var!(stellar_neighbor);
+    assert_eq!(1, stellar_neighbor.get_stellar_count());
+    assert!(stellar_neighbor.get_stellar_mass() <= CHANDRASEKHAR_MASS);
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_generate_brown_dwarf() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let stellar_neighbor = Constraints::brown_dwarf().generate(&mut rng)?;
+    trace_var!(stellar_neighbor);
+    print_var!(stellar_neighbor);
+    assert!(stellar_neighbor.get_stellar_mass() < 0.08);
+    trace_exit!();
+    Ok(())
+  }
 }
@@ -34,6 +34,92 @@ pub fn get_random_point_in_sphere<R: Rng + ?Sized>(rng: &mut R) -> (f64, f64, f6
   result
 }
 
+/// Get the distance between two points.
+///
+/// Both points and the result are measured in light years.
+#[named]
+pub fn get_distance_between_points(a: (f64, f64, f64), b: (f64, f64, f64)) -> f64 {
+  trace_enter!();
+  trace_var!(a);
+  trace_var!(b);
+  let result = ((a.0 - b.0).powf(2.0) + (a.1 - b.1).powf(2.0) + (a.2 - b.2).powf(2.0)).sqrt();
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the position of a point on the sky, as seen from the origin.
+///
+/// Returns (longitude, latitude) in degrees; longitude runs from 0 to 360,
+/// counterclockwise from the x-axis, and latitude from -90 to 90, measured
+/// from the x-y plane.
+#[named]
+pub fn get_sky_position(point: (f64, f64, f64)) -> (f64, f64) {
+  trace_enter!();
+  trace_var!(point);
+  let (x, y, z) = point;
+  let distance = (x.powf(2.0) + y.powf(2.0) + z.powf(2.0)).sqrt();
+  trace_var!(distance);
+  let longitude = y.atan2(x).to_degrees().rem_euclid(360.0);
+  trace_var!(longitude);
+  let latitude = match distance > 0.0 {
+    true => (z / distance).asin().to_degrees(),
+    false => 0.0,
+  };
+  trace_var!(latitude);
+  let result = (longitude, latitude);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Generate a random point in a cube with sides of unit length, centered on
+/// the origin.
+#[named]
+pub fn get_random_point_in_cube<R: Rng + ?Sized>(rng: &mut R) -> (f64, f64, f64) {
+  trace_enter!();
+  let x = rng.gen_range(-0.5..0.5);
+  let y = rng.gen_range(-0.5..0.5);
+  let z = rng.gen_range(-0.5..0.5);
+  let result = (x, y, z);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Generate a random point that keeps its distance from others.
+///
+/// This is simple dart-throwing Poisson-disk sampling: we draw a limited
+/// number of candidate points and accept the first one that is at least the
+/// minimum separation from every existing point.  If none of them make the
+/// cut, the region is probably too crowded.
+///
+/// The points, separation, and result are measured in light years.
+#[named]
+pub fn get_random_separated_point<R, F>(
+  rng: &mut R,
+  get_candidate: F,
+  points: &[(f64, f64, f64)],
+  minimum_separation: f64,
+  attempts: usize,
+) -> Option<(f64, f64, f64)>
+where
+  R: Rng + ?Sized,
+  F: Fn(&mut R) -> (f64, f64, f64),
+{
+  trace_enter!();
+  trace_var!(minimum_separation);
+  trace_var!(attempts);
+  let result = (0..attempts).map(|_| get_candidate(rng)).find(|&candidate| {
+    points
+      .iter()
+      .all(|&point| get_distance_between_points(candidate, point) >= minimum_separation)
+  });
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
 #[cfg(test)]
 pub mod test {
 
@@ -54,4 +140,59 @@ pub mod test {
     print_var!(point);
     trace_exit!();
   }
+
+  #[named]
+  #[test]
+  pub fn test_get_distance_between_points() {
+    init();
+    trace_enter!();
+    let actual = get_distance_between_points((1.0, 2.0, 3.0), (4.0, 6.0, 3.0));
+    trace_var!(actual);
+    assert_approx_eq!(5.0, actual);
+    let actual = get_distance_between_points((1.0, 2.0, 3.0), (1.0, 2.0, 3.0));
+    assert_approx_eq!(0.0, actual);
+    trace_exit!();
+  }
+
+  #[named]
+  #[test]
+  pub fn test_get_sky_position() {
+    init();
+    trace_enter!();
+    let (longitude, latitude) = get_sky_position((0.0, -2.0, 2.0));
+    trace_var!(longitude);
+    trace_var!(latitude);
+    assert_approx_eq!(longitude, 270.0);
+    assert_approx_eq!(latitude, 45.0);
+    trace_exit!();
+  }
+
+  #[named]
+  #[test]
+  pub fn test_get_random_separated_point() {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let get_candidate = |rng: &mut ThreadRng| {
+      let point = get_random_point_in_sphere(rng);
+      (point.0 * 10.0, point.1 * 10.0, point.2 * 10.0)
+    };
+    let mut points = vec![(0.0, 0.0, 0.0)];
+    for _ in 0..50 {
+      if let Some(point) = get_random_separated_point(&mut rng, get_candidate, &points, 1.0, 30) {
+        assert!(get_distance_between_points(point, (0.0, 0.0, 0.0)) <= 10.0);
+        points.push(point);
+      }
+    }
+    for (index, &a) in points.iter().enumerate() {
+      for &b in points.iter().skip(index + 1) {
+        assert!(get_distance_between_points(a, b) >= 1.0);
+      }
+    }
+    let crowded = get_random_separated_point(&mut rng, get_random_point_in_cube, &points[0..1], 5.0, 30);
+    assert_eq!(None, crowded);
+    print_var!(points);
+    trace_exit!();
+  }
 }
@@ -0,0 +1,182 @@
+use rand::prelude::*;
+use rand_distr::{Distribution, Normal};
+
+use crate::astronomy::stellar_neighbor::constants::*;
+
+/// Generate a random space velocity relative to our primary system.
+///
+/// Each component is drawn independently from a normal distribution.
+///
+/// Measured in km/s.
+#[named]
+pub fn get_random_space_velocity<R: Rng + ?Sized>(rng: &mut R) -> (f64, f64, f64) {
+  trace_enter!();
+  let (sigma_x, sigma_y, sigma_z) = VELOCITY_DISPERSION;
+  let x = Normal::new(0.0, sigma_x).unwrap().sample(rng);
+  trace_var!(x);
+  let y = Normal::new(0.0, sigma_y).unwrap().sample(rng);
+  trace_var!(y);
+  let z = Normal::new(0.0, sigma_z).unwrap().sample(rng);
+  trace_var!(z);
+  let result = (x, y, z);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the displacement after traveling at a velocity for some time.
+///
+/// Velocity is measured in km/s, time in years (negative to look backward),
+/// and the result in light years.
+#[named]
+pub fn get_displacement(velocity: (f64, f64, f64), years: f64) -> (f64, f64, f64) {
+  trace_enter!();
+  trace_var!(velocity);
+  trace_var!(years);
+  let factor = years * LIGHT_YEARS_PER_YEAR_PER_KM_PER_SECOND;
+  trace_var!(factor);
+  let result = (velocity.0 * factor, velocity.1 * factor, velocity.2 * factor);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the radial velocity of an object at a point, as seen from the origin.
+///
+/// Positive values are receding, negative approaching.
+///
+/// The point is measured in light years; velocities are measured in km/s.
+#[named]
+pub fn get_radial_velocity(point: (f64, f64, f64), velocity: (f64, f64, f64)) -> f64 {
+  trace_enter!();
+  trace_var!(point);
+  trace_var!(velocity);
+  let distance = (point.0.powf(2.0) + point.1.powf(2.0) + point.2.powf(2.0)).sqrt();
+  trace_var!(distance);
+  let result = match distance > 0.0 {
+    true => (point.0 * velocity.0 + point.1 * velocity.1 + point.2 * velocity.2) / distance,
+    false => 0.0,
+  };
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the tangential velocity of an object at a point, as seen from the
+/// origin.
+///
+/// The point is measured in light years; velocities are measured in km/s.
+#[named]
+pub fn get_tangential_velocity(point: (f64, f64, f64), velocity: (f64, f64, f64)) -> f64 {
+  trace_enter!();
+  trace_var!(point);
+  trace_var!(velocity);
+  let speed = (velocity.0.powf(2.0) + velocity.1.powf(2.0) + velocity.2.powf(2.0)).sqrt();
+  trace_var!(speed);
+  let radial_velocity = get_radial_velocity(point, velocity);
+  trace_var!(radial_velocity);
+  let result = (speed.powf(2.0) - radial_velocity.powf(2.0)).max(0.0).sqrt();
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the proper motion of an object at a point, as seen from the origin.
+///
+/// The point is measured in light years, the velocity in km/s, and the
+/// result in arcseconds per year.
+#[named]
+pub fn get_proper_motion(point: (f64, f64, f64), velocity: (f64, f64, f64)) -> f64 {
+  trace_enter!();
+  trace_var!(point);
+  trace_var!(velocity);
+  let distance = (point.0.powf(2.0) + point.1.powf(2.0) + point.2.powf(2.0)).sqrt() / LIGHT_YEARS_PER_PARSEC;
+  trace_var!(distance);
+  let tangential_velocity = get_tangential_velocity(point, velocity);
+  trace_var!(tangential_velocity);
+  let result = tangential_velocity / (KM_PER_SECOND_PER_ARCSECOND_PER_YEAR_PER_PARSEC * distance);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the time of closest approach to the origin.
+///
+/// Motion is assumed to be in a straight line; over the few million years
+/// that a campaign could conceivably last, that's close enough.
+///
+/// The point is measured in light years, the velocity in km/s, and the
+/// result in years from now (negative if the closest approach has already
+/// happened).
+#[named]
+pub fn get_time_of_closest_approach(point: (f64, f64, f64), velocity: (f64, f64, f64)) -> f64 {
+  trace_enter!();
+  trace_var!(point);
+  trace_var!(velocity);
+  let velocity = get_displacement(velocity, 1.0);
+  trace_var!(velocity);
+  let speed_squared = velocity.0.powf(2.0) + velocity.1.powf(2.0) + velocity.2.powf(2.0);
+  trace_var!(speed_squared);
+  let result = match speed_squared > 0.0 {
+    true => -(point.0 * velocity.0 + point.1 * velocity.1 + point.2 * velocity.2) / speed_squared,
+    false => 0.0,
+  };
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_random_space_velocity() {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let velocity = get_random_space_velocity(&mut rng);
+    print_var!(velocity);
+    let samples = (0..10_000)
+      .map(|_| get_random_space_velocity(&mut rng))
+      .collect::<Vec<(f64, f64, f64)>>();
+    let (sigma_x, sigma_y, sigma_z) = VELOCITY_DISPERSION;
+    let x = samples.iter().map(|velocity| velocity.0).collect::<Vec<f64>>();
+    let y = samples.iter().map(|velocity| velocity.1).collect::<Vec<f64>>();
+    let z = samples.iter().map(|velocity| velocity.2).collect::<Vec<f64>>();
+    for (values, sigma) in [(x, sigma_x), (y, sigma_y), (z, sigma_z)] {
+      let mean = values.iter().sum::<f64>() / values.len() as f64;
+      trace_var!(mean);
+      let dispersion = (values.iter().map(|value| (value - mean).powf(2.0)).sum::<f64>() / values.len() as f64).sqrt();
+      print_var!(dispersion);
+      assert_approx_eq!(mean, 0.0, 0.05 * sigma);
+      assert_approx_eq!(dispersion, sigma, 0.05 * sigma);
+    }
+    trace_exit!();
+  }
+
+  #[named]
+  #[test]
+  pub fn test_velocity_components() {
+    init();
+    trace_enter!();
+    let point = (10.0, 0.0, 0.0);
+    let velocity = (-30.0, 40.0, 0.0);
+    assert_approx_eq!(get_radial_velocity(point, velocity), -30.0);
+    assert_approx_eq!(get_tangential_velocity(point, velocity), 40.0);
+    // 40 km/s at ~3.07 pc is ~2.75"/yr.
+    assert_approx_eq!(get_proper_motion(point, velocity), 2.75, 0.01);
+    // 1 km/s for a million years is ~3.34 ly.
+    let displacement = get_displacement((1.0, 0.0, 0.0), 1_000_000.0);
+    assert_approx_eq!(displacement.0, 3.336, 0.001);
+    let time = get_time_of_closest_approach(point, (-30.0, 0.0, 0.0));
+    assert_approx_eq!(time, 10.0 / get_displacement((30.0, 0.0, 0.0), 1.0).0, 1.0);
+    trace_exit!();
+  }
+}
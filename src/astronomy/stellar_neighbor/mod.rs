@@ -1,5 +1,9 @@
+use crate::astronomy::star::math::magnitude::get_apparent_magnitude;
 use crate::astronomy::star_system::StarSystem;
+use crate::astronomy::stellar_neighbor::math::point::get_sky_position;
+use crate::astronomy::stellar_neighbor::math::velocity::*;
 
+pub mod constants;
 pub mod constraints;
 pub mod error;
 pub mod math;
@@ -16,6 +20,8 @@ pub struct StellarNeighbor {
   pub coordinates: (f64, f64, f64),
   /// The details of this particular star system.
   pub star_system: StarSystem,
+  /// The space velocity (x,y,z) relative to the origin, in km/s.
+  pub velocity: (f64, f64, f64),
   /// The distance from the origin.
   pub distance: f64,
   /// The name of the primary star.
@@ -44,4 +50,184 @@ impl StellarNeighbor {
     trace_exit!();
     result
   }
+
+  /// Retrieve or calculate the absolute visual (V band) magnitude of the
+  /// stars.
+  #[named]
+  pub fn get_absolute_visual_magnitude(&self) -> f64 {
+    trace_enter!();
+    let result = self.star_system.get_absolute_visual_magnitude();
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the apparent visual magnitude of the stars as
+  /// seen from the origin.
+  ///
+  /// Extinction is in magnitudes; if it isn't given, we estimate it from the
+  /// distance.
+  #[named]
+  pub fn get_apparent_magnitude(&self, extinction: Option<f64>) -> f64 {
+    trace_enter!();
+    trace_var!(extinction);
+    let result = get_apparent_magnitude(self.get_absolute_visual_magnitude(), self.distance, extinction);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the position of this neighbor on the sky.
+  ///
+  /// Returns (longitude, latitude) in degrees.
+  #[named]
+  pub fn get_sky_position(&self) -> (f64, f64) {
+    trace_enter!();
+    let result = get_sky_position(self.coordinates);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the radial velocity, in km/s.
+  ///
+  /// Positive values are receding, negative approaching.
+  #[named]
+  pub fn get_radial_velocity(&self) -> f64 {
+    trace_enter!();
+    let result = get_radial_velocity(self.coordinates, self.velocity);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the tangential velocity, in km/s.
+  #[named]
+  pub fn get_tangential_velocity(&self) -> f64 {
+    trace_enter!();
+    let result = get_tangential_velocity(self.coordinates, self.velocity);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the proper motion, in arcseconds per year.
+  #[named]
+  pub fn get_proper_motion(&self) -> f64 {
+    trace_enter!();
+    let result = get_proper_motion(self.coordinates, self.velocity);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Calculate the coordinates of this neighbor some years from now.
+  ///
+  /// Negative values look into the past.
+  #[named]
+  pub fn get_coordinates_at(&self, years: f64) -> (f64, f64, f64) {
+    trace_enter!();
+    trace_var!(years);
+    let displacement = get_displacement(self.velocity, years);
+    trace_var!(displacement);
+    let result = (
+      self.coordinates.0 + displacement.0,
+      self.coordinates.1 + displacement.1,
+      self.coordinates.2 + displacement.2,
+    );
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Move this neighbor forward (or, for negative values, backward) in time.
+  #[named]
+  pub fn advance(&mut self, years: f64) {
+    trace_enter!();
+    trace_var!(years);
+    self.coordinates = self.get_coordinates_at(years);
+    trace_var!(self.coordinates);
+    let (x, y, z) = self.coordinates;
+    self.distance = (x.powf(2.0) + y.powf(2.0) + z.powf(2.0)).sqrt();
+    trace_var!(self.distance);
+    trace_exit!();
+  }
+
+  /// Calculate the closest approach to the origin within a window of time.
+  ///
+  /// The window runs from now until `years` from now (or ago, if negative).
+  ///
+  /// Returns (years from now, distance in light years).
+  #[named]
+  pub fn get_closest_approach(&self, years: f64) -> (f64, f64) {
+    trace_enter!();
+    trace_var!(years);
+    let time = get_time_of_closest_approach(self.coordinates, self.velocity).clamp(years.min(0.0), years.max(0.0));
+    trace_var!(time);
+    let (x, y, z) = self.get_coordinates_at(time);
+    let distance = (x.powf(2.0) + y.powf(2.0) + z.powf(2.0)).sqrt();
+    trace_var!(distance);
+    let result = (time, distance);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use rand::prelude::*;
+
+  use super::constraints::Constraints;
+  use super::error::Error;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_advance() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let mut stellar_neighbor = Constraints::default().generate_at_coordinates(&mut rng, (5.0, 0.0, 0.0))?;
+    stellar_neighbor.velocity = (-30.0, 10.0, 0.0);
+    assert_approx_eq!(stellar_neighbor.get_radial_velocity(), -30.0);
+    assert_approx_eq!(stellar_neighbor.get_tangential_velocity(), 10.0);
+    let (time, distance) = stellar_neighbor.get_closest_approach(1_000_000.0);
+    print_var!(time);
+    print_var!(distance);
+    assert!(time > 0.0 && distance < 5.0);
+    let original = stellar_neighbor.clone();
+    stellar_neighbor.advance(time);
+    assert_approx_eq!(stellar_neighbor.distance, distance);
+    assert!(stellar_neighbor.get_radial_velocity().abs() < 0.001);
+    stellar_neighbor.advance(-time);
+    assert_approx_eq!(stellar_neighbor.coordinates.0, original.coordinates.0);
+    assert_approx_eq!(stellar_neighbor.distance, original.distance);
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_magnitudes() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let stellar_neighbor = Constraints::default().generate_at_coordinates(&mut rng, (5.0, 0.0, 0.0))?;
+    let absolute_magnitude = stellar_neighbor.get_absolute_visual_magnitude();
+    print_var!(absolute_magnitude);
+    let apparent_magnitude = stellar_neighbor.get_apparent_magnitude(Some(0.0));
+    print_var!(apparent_magnitude);
+    // Closer than ten parsecs, so brighter than it would be at ten parsecs
+    // (unless it's a lone black hole, which is infinitely faint anywhere).
+    if absolute_magnitude.is_finite() {
+      assert!(apparent_magnitude < absolute_magnitude);
+      assert!(stellar_neighbor.get_apparent_magnitude(None) > apparent_magnitude);
+    }
+    trace_exit!();
+    Ok(())
+  }
 }
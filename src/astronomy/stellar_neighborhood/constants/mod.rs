@@ -13,3 +13,34 @@ pub const STELLAR_NEIGHBORHOOD_RADIUS: f64 = 10.0;
 ///
 /// Measured in s/ly^3, or stars per cubic light year.
 pub const STELLAR_NEIGHBORHOOD_DENSITY: f64 = 0.004;
+
+/// The metallicity of our (stellar) neighborhood.
+///
+/// As above, this is just the Sun's.
+///
+/// Measured in [Fe/H], or dex relative to the Sun.
+pub const STELLAR_NEIGHBORHOOD_METALLICITY: f64 = 0.0;
+
+/// The minimum separation between neighboring star systems, when enforced.
+///
+/// Wide binaries can be a fair fraction of a light year across, but separate
+/// systems that get much closer than this are rare in the solar
+/// neighborhood.
+///
+/// Measured in Ly, or light years.
+pub const MINIMUM_NEIGHBOR_SEPARATION: f64 = 1.0;
+
+/// The number of attempts to place a neighbor before giving up.
+pub const MAXIMUM_PLACEMENT_ATTEMPTS: usize = 30;
+
+/// The number of white dwarfs per main-sequence star.
+///
+/// The 10 pc sample (Reylé et al. 2021) has around 20 white dwarfs to just
+/// over 400 main-sequence stars.
+pub const WHITE_DWARF_RATIO: f64 = 0.05;
+
+/// The number of brown dwarfs per main-sequence star.
+///
+/// As above; the 10 pc sample has a bit under 90 L, T, and Y dwarfs.  This is
+/// probably an underestimate, since the coolest ones are hard to spot.
+pub const BROWN_DWARF_RATIO: f64 = 0.2;
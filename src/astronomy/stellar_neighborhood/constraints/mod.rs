@@ -3,8 +3,12 @@ use std::f64::consts::PI;
 
 use crate::astronomy::star_system::constraints::Constraints as StarSystemConstraints;
 use crate::astronomy::stellar_neighbor::constraints::Constraints as StellarNeighborConstraints;
+use crate::astronomy::stellar_neighbor::math::point::{
+  get_random_point_in_cube, get_random_point_in_sphere, get_random_separated_point,
+};
 use crate::astronomy::stellar_neighborhood::constants::*;
 use crate::astronomy::stellar_neighborhood::error::*;
+use crate::astronomy::stellar_neighborhood::spatial_index::SpatialIndex;
 use crate::astronomy::stellar_neighborhood::StellarNeighborhood;
 
 /// Constraints for creating a stellar neighborhood.
@@ -12,10 +16,26 @@ use crate::astronomy::stellar_neighborhood::StellarNeighborhood;
 pub struct Constraints {
   /// The radius of the neighborhood, in light years.
   pub radius: Option<f64>,
+  /// The center of the neighborhood, relative to our primary star system.
+  /// Each coordinate is measured in light years.
+  pub center: Option<(f64, f64, f64)>,
+  /// If set, the neighborhood is a cubic sector with edges of this length,
+  /// in light years, rather than a sphere.  Sectors tile space, so adjacent
+  /// ones line up neatly; any minimum separation holds across their faces.
+  pub sector_size: Option<f64>,
   /// The density of the neighborhood, in stars per cubic light year.
   pub density: Option<f64>,
+  /// The typical metallicity of the neighborhood, in [Fe/H].
+  pub metallicity: Option<f64>,
   /// Any constraints placed on the various neighbors.
   pub neighbor_constraints: Option<StellarNeighborConstraints>,
+  /// The minimum separation between neighbors (and the origin), in light
+  /// years.  If this is `None`, neighbors are placed wherever they land.
+  pub minimum_separation: Option<f64>,
+  /// Add white dwarfs alongside the main-sequence stars.
+  pub include_white_dwarfs: bool,
+  /// Add brown dwarfs alongside the main-sequence stars.
+  pub include_brown_dwarfs: bool,
 }
 
 impl Constraints {
@@ -28,21 +48,78 @@ impl Constraints {
     }
   }
 
+  /// Generate a neighborhood that statistically resembles the local 10 pc
+  /// sample.
+  ///
+  /// Multiplicity depends on the primary mass, systems keep their distance
+  /// from one another, and white and brown dwarfs are included.
+  #[named]
+  pub fn realistic() -> Self {
+    trace_enter!();
+    let neighbor_constraints = Some(StellarNeighborConstraints::realistic());
+    let minimum_separation = Some(MINIMUM_NEIGHBOR_SEPARATION);
+    let include_white_dwarfs = true;
+    let include_brown_dwarfs = true;
+    let result = Self {
+      neighbor_constraints,
+      minimum_separation,
+      include_white_dwarfs,
+      include_brown_dwarfs,
+      ..Constraints::default()
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
   /// Generate a random stellar neighborhood with the specified constraints.
   ///
   /// This may or may not be habitable.
   #[named]
   pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<StellarNeighborhood, Error> {
     trace_enter!();
-    let radius = self.radius.unwrap_or(STELLAR_NEIGHBORHOOD_RADIUS);
+    let radius = match self.sector_size {
+      Some(sector_size) => sector_size / 2.0,
+      None => self.radius.unwrap_or(STELLAR_NEIGHBORHOOD_RADIUS),
+    };
     trace_var!(radius);
     let density = self.density.unwrap_or(STELLAR_NEIGHBORHOOD_DENSITY);
     trace_var!(density);
-    let volume = (4.0 / 3.0) * PI * radius.powf(3.0);
+    let metallicity = self.metallicity.unwrap_or(STELLAR_NEIGHBORHOOD_METALLICITY);
+    trace_var!(metallicity);
+    let center = self.center.unwrap_or((0.0, 0.0, 0.0));
+    trace_var!(center);
+    let volume = match self.sector_size {
+      Some(sector_size) => sector_size.powf(3.0),
+      None => (4.0 / 3.0) * PI * radius.powf(3.0),
+    };
     trace_var!(volume);
+    // Neighbors in a sector keep half the minimum separation away from each
+    // face, so they keep their distance from neighbors in adjacent sectors
+    // too, without our having to generate those.
+    let placement_size = self
+      .sector_size
+      .map(|sector_size| sector_size - self.minimum_separation.unwrap_or(0.0));
+    trace_var!(placement_size);
+    let get_candidate = |rng: &mut R| {
+      let (point, scale) = match placement_size {
+        Some(placement_size) => (get_random_point_in_cube(rng), placement_size),
+        None => (get_random_point_in_sphere(rng), radius),
+      };
+      (
+        center.0 + point.0 * scale,
+        center.1 + point.1 * scale,
+        center.2 + point.2 * scale,
+      )
+    };
     let average_stars = density * volume;
     trace_var!(average_stars);
-    let number_of_stars = rng.gen_range((0.875 * average_stars)..(1.125 * average_stars)) as usize;
+    // Sectors beyond the edge of the disk, or too small to fit anyone, are
+    // simply empty.
+    let number_of_stars = match average_stars > 0.0 && placement_size.unwrap_or(radius) > 0.0 {
+      true => rng.gen_range((0.875 * average_stars)..(1.125 * average_stars)) as usize,
+      false => 0,
+    };
     trace_var!(number_of_stars);
     let mut neighbors = vec![];
     trace_var!(neighbors);
@@ -50,24 +127,100 @@ impl Constraints {
     let neighbor_constraints = self.neighbor_constraints.unwrap_or(StellarNeighborConstraints {
       radius: Some(radius),
       system_constraints: Some(StarSystemConstraints::default()),
+      ..StellarNeighborConstraints::default()
     });
+    let neighbor_constraints = StellarNeighborConstraints {
+      metallicity: neighbor_constraints.metallicity.or(Some(metallicity)),
+      ..neighbor_constraints
+    };
     trace_var!(neighbor_constraints);
-    loop {
-      let neighbor = neighbor_constraints.generate(rng)?;
+    // The origin is reserved for our primary star system, which only lives
+    // in our own neighborhood.
+    let mut points = match center == (0.0, 0.0, 0.0) {
+      true => vec![(0.0, 0.0, 0.0)],
+      false => vec![],
+    };
+    // If the neighborhood is too crowded to fit another neighbor, we stop
+    // early and settle for a few fewer stars than we were aiming for.
+    let get_coordinates = |rng: &mut R, points: &[(f64, f64, f64)]| match self.minimum_separation {
+      Some(minimum_separation) => get_random_separated_point(
+        rng,
+        get_candidate,
+        points,
+        minimum_separation,
+        MAXIMUM_PLACEMENT_ATTEMPTS,
+      ),
+      None => Some(get_candidate(rng)),
+    };
+    while star_count < number_of_stars {
+      let coordinates = match get_coordinates(rng, &points) {
+        Some(coordinates) => coordinates,
+        None => break,
+      };
+      let neighbor = neighbor_constraints.generate_at_coordinates(rng, coordinates)?;
       star_count += neighbor.get_stellar_count() as usize;
+      points.push(neighbor.coordinates);
       neighbors.push(neighbor);
-      if star_count >= number_of_stars {
-        break;
+    }
+    trace_var!(star_count);
+    let number_of_white_dwarfs = match self.include_white_dwarfs {
+      true => (0..star_count)
+        .filter(|_| rng.gen_range(0.0..1.0) < WHITE_DWARF_RATIO)
+        .count(),
+      false => 0,
+    };
+    trace_var!(number_of_white_dwarfs);
+    let number_of_brown_dwarfs = match self.include_brown_dwarfs {
+      true => (0..star_count)
+        .filter(|_| rng.gen_range(0.0..1.0) < BROWN_DWARF_RATIO)
+        .count(),
+      false => 0,
+    };
+    trace_var!(number_of_brown_dwarfs);
+    let white_dwarf_constraints = StellarNeighborConstraints {
+      metallicity: Some(metallicity),
+      ..StellarNeighborConstraints::white_dwarf()
+    };
+    let brown_dwarf_constraints = StellarNeighborConstraints {
+      metallicity: Some(metallicity),
+      ..StellarNeighborConstraints::brown_dwarf()
+    };
+    let mut white_dwarf_count = 0;
+    let mut brown_dwarf_count = 0;
+    let extra_neighbors = [
+      (white_dwarf_constraints, number_of_white_dwarfs, &mut white_dwarf_count),
+      (brown_dwarf_constraints, number_of_brown_dwarfs, &mut brown_dwarf_count),
+    ];
+    for (constraints, number, count) in extra_neighbors {
+      while *count < number {
+        let coordinates = match get_coordinates(rng, &points) {
+          Some(coordinates) => coordinates,
+          None => break,
+        };
+        let neighbor = constraints.generate_at_coordinates(rng, coordinates)?;
+        star_count += neighbor.get_stellar_count() as usize;
+        points.push(neighbor.coordinates);
+        neighbors.push(neighbor);
+        *count += 1;
       }
     }
     trace_var!(neighbors);
     trace_var!(star_count);
-    let result = StellarNeighborhood {
+    trace_var!(white_dwarf_count);
+    trace_var!(brown_dwarf_count);
+    let mut result = StellarNeighborhood {
+      center,
       radius,
       density,
+      metallicity,
       neighbors,
       star_count,
+      white_dwarf_count,
+      brown_dwarf_count,
+      elapsed_time: 0.0,
+      spatial_index: SpatialIndex::default(),
     };
+    result.rebuild_spatial_index();
     trace_var!(result);
     trace_exit!();
     Ok(result)
@@ -78,12 +231,24 @@ impl Default for Constraints {
   /// No constraints, just let it all hang out.
   fn default() -> Self {
     let radius = Some(STELLAR_NEIGHBORHOOD_RADIUS);
+    let center = None;
+    let sector_size = None;
     let density = Some(STELLAR_NEIGHBORHOOD_DENSITY);
+    let metallicity = Some(STELLAR_NEIGHBORHOOD_METALLICITY);
     let neighbor_constraints = Some(StellarNeighborConstraints::default());
+    let minimum_separation = None;
+    let include_white_dwarfs = false;
+    let include_brown_dwarfs = false;
     Self {
       radius,
+      center,
+      sector_size,
       density,
+      metallicity,
       neighbor_constraints,
+      minimum_separation,
+      include_white_dwarfs,
+      include_brown_dwarfs,
     }
   }
 }
@@ -94,6 +259,7 @@ pub mod test {
   use rand::prelude::*;
 
   use super::*;
+  use crate::astronomy::stellar_neighbor::math::point::get_distance_between_points;
   use crate::test::*;
 
   #[named]
@@ -110,4 +276,45 @@ pub mod test {
     trace_exit!();
     Ok(())
   }
+
+  #[named]
+  #[test]
+  pub fn get_realistic() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let constraints = Constraints::realistic();
+    let stellar_neighborhood = constraints.generate(&mut rng)?;
+    info_var!(stellar_neighborhood);
+    let mut points = vec![(0.0, 0.0, 0.0)];
+    points.extend(
+      stellar_neighborhood
+        .neighbors
+        .iter()
+        .map(|neighbor| neighbor.coordinates),
+    );
+    for (index, &a) in points.iter().enumerate() {
+      for &b in points.iter().skip(index + 1) {
+        assert!(get_distance_between_points(a, b) >= MINIMUM_NEIGHBOR_SEPARATION);
+      }
+    }
+    let brown_dwarf_count = stellar_neighborhood
+      .neighbors
+      .iter()
+      .filter(|neighbor| neighbor.get_stellar_mass() < 0.08)
+      .count();
+    assert!(brown_dwarf_count >= stellar_neighborhood.brown_dwarf_count);
+    let star_count = stellar_neighborhood
+      .neighbors
+      .iter()
+      .map(|neighbor| neighbor.get_stellar_count() as usize)
+      .sum::<usize>();
+    assert_eq!(star_count, stellar_neighborhood.star_count);
+    print_var!(stellar_neighborhood.star_count);
+    print_var!(stellar_neighborhood.white_dwarf_count);
+    print_var!(stellar_neighborhood.brown_dwarf_count);
+    trace_exit!();
+    Ok(())
+  }
 }
@@ -5,6 +5,8 @@ use crate::astronomy::stellar_neighbor::error::Error as StellarNeighborError;
 pub enum Error {
   /// Stellar Neighbor Error.
   StellarNeighborError(StellarNeighborError),
+  /// The requested neighbor does not exist.
+  NoSuchNeighbor,
 }
 
 honeyholt_define_brief!(Error, |error: &Error| {
@@ -14,6 +16,7 @@ honeyholt_define_brief!(Error, |error: &Error| {
       "an error occurred in the stellar neighbor ({})",
       honeyholt_brief!(stellar_neighbor_error)
     ),
+    NoSuchNeighbor => "the requested neighbor does not exist".to_string(),
   }
 });
 
@@ -0,0 +1,17 @@
+/// The `Flyby` type.
+///
+/// A close pass of a neighboring star system by our primary star system.
+///
+/// Close enough, and a flyby will stir up the outer reaches of the system,
+/// sending comets inward; even if not, a neighbor that slowly brightens over
+/// the centuries until it outshines everything else in the night sky is the
+/// stuff of prophecy.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Flyby {
+  /// The index of the neighbor making the pass.
+  pub neighbor: usize,
+  /// The time of closest approach, in years from now (negative if past).
+  pub time: f64,
+  /// The distance at closest approach, in light years.
+  pub distance: f64,
+}
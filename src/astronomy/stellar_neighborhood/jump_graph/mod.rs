@@ -0,0 +1,153 @@
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+
+use crate::astronomy::stellar_neighborhood::spatial_index::SpatialIndex;
+
+/// The `JumpGraph` type.
+///
+/// This connects every pair of star systems that lie within some maximum
+/// travel distance of one another.  What a "jump" means is up to the travel
+/// and trade layers; it might be a generation ship, a spell, or a very, very
+/// long walk.
+///
+/// Nodes are identified by their index into the neighborhood's neighbors.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JumpGraph {
+  /// The maximum distance of a single jump, in light years.
+  pub maximum_jump_distance: f64,
+  /// For each node, a list of (node, distance in light years) reachable in a
+  /// single jump, nearest first.
+  pub adjacency: Vec<Vec<(usize, f64)>>,
+}
+
+impl JumpGraph {
+  /// Build a jump graph over the points of a spatial index.
+  #[named]
+  pub fn new(spatial_index: &SpatialIndex, maximum_jump_distance: f64) -> Self {
+    trace_enter!();
+    trace_var!(maximum_jump_distance);
+    let adjacency = spatial_index
+      .points
+      .iter()
+      .enumerate()
+      .map(|(index, &point)| {
+        spatial_index
+          .get_within_radius(point, maximum_jump_distance)
+          .into_iter()
+          .filter(|&(other, _)| other != index)
+          .collect::<Vec<(usize, f64)>>()
+      })
+      .collect::<Vec<Vec<(usize, f64)>>>();
+    trace_var!(adjacency);
+    let result = Self {
+      maximum_jump_distance,
+      adjacency,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the number of nodes in the graph.
+  #[named]
+  pub fn get_node_count(&self) -> usize {
+    trace_enter!();
+    let result = self.adjacency.len();
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the number of (undirected) jumps in the graph.
+  #[named]
+  pub fn get_edge_count(&self) -> usize {
+    trace_enter!();
+    let result = self.adjacency.iter().map(|edges| edges.len()).sum::<usize>() / 2;
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Calculate the connected components of the graph.
+  ///
+  /// Each component is a sorted list of nodes; the components themselves are
+  /// sorted largest first.  Isolated systems form components of their own.
+  #[named]
+  pub fn get_connected_components(&self) -> Vec<Vec<usize>> {
+    trace_enter!();
+    let mut visited = vec![false; self.adjacency.len()];
+    let mut result = Vec::new();
+    for start in 0..self.adjacency.len() {
+      if visited[start] {
+        continue;
+      }
+      visited[start] = true;
+      let mut component = vec![];
+      let mut queue = VecDeque::from(vec![start]);
+      while let Some(node) = queue.pop_front() {
+        component.push(node);
+        for &(other, _) in self.adjacency[node].iter() {
+          if !visited[other] {
+            visited[other] = true;
+            queue.push_back(other);
+          }
+        }
+      }
+      component.sort_unstable();
+      result.push(component);
+    }
+    result.sort_by_key(|component: &Vec<usize>| Reverse(component.len()));
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Indicate whether one node can be reached from another by some series of
+  /// jumps.
+  #[named]
+  pub fn is_connected(&self, from: usize, to: usize) -> bool {
+    trace_enter!();
+    trace_var!(from);
+    trace_var!(to);
+    let result = self
+      .get_connected_components()
+      .iter()
+      .any(|component| component.contains(&from) && component.contains(&to));
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_connected_components() {
+    init();
+    trace_enter!();
+    let points = vec![
+      (0.0, 0.0, 0.0),
+      (10.0, 0.0, 0.0),
+      (1.0, 0.0, 0.0),
+      (10.0, 1.5, 0.0),
+      (2.0, 0.0, 0.0),
+      (-8.0, -8.0, -8.0),
+    ];
+    let spatial_index = SpatialIndex::new(points);
+    let jump_graph = JumpGraph::new(&spatial_index, 1.75);
+    trace_var!(jump_graph);
+    assert_eq!(6, jump_graph.get_node_count());
+    assert_eq!(3, jump_graph.get_edge_count());
+    let components = jump_graph.get_connected_components();
+    print_var!(components);
+    assert_eq!(vec![vec![0, 2, 4], vec![1, 3], vec![5]], components);
+    assert!(jump_graph.is_connected(0, 4));
+    assert!(!jump_graph.is_connected(0, 1));
+    trace_exit!();
+  }
+}
@@ -1,8 +1,18 @@
+use std::cmp::Ordering;
+
+use crate::astronomy::stellar_neighbor::math::point::get_distance_between_points;
 use crate::astronomy::stellar_neighbor::*;
 
 pub mod constants;
 pub mod constraints;
 pub mod error;
+use error::Error;
+pub mod flyby;
+use flyby::Flyby;
+pub mod jump_graph;
+use jump_graph::JumpGraph;
+pub mod spatial_index;
+use spatial_index::SpatialIndex;
 
 /// The `StellarNeighborhood` type.
 ///
@@ -13,19 +23,296 @@ pub mod error;
 /// stars, possibly with planets of their own.
 ///
 /// Why?  Well, just to add a little color to the night sky.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct StellarNeighborhood {
-  /// The radius of this neighborhood, measured in light years.
+  /// The center of this neighborhood, relative to our primary star system.
+  /// Each coordinate is measured in light years.
+  pub center: (f64, f64, f64),
+  /// The radius of this neighborhood, measured in light years.  For a cubic
+  /// sector, this is half the length of an edge.
   pub radius: f64,
   /// The stellar density of this neighborhood, measured in stars per cubic
   /// light year.  This is not terribly useful once the neighborhood has
   /// been generated, but we keep it around for posterity.
   pub density: f64,
+  /// The typical metallicity of this neighborhood, measured in [Fe/H].
+  pub metallicity: f64,
   /// Stellar "neighbors", which is a glorified tuple of three-dimensional
   /// coordinates and a star system.
   pub neighbors: Vec<StellarNeighbor>,
   /// The number of stars in this stellar neighborhood.
   pub star_count: usize,
+  /// The number of lone white dwarf neighbors in this stellar neighborhood,
+  /// if we included them.
+  pub white_dwarf_count: usize,
+  /// The number of free-floating brown dwarf neighbors in this stellar
+  /// neighborhood, if we included them.
+  pub brown_dwarf_count: usize,
+  /// The time elapsed since this neighborhood was generated, in years.  This
+  /// is negative if the neighborhood has been rewound past that point.
+  pub elapsed_time: f64,
+  /// A spatial index over the coordinates of the neighbors.  This needs to be
+  /// rebuilt if the neighbors are added, removed, or moved.
+  pub spatial_index: SpatialIndex,
+}
+
+impl StellarNeighborhood {
+  /// Rebuild the spatial index from the current coordinates of the neighbors.
+  #[named]
+  pub fn rebuild_spatial_index(&mut self) {
+    trace_enter!();
+    let points = self
+      .neighbors
+      .iter()
+      .map(|neighbor| neighbor.coordinates)
+      .collect::<Vec<(f64, f64, f64)>>();
+    self.spatial_index = SpatialIndex::new(points);
+    trace_var!(self.spatial_index);
+    trace_exit!();
+  }
+
+  /// Find the `count` neighbors nearest to an arbitrary point.
+  ///
+  /// Returns a list of (neighbor index, distance in light years), nearest
+  /// first.
+  #[named]
+  pub fn get_nearest_neighbors_to_point(&self, point: (f64, f64, f64), count: usize) -> Vec<(usize, f64)> {
+    trace_enter!();
+    trace_var!(point);
+    trace_var!(count);
+    let result = self.spatial_index.get_nearest(point, count);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Find the `count` neighbors nearest to the specified neighbor.
+  ///
+  /// The neighbor itself is not included.
+  #[named]
+  pub fn get_nearest_neighbors(&self, index: usize, count: usize) -> Result<Vec<(usize, f64)>, Error> {
+    trace_enter!();
+    trace_var!(index);
+    trace_var!(count);
+    let point = self.get_neighbor_coordinates(index)?;
+    let mut result = self
+      .spatial_index
+      .get_nearest(point, count + 1)
+      .into_iter()
+      .filter(|&(other, _)| other != index)
+      .collect::<Vec<(usize, f64)>>();
+    result.truncate(count);
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Find all neighbors within `radius` light years of an arbitrary point.
+  ///
+  /// Returns a list of (neighbor index, distance in light years), nearest
+  /// first.
+  #[named]
+  pub fn get_neighbors_within_radius_of_point(&self, point: (f64, f64, f64), radius: f64) -> Vec<(usize, f64)> {
+    trace_enter!();
+    trace_var!(point);
+    trace_var!(radius);
+    let result = self.spatial_index.get_within_radius(point, radius);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Find all neighbors within `radius` light years of the specified neighbor.
+  ///
+  /// The neighbor itself is not included.
+  #[named]
+  pub fn get_neighbors_within_radius(&self, index: usize, radius: f64) -> Result<Vec<(usize, f64)>, Error> {
+    trace_enter!();
+    trace_var!(index);
+    trace_var!(radius);
+    let point = self.get_neighbor_coordinates(index)?;
+    let result = self
+      .spatial_index
+      .get_within_radius(point, radius)
+      .into_iter()
+      .filter(|&(other, _)| other != index)
+      .collect::<Vec<(usize, f64)>>();
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Calculate the distance between every pair of neighbors.
+  ///
+  /// Measured in light years; `result[i][j]` is the distance from neighbor `i`
+  /// to neighbor `j`.
+  #[named]
+  pub fn get_distance_matrix(&self) -> Vec<Vec<f64>> {
+    trace_enter!();
+    let result = self
+      .neighbors
+      .iter()
+      .map(|neighbor| {
+        self
+          .neighbors
+          .iter()
+          .map(|other| get_distance_between_points(neighbor.coordinates, other.coordinates))
+          .collect::<Vec<f64>>()
+      })
+      .collect::<Vec<Vec<f64>>>();
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Build a graph connecting all neighbors within `maximum_jump_distance`
+  /// light years of one another.
+  #[named]
+  pub fn get_jump_graph(&self, maximum_jump_distance: f64) -> JumpGraph {
+    trace_enter!();
+    trace_var!(maximum_jump_distance);
+    let result = JumpGraph::new(&self.spatial_index, maximum_jump_distance);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Move every neighbor forward in time.
+  ///
+  /// Coordinates are relative to our primary star system, which stays at the
+  /// origin.  Neighbors may wander outside the neighborhood's radius (and
+  /// others would wander in, but we don't generate those).
+  #[named]
+  pub fn advance(&mut self, years: f64) {
+    trace_enter!();
+    trace_var!(years);
+    for neighbor in self.neighbors.iter_mut() {
+      neighbor.advance(years);
+    }
+    self.elapsed_time += years;
+    trace_var!(self.elapsed_time);
+    self.rebuild_spatial_index();
+    trace_exit!();
+  }
+
+  /// Move every neighbor backward in time.
+  #[named]
+  pub fn rewind(&mut self, years: f64) {
+    trace_enter!();
+    trace_var!(years);
+    self.advance(-years);
+    trace_exit!();
+  }
+
+  /// Find neighbors that pass within `maximum_distance` light years of our
+  /// primary star system during the next `years` years (or the last, if
+  /// negative).
+  ///
+  /// Returns the flybys sorted by time, soonest first.
+  #[named]
+  pub fn get_flybys(&self, years: f64, maximum_distance: f64) -> Vec<Flyby> {
+    trace_enter!();
+    trace_var!(years);
+    trace_var!(maximum_distance);
+    let mut result = self
+      .neighbors
+      .iter()
+      .enumerate()
+      .map(|(neighbor, stellar_neighbor)| {
+        let (time, distance) = stellar_neighbor.get_closest_approach(years);
+        Flyby {
+          neighbor,
+          time,
+          distance,
+        }
+      })
+      .filter(|flyby| flyby.distance <= maximum_distance)
+      .collect::<Vec<Flyby>>();
+    result.sort_by(|a, b| a.time.abs().partial_cmp(&b.time.abs()).unwrap_or(Ordering::Equal));
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve the coordinates of the specified neighbor.
+  #[named]
+  fn get_neighbor_coordinates(&self, index: usize) -> Result<(f64, f64, f64), Error> {
+    trace_enter!();
+    trace_var!(index);
+    let result = match self.neighbors.get(index) {
+      Some(neighbor) => Ok(neighbor.coordinates),
+      None => Err(Error::NoSuchNeighbor),
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
 }
 
-impl StellarNeighborhood {}
+#[cfg(test)]
+pub mod test {
+
+  use rand::prelude::*;
+
+  use super::constraints::Constraints;
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_proximity_queries() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let stellar_neighborhood = Constraints::default().generate(&mut rng)?;
+    let count = stellar_neighborhood.neighbors.len();
+    let distance_matrix = stellar_neighborhood.get_distance_matrix();
+    assert_eq!(count, distance_matrix.len());
+    let nearest = stellar_neighborhood.get_nearest_neighbors(0, 3)?;
+    print_var!(nearest);
+    assert!(nearest.iter().all(|&(index, _)| index != 0));
+    for &(index, distance) in nearest.iter() {
+      assert_approx_eq!(distance_matrix[0][index], distance);
+    }
+    let within = stellar_neighborhood.get_neighbors_within_radius(0, 5.0)?;
+    assert!(within.iter().all(|&(index, _)| distance_matrix[0][index] <= 5.0));
+    let origin = stellar_neighborhood.get_nearest_neighbors_to_point((0.0, 0.0, 0.0), 1);
+    assert_approx_eq!(stellar_neighborhood.neighbors[origin[0].0].distance, origin[0].1);
+    let jump_graph = stellar_neighborhood.get_jump_graph(5.0);
+    let components = jump_graph.get_connected_components();
+    print_var!(components);
+    assert_eq!(count, components.iter().map(|component| component.len()).sum::<usize>());
+    assert_eq!(
+      Err(Error::NoSuchNeighbor),
+      stellar_neighborhood.get_nearest_neighbors(count, 3)
+    );
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_advance() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let mut stellar_neighborhood = Constraints::default().generate(&mut rng)?;
+    let original = stellar_neighborhood.clone();
+    stellar_neighborhood.advance(100_000.0);
+    assert_approx_eq!(stellar_neighborhood.elapsed_time, 100_000.0);
+    let flybys = stellar_neighborhood.get_flybys(-100_000.0, 1.0);
+    print_var!(flybys);
+    assert!(flybys.iter().all(|flyby| flyby.time <= 0.0 && flyby.distance <= 1.0));
+    let nearest = stellar_neighborhood.get_nearest_neighbors_to_point((0.0, 0.0, 0.0), 1);
+    assert_approx_eq!(stellar_neighborhood.neighbors[nearest[0].0].distance, nearest[0].1);
+    stellar_neighborhood.rewind(100_000.0);
+    assert_approx_eq!(stellar_neighborhood.elapsed_time, 0.0);
+    for (neighbor, original) in stellar_neighborhood.neighbors.iter().zip(original.neighbors.iter()) {
+      assert_approx_eq!(neighbor.distance, original.distance);
+    }
+    trace_exit!();
+    Ok(())
+  }
+}
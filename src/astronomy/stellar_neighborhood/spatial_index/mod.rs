@@ -0,0 +1,254 @@
+use std::cmp::Ordering;
+
+use crate::astronomy::stellar_neighbor::math::point::get_distance_between_points;
+
+/// A node in the spatial index.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Node {
+  /// The index of the point stored at this node.
+  pub point: usize,
+  /// The axis along which this node splits space (0 = x, 1 = y, 2 = z).
+  pub axis: usize,
+  /// The node containing points below the split, if any.
+  pub left: Option<usize>,
+  /// The node containing points at or above the split, if any.
+  pub right: Option<usize>,
+}
+
+/// The `SpatialIndex` type.
+///
+/// This is a plain, static k-d tree over a set of three-dimensional points.
+///
+/// A stellar neighborhood only holds a dozen or so systems at the moment, so
+/// this is arguably overkill, but the travel and trade layers are going to
+/// hammer these queries and neighborhoods are likely to get bigger.
+///
+/// The index refers to points by their position in the original list, so it
+/// must be rebuilt if that list changes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SpatialIndex {
+  /// The indexed points; each coordinate is measured in light years.
+  pub points: Vec<(f64, f64, f64)>,
+  /// The nodes of the tree.
+  pub nodes: Vec<Node>,
+  /// The root node, if there are any points at all.
+  pub root: Option<usize>,
+}
+
+impl SpatialIndex {
+  /// Build a spatial index from a list of points.
+  #[named]
+  pub fn new(points: Vec<(f64, f64, f64)>) -> Self {
+    trace_enter!();
+    trace_var!(points);
+    let mut indices = (0..points.len()).collect::<Vec<usize>>();
+    let mut nodes = Vec::with_capacity(points.len());
+    let root = build_subtree(&points, &mut indices, 0, &mut nodes);
+    trace_var!(root);
+    let result = Self { points, nodes, root };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Find the `count` points nearest to the specified point.
+  ///
+  /// Returns a list of (index, distance in light years), nearest first.
+  #[named]
+  pub fn get_nearest(&self, point: (f64, f64, f64), count: usize) -> Vec<(usize, f64)> {
+    trace_enter!();
+    trace_var!(point);
+    trace_var!(count);
+    let mut result = Vec::with_capacity(count);
+    if count > 0 {
+      if let Some(root) = self.root {
+        self.search_nearest(root, point, count, &mut result);
+      }
+    }
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Find all points within `radius` light years of the specified point.
+  ///
+  /// Returns a list of (index, distance in light years), nearest first.
+  #[named]
+  pub fn get_within_radius(&self, point: (f64, f64, f64), radius: f64) -> Vec<(usize, f64)> {
+    trace_enter!();
+    trace_var!(point);
+    trace_var!(radius);
+    let mut result = Vec::new();
+    if let Some(root) = self.root {
+      self.search_within_radius(root, point, radius, &mut result);
+    }
+    result.sort_by(compare_by_distance);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Recursively collect the nearest points beneath a node.
+  ///
+  /// `best` is kept sorted, nearest first, and never exceeds `count` entries.
+  fn search_nearest(&self, node: usize, point: (f64, f64, f64), count: usize, best: &mut Vec<(usize, f64)>) {
+    let node = self.nodes[node];
+    let candidate = self.points[node.point];
+    let distance = get_distance_between_points(point, candidate);
+    if best.len() < count || distance < best[best.len() - 1].1 {
+      let position = best
+        .iter()
+        .position(|&(_, other)| distance < other)
+        .unwrap_or(best.len());
+      best.insert(position, (node.point, distance));
+      best.truncate(count);
+    }
+    let difference = get_axis(point, node.axis) - get_axis(candidate, node.axis);
+    let (near, far) = match difference < 0.0 {
+      true => (node.left, node.right),
+      false => (node.right, node.left),
+    };
+    if let Some(near) = near {
+      self.search_nearest(near, point, count, best);
+    }
+    if let Some(far) = far {
+      if best.len() < count || difference.abs() < best[best.len() - 1].1 {
+        self.search_nearest(far, point, count, best);
+      }
+    }
+  }
+
+  /// Recursively collect all points within a radius beneath a node.
+  fn search_within_radius(&self, node: usize, point: (f64, f64, f64), radius: f64, found: &mut Vec<(usize, f64)>) {
+    let node = self.nodes[node];
+    let candidate = self.points[node.point];
+    let distance = get_distance_between_points(point, candidate);
+    if distance <= radius {
+      found.push((node.point, distance));
+    }
+    let difference = get_axis(point, node.axis) - get_axis(candidate, node.axis);
+    if let Some(left) = node.left {
+      if difference - radius <= 0.0 {
+        self.search_within_radius(left, point, radius, found);
+      }
+    }
+    if let Some(right) = node.right {
+      if difference + radius >= 0.0 {
+        self.search_within_radius(right, point, radius, found);
+      }
+    }
+  }
+}
+
+/// Get the coordinate of a point along an axis.
+fn get_axis(point: (f64, f64, f64), axis: usize) -> f64 {
+  match axis {
+    0 => point.0,
+    1 => point.1,
+    _ => point.2,
+  }
+}
+
+/// Order (index, distance) pairs by distance.
+fn compare_by_distance(a: &(usize, f64), b: &(usize, f64)) -> Ordering {
+  a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal)
+}
+
+/// Build the subtree for a set of point indices, returning its root node.
+fn build_subtree(
+  points: &[(f64, f64, f64)],
+  indices: &mut [usize],
+  depth: usize,
+  nodes: &mut Vec<Node>,
+) -> Option<usize> {
+  if indices.is_empty() {
+    return None;
+  }
+  let axis = depth % 3;
+  indices.sort_by(|&a, &b| {
+    get_axis(points[a], axis)
+      .partial_cmp(&get_axis(points[b], axis))
+      .unwrap_or(Ordering::Equal)
+  });
+  let median = indices.len() / 2;
+  let (below, rest) = indices.split_at_mut(median);
+  let (middle, above) = rest.split_at_mut(1);
+  let left = build_subtree(points, below, depth + 1, nodes);
+  let right = build_subtree(points, above, depth + 1, nodes);
+  nodes.push(Node {
+    point: middle[0],
+    axis,
+    left,
+    right,
+  });
+  Some(nodes.len() - 1)
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::astronomy::stellar_neighbor::math::point::get_random_point_in_sphere;
+  use crate::test::*;
+
+  /// Find the nearest points the slow way.
+  fn get_nearest_by_brute_force(points: &[(f64, f64, f64)], point: (f64, f64, f64), count: usize) -> Vec<usize> {
+    let mut distances = points
+      .iter()
+      .enumerate()
+      .map(|(index, &other)| (index, get_distance_between_points(point, other)))
+      .collect::<Vec<(usize, f64)>>();
+    distances.sort_by(compare_by_distance);
+    distances.into_iter().take(count).map(|(index, _)| index).collect()
+  }
+
+  #[named]
+  #[test]
+  pub fn test_get_nearest() {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let points = (0..200)
+      .map(|_| {
+        let point = get_random_point_in_sphere(&mut rng);
+        (point.0 * 10.0, point.1 * 10.0, point.2 * 10.0)
+      })
+      .collect::<Vec<(f64, f64, f64)>>();
+    let spatial_index = SpatialIndex::new(points.clone());
+    for _ in 0..50 {
+      let point = get_random_point_in_sphere(&mut rng);
+      let expected = get_nearest_by_brute_force(&points, point, 5);
+      let actual = spatial_index
+        .get_nearest(point, 5)
+        .into_iter()
+        .map(|(index, _)| index)
+        .collect::<Vec<usize>>();
+      assert_eq!(expected, actual);
+    }
+    trace_exit!();
+  }
+
+  #[named]
+  #[test]
+  pub fn test_get_within_radius() {
+    init();
+    trace_enter!();
+    let points = vec![
+      (0.0, 0.0, 0.0),
+      (1.0, 0.0, 0.0),
+      (0.0, 2.0, 0.0),
+      (0.0, 0.0, -3.0),
+      (4.0, 4.0, 4.0),
+    ];
+    let spatial_index = SpatialIndex::new(points);
+    let actual = spatial_index.get_within_radius((0.0, 0.0, 0.0), 2.5);
+    trace_var!(actual);
+    assert_eq!(vec![(0, 0.0), (1, 1.0), (2, 2.0)], actual);
+    assert!(spatial_index.get_within_radius((20.0, 20.0, 20.0), 1.0).is_empty());
+    assert!(SpatialIndex::default().get_nearest((0.0, 0.0, 0.0), 3).is_empty());
+    trace_exit!();
+  }
+}
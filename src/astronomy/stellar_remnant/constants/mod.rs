@@ -0,0 +1,93 @@
+/// Stars below this mass leave behind white dwarfs; above it, they go out
+/// with a bang and leave a neutron star or a black hole.
+///
+/// Measured in Msol.
+pub const MINIMUM_NEUTRON_STAR_PROGENITOR_MASS: f64 = 8.0;
+
+/// Stars above this mass generally collapse into black holes.
+///
+/// This is very fuzzy in reality; there are "islands of explodability" and
+/// everything depends on metallicity and rotation.
+///
+/// Measured in Msol.
+pub const MINIMUM_BLACK_HOLE_PROGENITOR_MASS: f64 = 20.0;
+
+/// Stars much smaller than this haven't had time to die yet.
+///
+/// Measured in Msol.
+pub const MINIMUM_WHITE_DWARF_PROGENITOR_MASS: f64 = 1.0;
+
+/// The maximum age of a remnant, which is roughly the age of the disk.
+///
+/// Measured in Gyr.
+pub const MAXIMUM_AGE: f64 = 13.0;
+
+/// The slope of the initial-final mass relation (Kalirai et al. 2008).
+pub const INITIAL_FINAL_MASS_SLOPE: f64 = 0.109;
+
+/// The intercept of the initial-final mass relation, in Msol.
+pub const INITIAL_FINAL_MASS_INTERCEPT: f64 = 0.394;
+
+/// The Chandrasekhar limit; no white dwarf can support more than this.
+///
+/// Measured in Msol.
+pub const CHANDRASEKHAR_MASS: f64 = 1.44;
+
+/// The scale of the white dwarf mass-radius relation (Nauenberg 1972).
+///
+/// Measured in Rsol.
+pub const WHITE_DWARF_RADIUS_SCALE: f64 = 0.01125;
+
+/// The luminosity of a typical (0.6 Msol) white dwarf that has been cooling
+/// for 1 Gyr.
+///
+/// Measured in Lsol.
+pub const WHITE_DWARF_LUMINOSITY_AT_ONE_GYR: f64 = 0.001;
+
+/// The mass of a typical white dwarf.
+///
+/// Measured in Msol.
+pub const TYPICAL_WHITE_DWARF_MASS: f64 = 0.6;
+
+/// The maximum luminosity of a newly-formed white dwarf.
+///
+/// Measured in Lsol.
+pub const MAXIMUM_WHITE_DWARF_LUMINOSITY: f64 = 100.0;
+
+/// The minimum mass of a neutron star.
+///
+/// Measured in Msol.
+pub const MINIMUM_NEUTRON_STAR_MASS: f64 = 1.2;
+
+/// The maximum mass of a neutron star (the TOV limit, give or take).
+///
+/// Measured in Msol.
+pub const MAXIMUM_NEUTRON_STAR_MASS: f64 = 2.1;
+
+/// The radius of a neutron star; about 12 km, regardless of mass.
+///
+/// Measured in Rsol.
+pub const NEUTRON_STAR_RADIUS: f64 = 1.7e-5;
+
+/// The surface temperature of a neutron star that has been cooling for 1 Gyr.
+///
+/// Measured in Kelvin.
+pub const NEUTRON_STAR_TEMPERATURE_AT_ONE_GYR: f64 = 30_000.0;
+
+/// The maximum surface temperature of a newly-formed neutron star.
+///
+/// Measured in Kelvin.
+pub const MAXIMUM_NEUTRON_STAR_TEMPERATURE: f64 = 3_000_000.0;
+
+/// The fraction of the progenitor mass that ends up in the black hole.
+pub const BLACK_HOLE_MASS_FRACTION: f64 = 0.3;
+
+/// The minimum mass of a stellar black hole.
+///
+/// Measured in Msol.
+pub const MINIMUM_BLACK_HOLE_MASS: f64 = 3.0;
+
+/// The Schwarzschild radius of one solar mass; about 2.95 km.
+///
+/// Measured in Rsol.
+pub const SCHWARZSCHILD_RADIUS_PER_MSOL: f64 = 4.24e-6;
@@ -0,0 +1,158 @@
+use rand::prelude::*;
+use std::default::Default;
+
+use crate::astronomy::star::constants::{MAXIMUM_MASS as MAXIMUM_STAR_MASS, MEAN_METALLICITY};
+use crate::astronomy::star::math::evolution::get_remnant_formation_age;
+use crate::astronomy::star::math::mass::get_random_stellar_mass_in_range;
+use crate::astronomy::star::math::metallicity::get_random_metallicity;
+use crate::astronomy::stellar_remnant::constants::*;
+use crate::astronomy::stellar_remnant::error::Error;
+use crate::astronomy::stellar_remnant::StellarRemnant;
+
+/// Constraints for creating a stellar remnant.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Constraints {
+  /// Minimum mass of the progenitor star, in Msol.
+  pub minimum_progenitor_mass: Option<f64>,
+  /// Maximum mass of the progenitor star, in Msol.
+  pub maximum_progenitor_mass: Option<f64>,
+  /// Maximum age of the system, in Gyr.
+  pub maximum_age: Option<f64>,
+  /// The metallicity ([Fe/H]) of the progenitor star.
+  pub metallicity: Option<f64>,
+}
+
+impl Constraints {
+  /// Generate a white dwarf.
+  #[named]
+  pub fn white_dwarf() -> Self {
+    trace_enter!();
+    let maximum_progenitor_mass = Some(MINIMUM_NEUTRON_STAR_PROGENITOR_MASS);
+    let result = Self {
+      maximum_progenitor_mass,
+      ..Constraints::default()
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Generate a neutron star.
+  #[named]
+  pub fn neutron_star() -> Self {
+    trace_enter!();
+    let minimum_progenitor_mass = Some(MINIMUM_NEUTRON_STAR_PROGENITOR_MASS);
+    let maximum_progenitor_mass = Some(MINIMUM_BLACK_HOLE_PROGENITOR_MASS);
+    let result = Self {
+      minimum_progenitor_mass,
+      maximum_progenitor_mass,
+      ..Constraints::default()
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Generate a black hole.
+  #[named]
+  pub fn black_hole() -> Self {
+    trace_enter!();
+    let minimum_progenitor_mass = Some(MINIMUM_BLACK_HOLE_PROGENITOR_MASS);
+    let result = Self {
+      minimum_progenitor_mass,
+      ..Constraints::default()
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Generate.
+  ///
+  /// The progenitor mass is drawn from the usual spectral class weights, so
+  /// white dwarfs vastly outnumber everything else.
+  #[named]
+  pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<StellarRemnant, Error> {
+    trace_enter!();
+    let minimum_progenitor_mass = self
+      .minimum_progenitor_mass
+      .unwrap_or(MINIMUM_WHITE_DWARF_PROGENITOR_MASS)
+      .max(MINIMUM_WHITE_DWARF_PROGENITOR_MASS);
+    trace_var!(minimum_progenitor_mass);
+    let maximum_progenitor_mass = self
+      .maximum_progenitor_mass
+      .unwrap_or(MAXIMUM_STAR_MASS)
+      .min(0.999 * MAXIMUM_STAR_MASS);
+    trace_var!(maximum_progenitor_mass);
+    let maximum_age = self.maximum_age.unwrap_or(MAXIMUM_AGE);
+    trace_var!(maximum_age);
+    let metallicity = match self.metallicity {
+      Some(metallicity) => metallicity,
+      None => get_random_metallicity(rng, MEAN_METALLICITY),
+    };
+    trace_var!(metallicity);
+    // Heavier stars die sooner, so if the heaviest can't make it, none can.
+    if get_remnant_formation_age(maximum_progenitor_mass, metallicity)? >= maximum_age {
+      return Err(Error::NotYetFormed);
+    }
+    let mut progenitor_mass = get_random_stellar_mass_in_range(rng, minimum_progenitor_mass, maximum_progenitor_mass)?;
+    let mut formation_age = get_remnant_formation_age(progenitor_mass, metallicity)?;
+    while formation_age >= maximum_age {
+      progenitor_mass = get_random_stellar_mass_in_range(rng, minimum_progenitor_mass, maximum_progenitor_mass)?;
+      formation_age = get_remnant_formation_age(progenitor_mass, metallicity)?;
+    }
+    trace_var!(progenitor_mass);
+    trace_var!(formation_age);
+    let current_age = rng.gen_range(formation_age..maximum_age);
+    trace_var!(current_age);
+    let result = StellarRemnant::from_progenitor(rng, progenitor_mass, metallicity, current_age)?;
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+}
+
+impl Default for Constraints {
+  /// No constraints, just let it all hang out.
+  fn default() -> Self {
+    let minimum_progenitor_mass = None;
+    let maximum_progenitor_mass = None;
+    let maximum_age = None;
+    let metallicity = None;
+    Self {
+      minimum_progenitor_mass,
+      maximum_progenitor_mass,
+      maximum_age,
+      metallicity,
+    }
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::astronomy::stellar_remnant::remnant_type::RemnantType;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_generate() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let stellar_remnant = Constraints::default().generate(&mut rng)?;
+    print_var!(stellar_remnant);
+    let white_dwarf = Constraints::white_dwarf().generate(&mut rng)?;
+    assert_eq!(RemnantType::WhiteDwarf, white_dwarf.remnant_type);
+    let neutron_star = Constraints::neutron_star().generate(&mut rng)?;
+    assert_eq!(RemnantType::NeutronStar, neutron_star.remnant_type);
+    let black_hole = Constraints::black_hole().generate(&mut rng)?;
+    assert_eq!(RemnantType::BlackHole, black_hole.remnant_type);
+    trace_exit!();
+    Ok(())
+  }
+}
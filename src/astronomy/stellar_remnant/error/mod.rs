@@ -0,0 +1,31 @@
+use crate::astronomy::star::error::Error as StarError;
+
+/// Stellar remnant-related errors.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Error {
+  /// Star Error.
+  StarError(StarError),
+  /// The progenitor star hasn't finished dying yet.
+  NotYetFormed,
+  /// Whatever planets survived the progenitor's death are sterile.
+  RemnantsCannotSupportLife,
+}
+
+honeyholt_define_brief!(Error, |error: &Error| {
+  use Error::*;
+  match error {
+    StarError(star_error) => format!(
+      "an error occurred in the progenitor star ({})",
+      honeyholt_brief!(star_error)
+    ),
+    NotYetFormed => "its progenitor star has not died yet".to_string(),
+    RemnantsCannotSupportLife => "stellar remnants cannot support life".to_string(),
+  }
+});
+
+impl From<StarError> for Error {
+  #[named]
+  fn from(error: StarError) -> Self {
+    Error::StarError(error)
+  }
+}
@@ -0,0 +1,96 @@
+use crate::astronomy::star::math::evolution::star_luminosity_and_radius_to_temperature;
+use crate::astronomy::stellar_remnant::constants::*;
+use crate::astronomy::stellar_remnant::math::radius::remnant_mass_to_radius;
+use crate::astronomy::stellar_remnant::remnant_type::RemnantType;
+
+/// Get the luminosity of a white dwarf in Lsol from its mass in Msol and the
+/// time it has spent cooling in Gyr.
+///
+/// This is Mestel's cooling law; luminosity falls off as t^-7/5 and scales
+/// with mass.  It ignores crystallization and all of that fun stuff.
+#[named]
+pub fn get_white_dwarf_luminosity(mass: f64, cooling_age: f64) -> f64 {
+  trace_enter!();
+  trace_var!(mass);
+  trace_var!(cooling_age);
+  let result = (WHITE_DWARF_LUMINOSITY_AT_ONE_GYR * (mass / TYPICAL_WHITE_DWARF_MASS) * cooling_age.powf(-1.4))
+    .min(MAXIMUM_WHITE_DWARF_LUMINOSITY);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the surface temperature of a neutron star in Kelvin from the time it
+/// has spent cooling in Gyr.
+#[named]
+pub fn get_neutron_star_temperature(cooling_age: f64) -> f64 {
+  trace_enter!();
+  trace_var!(cooling_age);
+  let result = (NEUTRON_STAR_TEMPERATURE_AT_ONE_GYR * cooling_age.powf(-0.5)).min(MAXIMUM_NEUTRON_STAR_TEMPERATURE);
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the luminosity (in Lsol) and temperature (in Kelvin) of a remnant of
+/// the given type and mass (in Msol) that has been cooling for some time (in
+/// Gyr).
+///
+/// Black holes are treated as perfectly dark; Hawking radiation from a
+/// stellar-mass black hole is far too faint to matter.
+#[named]
+pub fn get_remnant_luminosity_and_temperature(remnant_type: RemnantType, mass: f64, cooling_age: f64) -> (f64, f64) {
+  trace_enter!();
+  trace_var!(remnant_type);
+  trace_var!(mass);
+  trace_var!(cooling_age);
+  use RemnantType::*;
+  let radius = remnant_mass_to_radius(remnant_type, mass);
+  trace_var!(radius);
+  let result = match remnant_type {
+    WhiteDwarf => {
+      let luminosity = get_white_dwarf_luminosity(mass, cooling_age);
+      (
+        luminosity,
+        star_luminosity_and_radius_to_temperature(luminosity, radius),
+      )
+    },
+    NeutronStar => {
+      let temperature = get_neutron_star_temperature(cooling_age);
+      (radius.powf(2.0) * (temperature / 5776.0).powf(4.0), temperature)
+    },
+    BlackHole => (0.0, 0.0),
+  };
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_remnant_luminosity_and_temperature() {
+    init();
+    trace_enter!();
+    use RemnantType::*;
+    // Sirius B: ~1 Msol, ~0.1 Gyr old, ~0.056 Lsol, ~25,000 K.
+    let (luminosity, temperature) = get_remnant_luminosity_and_temperature(WhiteDwarf, 1.02, 0.12);
+    print_var!(luminosity);
+    print_var!(temperature);
+    assert!(luminosity > 0.01 && luminosity < 0.2);
+    assert!(temperature > 15_000.0 && temperature < 35_000.0);
+    // Older white dwarfs are cooler and dimmer.
+    let (old_luminosity, old_temperature) = get_remnant_luminosity_and_temperature(WhiteDwarf, 1.02, 5.0);
+    assert!(old_luminosity < luminosity && old_temperature < temperature);
+    let (luminosity, temperature) = get_remnant_luminosity_and_temperature(NeutronStar, 1.4, 1.0);
+    assert!(luminosity < 1e-5);
+    assert_approx_eq!(temperature, NEUTRON_STAR_TEMPERATURE_AT_ONE_GYR);
+    assert_eq!((0.0, 0.0), get_remnant_luminosity_and_temperature(BlackHole, 10.0, 1.0));
+    trace_exit!();
+  }
+}
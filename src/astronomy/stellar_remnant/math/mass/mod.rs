@@ -0,0 +1,70 @@
+use crate::astronomy::stellar_remnant::constants::*;
+use crate::astronomy::stellar_remnant::remnant_type::RemnantType;
+
+/// Get the type of remnant left behind by a star of the given mass in Msol.
+#[named]
+pub fn progenitor_mass_to_remnant_type(mass: f64) -> RemnantType {
+  trace_enter!();
+  trace_var!(mass);
+  use RemnantType::*;
+  let result = match mass {
+    mass if mass < MINIMUM_NEUTRON_STAR_PROGENITOR_MASS => WhiteDwarf,
+    mass if mass < MINIMUM_BLACK_HOLE_PROGENITOR_MASS => NeutronStar,
+    _ => BlackHole,
+  };
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the mass of the remnant left behind by a star of the given mass.
+///
+/// White dwarfs follow a linear initial-final mass relation; neutron stars
+/// scale gently from the minimum to the maximum neutron star mass across the
+/// progenitor range; black holes keep a fixed fraction of the progenitor.
+///
+/// Measured in Msol.
+#[named]
+pub fn progenitor_mass_to_remnant_mass(mass: f64) -> f64 {
+  trace_enter!();
+  trace_var!(mass);
+  use RemnantType::*;
+  let result = match progenitor_mass_to_remnant_type(mass) {
+    WhiteDwarf => (INITIAL_FINAL_MASS_SLOPE * mass + INITIAL_FINAL_MASS_INTERCEPT).min(0.99 * CHANDRASEKHAR_MASS),
+    NeutronStar => {
+      let fraction = (mass - MINIMUM_NEUTRON_STAR_PROGENITOR_MASS)
+        / (MINIMUM_BLACK_HOLE_PROGENITOR_MASS - MINIMUM_NEUTRON_STAR_PROGENITOR_MASS);
+      MINIMUM_NEUTRON_STAR_MASS + fraction * (MAXIMUM_NEUTRON_STAR_MASS - MINIMUM_NEUTRON_STAR_MASS)
+    },
+    BlackHole => (BLACK_HOLE_MASS_FRACTION * mass).max(MINIMUM_BLACK_HOLE_MASS),
+  };
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_progenitor_mass_to_remnant_mass() {
+    init();
+    trace_enter!();
+    use RemnantType::*;
+    // Sol will end up as a ~0.5 Msol white dwarf.
+    assert_eq!(WhiteDwarf, progenitor_mass_to_remnant_type(1.0));
+    assert_approx_eq!(progenitor_mass_to_remnant_mass(1.0), 0.503);
+    // Sirius B's progenitor was about 5 Msol; it's now about 1 Msol.
+    assert_approx_eq!(progenitor_mass_to_remnant_mass(5.0), 0.94, 0.1);
+    assert_eq!(NeutronStar, progenitor_mass_to_remnant_type(12.0));
+    let mass = progenitor_mass_to_remnant_mass(12.0);
+    assert!(mass > MINIMUM_NEUTRON_STAR_MASS && mass < MAXIMUM_NEUTRON_STAR_MASS);
+    assert_eq!(BlackHole, progenitor_mass_to_remnant_type(40.0));
+    assert_approx_eq!(progenitor_mass_to_remnant_mass(40.0), 12.0);
+    trace_exit!();
+  }
+}
@@ -0,0 +1,3 @@
+pub mod luminosity;
+pub mod mass;
+pub mod radius;
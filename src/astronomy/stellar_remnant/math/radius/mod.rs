@@ -0,0 +1,61 @@
+use crate::astronomy::stellar_remnant::constants::*;
+use crate::astronomy::stellar_remnant::remnant_type::RemnantType;
+
+/// Get the radius of a white dwarf in Rsol from its mass in Msol.
+///
+/// This is the Nauenberg (1972) approximation; white dwarfs get smaller as
+/// they get heavier, shrinking to nothing at the Chandrasekhar limit.
+#[named]
+pub fn white_dwarf_mass_to_radius(mass: f64) -> f64 {
+  trace_enter!();
+  trace_var!(mass);
+  let ratio = mass / CHANDRASEKHAR_MASS;
+  trace_var!(ratio);
+  let result = WHITE_DWARF_RADIUS_SCALE * ratio.powf(-1.0 / 3.0) * (1.0 - ratio.powf(4.0 / 3.0)).max(0.0).sqrt();
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+/// Get the radius of a remnant of the given type and mass in Msol.
+///
+/// For black holes, this is the event horizon.
+///
+/// Measured in Rsol.
+#[named]
+pub fn remnant_mass_to_radius(remnant_type: RemnantType, mass: f64) -> f64 {
+  trace_enter!();
+  trace_var!(remnant_type);
+  trace_var!(mass);
+  use RemnantType::*;
+  let result = match remnant_type {
+    WhiteDwarf => white_dwarf_mass_to_radius(mass),
+    NeutronStar => NEUTRON_STAR_RADIUS,
+    BlackHole => SCHWARZSCHILD_RADIUS_PER_MSOL * mass,
+  };
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_white_dwarf_mass_to_radius() {
+    init();
+    trace_enter!();
+    // A typical white dwarf is about the size of the Earth.
+    assert_approx_eq!(white_dwarf_mass_to_radius(0.6), 0.0125, 0.001);
+    // Sirius B is about 1 Msol and 0.0084 Rsol.
+    assert_approx_eq!(white_dwarf_mass_to_radius(1.02), 0.0084, 0.001);
+    assert!(white_dwarf_mass_to_radius(1.3) < white_dwarf_mass_to_radius(1.0));
+    // A 10 Msol black hole is about 30 km across.
+    assert_approx_eq!(remnant_mass_to_radius(RemnantType::BlackHole, 10.0), 4.24e-5);
+    trace_exit!();
+  }
+}
@@ -0,0 +1,252 @@
+use rand::prelude::*;
+
+use crate::astronomy::star::math::evolution::get_remnant_formation_age;
+use crate::astronomy::star::math::habitable_zone::star_luminosity_to_habitable_zone;
+use crate::astronomy::star::math::luminosity::star_mass_to_luminosity;
+use crate::astronomy::star::math::metallicity::metallicity_to_luminosity_factor;
+use crate::astronomy::star::math::satellite_zone::{get_approximate_innermost_orbit, get_approximate_outermost_orbit};
+use crate::astronomy::star::name::generate_star_name;
+
+pub mod constants;
+pub mod constraints;
+pub mod error;
+use error::*;
+pub mod math;
+use math::luminosity::get_remnant_luminosity_and_temperature;
+use math::mass::{progenitor_mass_to_remnant_mass, progenitor_mass_to_remnant_type};
+use math::radius::remnant_mass_to_radius;
+pub mod remnant_type;
+use remnant_type::RemnantType;
+
+/// The `StellarRemnant` type.
+///
+/// This is whatever is left over after a star has burned through its fuel
+/// and shed (or exploded) its outer layers: a white dwarf, a neutron star, or
+/// a black hole.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StellarRemnant {
+  /// White dwarf, neutron star, or black hole.
+  pub remnant_type: RemnantType,
+  /// Measured in Msol.
+  pub mass: f64,
+  /// The mass of the star that died to make this, measured in Msol.
+  pub progenitor_mass: f64,
+  /// The metallicity ([Fe/H]) of the star that died to make this.
+  pub metallicity: f64,
+  /// Measured in Kelvin.
+  pub temperature: f64,
+  /// Measured in Rsol.
+  pub radius: f64,
+  /// Measured in Lsol.
+  pub luminosity: f64,
+  /// The age of the system, measured in Gyr.
+  pub current_age: f64,
+  /// The time since the progenitor died, measured in Gyr.
+  pub cooling_age: f64,
+  /// Habitable zone, measured in AU.
+  pub habitable_zone: (f64, f64),
+  /// Minimum and maximum sustainable distance for satellites, measured in AU.
+  pub satellite_zone: (f64, f64),
+  /// The frost line of the progenitor star, measured in AU.
+  pub frost_line: f64,
+  /// A generated name for this remnant.
+  pub name: String,
+}
+
+impl StellarRemnant {
+  /// Generate the remnant of a star of a given mass and metallicity, at a
+  /// given system age.
+  ///
+  /// Metallicity is [Fe/H], so 0.0 is solar.
+  #[named]
+  pub fn from_progenitor<R: Rng + ?Sized>(
+    rng: &mut R,
+    progenitor_mass: f64,
+    metallicity: f64,
+    current_age: f64,
+  ) -> Result<Self, Error> {
+    trace_enter!();
+    trace_var!(progenitor_mass);
+    trace_var!(metallicity);
+    trace_var!(current_age);
+    let name = generate_star_name(rng);
+    trace_var!(name);
+    let result = get_remnant(progenitor_mass, metallicity, current_age, name)?;
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Retrieve or calculate this remnant as it was (or will be) at a given age.
+  #[named]
+  pub fn get_at_age(&self, age: f64) -> Result<Self, Error> {
+    trace_enter!();
+    trace_var!(age);
+    let result = get_remnant(self.progenitor_mass, self.metallicity, age, self.name.clone())?;
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Retrieve or calculate the habitable zone at a given age, in AU.
+  #[named]
+  pub fn get_habitable_zone_at_age(&self, age: f64) -> Result<(f64, f64), Error> {
+    trace_enter!();
+    trace_var!(age);
+    let result = self.get_at_age(age)?.habitable_zone;
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+
+  /// Indicate whether this remnant is capable of supporting conventional life.
+  ///
+  /// Even if a white dwarf's habitable zone is briefly pleasant, anything
+  /// orbiting there was roasted or swallowed by the giant that preceded it.
+  #[named]
+  pub fn check_habitable(&self) -> Result<(), Error> {
+    trace_enter!();
+    let result = Err(Error::RemnantsCannotSupportLife);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Indicate whether this remnant is capable of supporting conventional life.
+  #[named]
+  pub fn is_habitable(&self) -> bool {
+    trace_enter!();
+    let result = match self.check_habitable() {
+      Ok(()) => true,
+      Err(_) => false,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
+
+/// Calculate the remnant of a star of a given mass and metallicity at a given
+/// system age.
+#[named]
+fn get_remnant(
+  progenitor_mass: f64,
+  metallicity: f64,
+  current_age: f64,
+  name: String,
+) -> Result<StellarRemnant, Error> {
+  trace_enter!();
+  trace_var!(progenitor_mass);
+  trace_var!(metallicity);
+  trace_var!(current_age);
+  let formation_age = get_remnant_formation_age(progenitor_mass, metallicity)?;
+  trace_var!(formation_age);
+  if current_age < formation_age {
+    return Err(Error::NotYetFormed);
+  }
+  let cooling_age = current_age - formation_age;
+  trace_var!(cooling_age);
+  let remnant_type = progenitor_mass_to_remnant_type(progenitor_mass);
+  trace_var!(remnant_type);
+  let mass = progenitor_mass_to_remnant_mass(progenitor_mass);
+  trace_var!(mass);
+  let radius = remnant_mass_to_radius(remnant_type, mass);
+  trace_var!(radius);
+  let (luminosity, temperature) = get_remnant_luminosity_and_temperature(remnant_type, mass, cooling_age);
+  trace_var!(luminosity);
+  trace_var!(temperature);
+  let habitable_zone = star_luminosity_to_habitable_zone(luminosity);
+  trace_var!(habitable_zone);
+  let satellite_zone = (
+    get_approximate_innermost_orbit(mass),
+    get_approximate_outermost_orbit(mass),
+  );
+  trace_var!(satellite_zone);
+  // The disk formed around the progenitor, so its frost line is the one that
+  // decided where the gas giants ended up.
+  let frost_line =
+    4.85 * (star_mass_to_luminosity(progenitor_mass)? * metallicity_to_luminosity_factor(metallicity)).sqrt();
+  trace_var!(frost_line);
+  let result = StellarRemnant {
+    remnant_type,
+    mass,
+    progenitor_mass,
+    metallicity,
+    temperature,
+    radius,
+    luminosity,
+    current_age,
+    cooling_age,
+    habitable_zone,
+    satellite_zone,
+    frost_line,
+    name,
+  };
+  trace_var!(result);
+  trace_exit!();
+  Ok(result)
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::astronomy::star::evolutionary_phase::EvolutionaryPhase;
+  use crate::astronomy::star::Star;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_from_progenitor() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    // Sirius B: a ~5 Msol progenitor in a ~0.24 Gyr old system.
+    let sirius_b = StellarRemnant::from_progenitor(&mut rng, 5.0, 0.1, 0.24)?;
+    print_var!(sirius_b);
+    assert_approx_eq!(sirius_b.metallicity, 0.1);
+    assert_eq!(RemnantType::WhiteDwarf, sirius_b.remnant_type);
+    assert!(sirius_b.temperature > 10_000.0);
+    let later = sirius_b.get_at_age(5.0)?;
+    assert_approx_eq!(later.metallicity, 0.1);
+    assert!(later.luminosity < sirius_b.luminosity);
+    assert_eq!(
+      Err(Error::NotYetFormed),
+      StellarRemnant::from_progenitor(&mut rng, 1.0, 0.0, 4.6)
+    );
+    let neutron_star = StellarRemnant::from_progenitor(&mut rng, 12.0, 0.0, 1.0)?;
+    assert_eq!(RemnantType::NeutronStar, neutron_star.remnant_type);
+    let black_hole = StellarRemnant::from_progenitor(&mut rng, 40.0, 0.0, 1.0)?;
+    assert_eq!(RemnantType::BlackHole, black_hole.remnant_type);
+    assert!(!black_hole.is_habitable());
+    trace_exit!();
+    Ok(())
+  }
+
+  #[named]
+  #[test]
+  pub fn test_formation_age() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    // The star and its remnant have to agree on when one becomes the other.
+    for metallicity in [-0.5, 0.5] {
+      let formation_age = get_remnant_formation_age(3.0, metallicity)?;
+      print_var!(formation_age);
+      let star = Star::from_mass_and_metallicity(&mut rng, 3.0, metallicity)?;
+      assert_ne!(EvolutionaryPhase::Remnant, star.get_at_age(0.99 * formation_age)?.phase);
+      assert_eq!(EvolutionaryPhase::Remnant, star.get_at_age(1.01 * formation_age)?.phase);
+      assert_eq!(
+        Err(Error::NotYetFormed),
+        StellarRemnant::from_progenitor(&mut rng, 3.0, metallicity, 0.99 * formation_age)
+      );
+      assert!(StellarRemnant::from_progenitor(&mut rng, 3.0, metallicity, 1.01 * formation_age).is_ok());
+    }
+    trace_exit!();
+    Ok(())
+  }
+}
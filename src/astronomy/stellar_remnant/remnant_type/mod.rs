@@ -0,0 +1,10 @@
+/// The kind of corpse a star leaves behind.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RemnantType {
+  /// The exposed core of a low- or intermediate-mass star.
+  WhiteDwarf,
+  /// What's left of the core of a massive star after a supernova.
+  NeutronStar,
+  /// What's left of the core of a very massive star.
+  BlackHole,
+}
@@ -56,3 +56,19 @@ pub const MINIMUM_HABITABLE_GRAVITY: f64 = 0.5;
 
 /// Too damned hard to get out of bed.
 pub const MAXIMUM_HABITABLE_GRAVITY: f64 = 1.5;
+
+/// Too damned much X-ray and extreme UV, in erg/cm²/s.
+///
+/// Earth gets about 1 erg/cm²/s; much more than ten times that, sustained
+/// over billions of years, and the atmosphere gets stripped away.
+pub const MAXIMUM_HABITABLE_XUV_FLUX: f64 = 10.0;
+
+/// A flare that delivers this fluence, in erg/cm², does real damage to an
+/// atmosphere, e.g. by stripping the ozone layer.
+///
+/// This is about what a 10^34 erg superflare from the Sun would deliver to
+/// Earth.
+pub const HAZARDOUS_FLARE_FLUENCE: f64 = 3.6E6;
+
+/// Too damned many hazardous flares, per year.
+pub const MAXIMUM_HABITABLE_HAZARDOUS_FLARE_RATE: f64 = 1.0;
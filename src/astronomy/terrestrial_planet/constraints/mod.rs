@@ -1,6 +1,8 @@
 use rand::prelude::*;
 
 use crate::astronomy::host_star::HostStar;
+use crate::astronomy::planet::math::occurrence::get_rocky_planet_mass_factor;
+use crate::astronomy::star::math::activity::get_flare_energy_for_fluence;
 use crate::astronomy::terrestrial_planet::constants::*;
 use crate::astronomy::terrestrial_planet::error::Error;
 use crate::astronomy::terrestrial_planet::math::temperature::get_equilibrium_temperature;
@@ -65,7 +67,15 @@ impl Constraints {
     trace_var!(minimum_mass);
     let maximum_mass = self.maximum_mass.unwrap_or(MAXIMUM_MASS);
     trace_var!(maximum_mass);
-    let mass = rng.gen_range(minimum_mass..maximum_mass);
+    // Metal-poor disks don't have the material to build big rocky planets.
+    let mass_factor = get_rocky_planet_mass_factor(host_star.get_metallicity());
+    trace_var!(mass_factor);
+    let maximum_mass = minimum_mass + mass_factor * (maximum_mass - minimum_mass);
+    trace_var!(maximum_mass);
+    let mass = match minimum_mass < maximum_mass {
+      true => rng.gen_range(minimum_mass..maximum_mass),
+      false => minimum_mass,
+    };
     trace_var!(mass);
     let mut result = TerrestrialPlanet::from_mass(mass)?;
     trace_var!(result);
@@ -119,6 +129,14 @@ impl Constraints {
     trace_var!(greenhouse_effect);
     let luminosity = host_star.get_luminosity();
     result.equilibrium_temperature = get_equilibrium_temperature(bond_albedo, greenhouse_effect, luminosity, distance);
+    let xuv_flux = host_star.get_xuv_flux(distance);
+    result.xuv_flux = xuv_flux;
+    trace_var!(xuv_flux);
+    let hazardous_flare_energy = get_flare_energy_for_fluence(HAZARDOUS_FLARE_FLUENCE, distance);
+    trace_var!(hazardous_flare_energy);
+    let hazardous_flare_rate = host_star.get_flare_rate(hazardous_flare_energy);
+    result.hazardous_flare_rate = hazardous_flare_rate;
+    trace_var!(hazardous_flare_rate);
     trace_exit!();
     Ok(result)
   }
@@ -21,6 +21,10 @@ pub enum Error {
   AtmosphereUnstableForArgon,
   /// Nitrogen unstable in this atmosphere.
   AtmosphereUnstableForNitrogen,
+  /// The host star is busy sandblasting the atmosphere away.
+  XuvFluxTooHighToSupportConventionalLife,
+  /// The host star keeps blowing off the ozone layer.
+  FlaresTooFrequentToSupportConventionalLife,
 }
 
 honeyholt_define_brief!(Error, |error: &Error| {
@@ -38,6 +42,8 @@ honeyholt_define_brief!(Error, |error: &Error| {
     AtmosphereUnstableForCarbonDioxide => "not habitable because it cannot retain carbon dioxide".to_string(),
     AtmosphereUnstableForArgon => "not habitable because it cannot retain argon".to_string(),
     AtmosphereUnstableForNitrogen => "not habitable because it cannot retain nitrogen".to_string(),
+    XuvFluxTooHighToSupportConventionalLife => "not habitable because its star's XUV flux is too high".to_string(),
+    FlaresTooFrequentToSupportConventionalLife => "not habitable because its star flares too often".to_string(),
   }
 });
 
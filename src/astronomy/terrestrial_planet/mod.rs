@@ -56,6 +56,11 @@ pub struct TerrestrialPlanet {
   pub equilibrium_temperature: f64,
   /// Whether we can retain the gases necessary for conventional life.
   pub is_atmospherically_stable: bool,
+  /// X-ray and extreme UV flux from the host star, in erg/cm²/s.
+  pub xuv_flux: f64,
+  /// The rate of flares from the host star that are hazardous at this
+  /// distance, per year.
+  pub hazardous_flare_rate: f64,
 }
 
 impl TerrestrialPlanet {
@@ -104,6 +109,10 @@ impl TerrestrialPlanet {
     trace_var!(equilibrium_temperature);
     let is_atmospherically_stable = is_atmospherically_stable(equilibrium_temperature, escape_velocity);
     trace_var!(is_atmospherically_stable);
+    let xuv_flux = 1.0;
+    trace_var!(xuv_flux);
+    let hazardous_flare_rate = 0.0;
+    trace_var!(hazardous_flare_rate);
     let result = Self {
       mass,
       core_mass_fraction,
@@ -124,6 +133,8 @@ impl TerrestrialPlanet {
       greenhouse_effect,
       equilibrium_temperature,
       is_atmospherically_stable,
+      xuv_flux,
+      hazardous_flare_rate,
     };
     trace_var!(result);
     trace_exit!();
@@ -161,6 +172,12 @@ impl TerrestrialPlanet {
       if !is_nitrogen_stable(self.equilibrium_temperature, self.escape_velocity) {
         return Err(Error::AtmosphereUnstableForNitrogen);
       }
+      if self.xuv_flux >= MAXIMUM_HABITABLE_XUV_FLUX {
+        return Err(Error::XuvFluxTooHighToSupportConventionalLife);
+      }
+      if self.hazardous_flare_rate >= MAXIMUM_HABITABLE_HAZARDOUS_FLARE_RATE {
+        return Err(Error::FlaresTooFrequentToSupportConventionalLife);
+      }
       Ok(())
     };
     trace_var!(result);
@@ -0,0 +1,29 @@
+/// The L4 and L5 points are stable only if the body is less than this
+/// fraction of the total mass (Routh's criterion).
+pub const ROUTH_CRITICAL_MASS_RATIO: f64 = 0.038_52;
+
+/// The probability that each of a giant's Lagrange points has a swarm.
+pub const SWARM_PROBABILITY: f64 = 0.75;
+
+/// The least number of bodies bigger than a kilometer in a swarm.
+pub const MINIMUM_SWARM_COUNT: f64 = 1.0E3;
+
+/// The greatest number of bodies bigger than a kilometer in a swarm;
+/// Jupiter's have about this many.
+pub const MAXIMUM_SWARM_COUNT: f64 = 1.0E6;
+
+/// The average mass of a swarm member, in Mearth.
+pub const MASS_PER_TROJAN: f64 = 1.0E-11;
+
+/// The farthest a swarm member swings around the point, in degrees.
+pub const MAXIMUM_LIBRATION_AMPLITUDE: f64 = 35.0;
+
+/// The probability that each of a giant's Lagrange points holds a planet.
+pub const TROJAN_PLANET_PROBABILITY: f64 = 0.02;
+
+/// The least mass of a trojan planet, in Mearth.
+pub const MINIMUM_TROJAN_PLANET_MASS: f64 = 0.1;
+
+/// The greatest mass of a trojan planet, in Mearth; the giant has to herd it
+/// in, after all.
+pub const MAXIMUM_TROJAN_PLANET_MASS: f64 = 1.0;
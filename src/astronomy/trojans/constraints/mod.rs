@@ -0,0 +1,154 @@
+use rand::prelude::*;
+
+use crate::astronomy::host_star::HostStar;
+use crate::astronomy::planet::constraints::Constraints as PlanetConstraints;
+use crate::astronomy::planet::math::mass::EARTH_MASS_PER_SOLAR_MASS;
+use crate::astronomy::planet::Planet;
+use crate::astronomy::trojans::constants::*;
+use crate::astronomy::trojans::error::Error;
+use crate::astronomy::trojans::lagrange_point::LagrangePoint;
+use crate::astronomy::trojans::math::stability::is_stable;
+use crate::astronomy::trojans::Trojans;
+
+/// Constraints for creating trojans.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Constraints {
+  /// The probability that each of a giant's Lagrange points has a swarm.
+  pub swarm_probability: Option<f64>,
+  /// The probability that each of a giant's Lagrange points holds a planet.
+  pub trojan_planet_probability: Option<f64>,
+}
+
+impl Constraints {
+  /// Generate.
+  ///
+  /// Only giants can gather trojans, and only if they're small enough next
+  /// to the star for the Lagrange points to be stable.  Each point gets its
+  /// own swarm, or not, and maybe a planet.
+  #[named]
+  pub fn generate<R: Rng + ?Sized>(
+    &self,
+    rng: &mut R,
+    host_star: &HostStar,
+    star_distance: f64,
+    planet: &Planet,
+  ) -> Result<Vec<Trojans>, Error> {
+    trace_enter!();
+    trace_var!(host_star);
+    trace_var!(star_distance);
+    trace_var!(planet);
+    let mut result = vec![];
+    if matches!(planet, Planet::TerrestrialPlanet(_))
+      || !is_stable(
+        planet.get_mass_in_earth_masses(),
+        host_star.get_stellar_mass() * EARTH_MASS_PER_SOLAR_MASS,
+      )
+    {
+      trace_exit!();
+      return Ok(result);
+    }
+    let swarm_probability = self.swarm_probability.unwrap_or(SWARM_PROBABILITY);
+    trace_var!(swarm_probability);
+    let trojan_planet_probability = self.trojan_planet_probability.unwrap_or(TROJAN_PLANET_PROBABILITY);
+    trace_var!(trojan_planet_probability);
+    for lagrange_point in [LagrangePoint::L4, LagrangePoint::L5] {
+      let count = match rng.gen_range(0.0..1.0) < swarm_probability {
+        true => rng
+          .gen_range(MINIMUM_SWARM_COUNT.ln()..MAXIMUM_SWARM_COUNT.ln())
+          .exp()
+          .round() as u64,
+        false => 0,
+      };
+      trace_var!(count);
+      let planet = match rng.gen_range(0.0..1.0) < trojan_planet_probability {
+        true => {
+          let constraints = PlanetConstraints {
+            mass: Some(rng.gen_range(MINIMUM_TROJAN_PLANET_MASS..MAXIMUM_TROJAN_PLANET_MASS)),
+            ..PlanetConstraints::default()
+          };
+          Some(constraints.generate(rng, host_star, star_distance)?)
+        },
+        false => None,
+      };
+      trace_var!(planet);
+      if count == 0 && planet.is_none() {
+        continue;
+      }
+      let mass = count as f64 * MASS_PER_TROJAN;
+      trace_var!(mass);
+      let libration_amplitude = rng.gen_range(0.0..MAXIMUM_LIBRATION_AMPLITUDE);
+      trace_var!(libration_amplitude);
+      result.push(Trojans {
+        lagrange_point,
+        count,
+        mass,
+        libration_amplitude,
+        planet,
+      });
+    }
+    trace_var!(result);
+    trace_exit!();
+    Ok(result)
+  }
+}
+
+impl Default for Constraints {
+  /// No constraints, just let it all hang out.
+  fn default() -> Self {
+    let swarm_probability = None;
+    let trojan_planet_probability = None;
+    Self {
+      swarm_probability,
+      trojan_planet_probability,
+    }
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use crate::astronomy::host_star::constraints::Constraints as HostStarConstraints;
+  use rand::prelude::*;
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_generate() -> Result<(), Error> {
+    init();
+    trace_enter!();
+    let mut rng = thread_rng();
+    trace_var!(rng);
+    let host_star = &HostStarConstraints::habitable().generate(&mut rng)?;
+    trace_var!(host_star);
+    let star_distance = host_star.get_frost_line() * 2.0;
+    let planet_constraints = PlanetConstraints {
+      mass: Some(317.8),
+      ..PlanetConstraints::default()
+    };
+    let planet = &planet_constraints.generate(&mut rng, host_star, star_distance)?;
+    let constraints = Constraints {
+      swarm_probability: Some(1.0),
+      trojan_planet_probability: Some(1.0),
+    };
+    let trojans = constraints.generate(&mut rng, host_star, star_distance, planet)?;
+    print_var!(trojans);
+    assert_eq!(trojans.len(), 2);
+    assert!(trojans.iter().all(|trojans| trojans.count > 0
+      && matches!(trojans.planet, Some(Planet::TerrestrialPlanet(_)))
+      && trojans.planet.as_ref().unwrap().get_semi_major_axis() == star_distance));
+    assert_ne!(trojans[0].lagrange_point, trojans[1].lagrange_point);
+    // Nothing for a terrestrial planet.
+    let planet_constraints = PlanetConstraints {
+      mass: Some(1.0),
+      ..PlanetConstraints::default()
+    };
+    let planet = &planet_constraints.generate(&mut rng, host_star, star_distance)?;
+    assert!(constraints
+      .generate(&mut rng, host_star, star_distance, planet)?
+      .is_empty());
+    trace_exit!();
+    Ok(())
+  }
+}
@@ -0,0 +1,79 @@
+/// The `LagrangePoint` type.
+///
+/// Which of the two stable points on a body's orbit something is parked at.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LagrangePoint {
+  /// 60º ahead of the body; Jupiter's Greek camp.
+  L4,
+  /// 60º behind the body; Jupiter's Trojan camp.
+  L5,
+}
+
+impl LagrangePoint {
+  /// Get how far ahead of the body the point sits, in degrees.
+  #[named]
+  pub fn get_longitude_offset(&self) -> f64 {
+    trace_enter!();
+    use LagrangePoint::*;
+    let result = match self {
+      L4 => 60.0,
+      L5 => -60.0,
+    };
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Get the longitude of the point, in degrees, given the body's.
+  #[named]
+  pub fn get_longitude(&self, longitude: f64) -> f64 {
+    trace_enter!();
+    trace_var!(longitude);
+    let result = (longitude + self.get_longitude_offset()).rem_euclid(360.0);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Get the position of the point in the orbital plane, given the body's
+  /// semi-major axis and longitude in degrees, in the same units as the
+  /// semi-major axis.
+  ///
+  /// The point, the body, and the thing they both orbit make an equilateral
+  /// triangle, so it's on the same circle.
+  #[named]
+  pub fn get_position(&self, semi_major_axis: f64, longitude: f64) -> (f64, f64) {
+    trace_enter!();
+    trace_var!(semi_major_axis);
+    trace_var!(longitude);
+    let longitude = self.get_longitude(longitude).to_radians();
+    trace_var!(longitude);
+    let result = (semi_major_axis * longitude.cos(), semi_major_axis * longitude.sin());
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_get_position() {
+    init();
+    trace_enter!();
+    assert_approx_eq!(LagrangePoint::L4.get_longitude(330.0), 30.0);
+    assert_approx_eq!(LagrangePoint::L5.get_longitude(30.0), 330.0);
+    let (x, y) = LagrangePoint::L4.get_position(5.2, 0.0);
+    print_var!((x, y));
+    assert_approx_eq!(x, 2.6);
+    assert_approx_eq!(y, 4.503, 0.001);
+    // It's as far from the body as from the star.
+    assert_approx_eq!(((x - 5.2).powf(2.0) + y.powf(2.0)).sqrt(), 5.2);
+    trace_exit!();
+  }
+}
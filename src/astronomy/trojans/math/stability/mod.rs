@@ -0,0 +1,39 @@
+use crate::astronomy::trojans::constants::*;
+
+/// Indicate whether the L4 and L5 points of a body can hold on to anything,
+/// given its mass and the mass of what it orbits, in the same units.
+///
+/// They're only stable if the body is less than about 1/26 of the total
+/// (Routh's criterion); the Sun and Jupiter manage it easily, but a brown
+/// dwarf around a small star might not.
+#[named]
+pub fn is_stable(mass: f64, primary_mass: f64) -> bool {
+  trace_enter!();
+  trace_var!(mass);
+  trace_var!(primary_mass);
+  let mass_ratio = mass / (mass + primary_mass);
+  trace_var!(mass_ratio);
+  let result = mass_ratio < ROUTH_CRITICAL_MASS_RATIO;
+  trace_var!(result);
+  trace_exit!();
+  result
+}
+
+#[cfg(test)]
+pub mod test {
+
+  use super::*;
+  use crate::test::*;
+
+  #[named]
+  #[test]
+  pub fn test_is_stable() {
+    init();
+    trace_enter!();
+    // Jupiter and the Sun, in Msol.
+    assert!(is_stable(0.000_954, 1.0));
+    // Pluto and Charon, in Mmoon.
+    assert!(!is_stable(0.0216, 0.177));
+    trace_exit!();
+  }
+}
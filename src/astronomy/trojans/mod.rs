@@ -0,0 +1,57 @@
+use crate::astronomy::planet::Planet;
+
+pub mod constants;
+pub mod constraints;
+pub mod error;
+pub mod lagrange_point;
+use lagrange_point::LagrangePoint;
+pub mod math;
+
+/// `Trojans` share a giant planet's orbit, herded around 60º ahead of it or
+/// behind it.
+///
+/// Mostly they're a swarm of asteroids, like Jupiter's, but now and then a
+/// whole planet ends up there.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trojans {
+  /// Which point they're gathered around.
+  pub lagrange_point: LagrangePoint,
+  /// The number of bodies bigger than a kilometer.
+  pub count: u64,
+  /// Their total mass, in Mearth.
+  pub mass: f64,
+  /// How far they swing around the point, in degrees.
+  pub libration_amplitude: f64,
+  /// A co-orbital planet, if there's one.
+  pub planet: Option<Planet>,
+}
+
+impl Trojans {
+  /// Retrieve or calculate the range of longitudes, in degrees, that the
+  /// swarm covers, given the giant's longitude.
+  #[named]
+  pub fn get_longitude_range(&self, longitude: f64) -> (f64, f64) {
+    trace_enter!();
+    trace_var!(longitude);
+    let center = self.lagrange_point.get_longitude(longitude);
+    trace_var!(center);
+    let result = (
+      (center - self.libration_amplitude).rem_euclid(360.0),
+      (center + self.libration_amplitude).rem_euclid(360.0),
+    );
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+
+  /// Retrieve or calculate the position of the swarm's center, in AU, given
+  /// the giant's semi-major axis in AU and longitude in degrees.
+  #[named]
+  pub fn get_position(&self, semi_major_axis: f64, longitude: f64) -> (f64, f64) {
+    trace_enter!();
+    let result = self.lagrange_point.get_position(semi_major_axis, longitude);
+    trace_var!(result);
+    trace_exit!();
+    result
+  }
+}